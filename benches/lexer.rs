@@ -0,0 +1,46 @@
+/*
+Lexer Benchmarks
+
+Lexes queries from a few hundred bytes up to 64KB. The lexer reads each
+character once, so the throughput reported for every size should stay
+about the same, a lexer going back over the query would fall behind as
+queries grow.
+*/
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use eaql::language::lexer;
+use std::hint::black_box;
+
+const CLAUSE: &str =
+    "name is \"caf\u{e9} latte \u{2615}\" or (price >= 2.50 and size != \"large\")";
+
+/// A single query whose filter is repeated until it's at least `size` bytes long.
+fn query(size: usize) -> String {
+    let mut query: String = format!("get name, price and size from b\u{fc}cher where {}", CLAUSE);
+
+    while query.len() < size {
+        query.push_str(" and ");
+        query.push_str(CLAUSE);
+    }
+
+    query.push_str(" then sort by price descending and limit it to 10;");
+    query
+}
+
+fn bench_lexer(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lexer");
+
+    for size in [512, 1024, 4096, 16384, 65536] {
+        let query: String = query(size);
+
+        group.throughput(Throughput::Bytes(query.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &query, |b, query| {
+            b.iter(|| lexer::scan_tokens(black_box(query)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_lexer);
+criterion_main!(benches);
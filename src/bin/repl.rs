@@ -1,20 +1,73 @@
-use eaql::{transpiler, utils, validator};
+use eaql::{
+    formatter, transpiler,
+    utils::{
+        self,
+        io::Format,
+        logger::{self, Level},
+    },
+    validator,
+};
+use std::process::ExitCode;
 
-fn main() {
+fn main() -> ExitCode {
     let args: Vec<String> = std::env::args().collect();
 
+    // The library is silent by default, the REPL logs to the terminal
+    // at the level set by EAQL_LOG (i.e. EAQL_LOG=debug)
+    logger::set_sink(logger::Terminal);
+    logger::set_level(
+        std::env::var("EAQL_LOG")
+            .ok()
+            .and_then(|v| Level::from_name(&v))
+            .unwrap_or(Level::Warning),
+    );
+
     utils::help::display_logo();
 
-    if args.len() < 2 {
-        utils::help::display_help(None);
-        panic!("Abort: Invalid number of params");
+    let mut format: Format = Format::Text;
+    let mut module: Option<&str> = None;
+    let mut params = args.iter().skip(1);
+
+    while let Some(param) = params.next() {
+        if param != "--format" {
+            module = Some(param);
+            continue;
+        }
+
+        let name: &str = params.next().map(|v| v.as_str()).unwrap_or("");
+
+        match Format::from_name(name) {
+            Some(state) => format = state,
+            None => {
+                utils::help::display_help(Some(
+                    format!(
+                        "Invalid output format -> \"{}\", see usage! (json needs the `serde` feature)",
+                        name
+                    )
+                    .as_str(),
+                ));
+                return ExitCode::FAILURE;
+            }
+        }
     }
 
-    match args[args.len() - 1].as_str() {
-        "transpile" => transpiler::repl_loop(),
-        "query_test" => validator::repl_loop(),
-        _ => utils::help::display_help(Some(
-            format!("Invalid Testing CLI Argument -> {}, see usage!", args[2]).as_str(),
-        )),
+    let Some(module) = module else {
+        utils::help::display_help(Some("Invalid number of params, see usage!"));
+        return ExitCode::FAILURE;
+    };
+
+    match module {
+        "transpile" => transpiler::repl_loop(format),
+        "reverse" => transpiler::reverse::repl_loop(format),
+        "query_test" => validator::repl_loop(format),
+        "format" => formatter::repl_loop(format),
+        arg => {
+            utils::help::display_help(Some(
+                format!("Invalid Testing CLI Argument -> {}, see usage!", arg).as_str(),
+            ));
+            return ExitCode::FAILURE;
+        }
     }
+
+    ExitCode::SUCCESS
 }
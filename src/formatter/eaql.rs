@@ -0,0 +1,382 @@
+/*
+EAQL Generation
+
+Turns a query back into EAQL by visiting its tree. Rather than echoing
+what was originally written, every query comes out the same way: the
+keywords preferred by the style, lists and comparisons written out in
+English and only the parentheses the conditions actually need.
+*/
+
+use crate::{
+    formatter::Style,
+    language::{
+        errors::{EaqlError, ErrorContext},
+        parser::{
+            arithmetic::{ArithmeticChild, ArithmeticNode, NegateNode},
+            conditional::{
+                self, BoolNode, Case, ComparisonNode, ComparisonOperator, ConditionChild,
+                ConditionNode, ExpressionNode, ListNode, LogicalOperator, NotNode, OperandNode,
+                RangeNode, Value,
+            },
+            database::{CreateNode, DestroyNode, ShowNode, UseNode},
+            get::{ColumnNode, FilterNode, GetNode, TableNode},
+            parser::Query,
+            postprocessor::{LimitNode, PostProcessorNode, SortDirection, SortKeyNode, SortNode},
+        },
+        visitor::{Visitor, walk_arithmetic, walk_condition, walk_condition_child, walk_query},
+    },
+    transpiler::sql::{Precedence, precedence},
+};
+
+pub(crate) struct EaqlWriter<'s> {
+    style: &'s Style,
+
+    eaql: String,
+
+    precedence: Precedence,
+    error: Option<EaqlError>,
+}
+
+/// Outputs whatever `visit` visits as canonical EAQL.
+pub(crate) fn write(
+    style: &Style,
+    visit: impl FnOnce(&mut EaqlWriter),
+) -> Result<String, EaqlError> {
+    let mut writer: EaqlWriter = EaqlWriter {
+        style,
+        eaql: String::new(),
+        precedence: Precedence::Or,
+        error: None,
+    };
+
+    visit(&mut writer);
+
+    match writer.error {
+        Some(err) => Err(err),
+        None => Ok(writer.eaql),
+    }
+}
+
+/// Joins items the way they'd be listed in English (`a, b and c`).
+fn english_list(items: &[String]) -> String {
+    match items {
+        [] => String::new(),
+        [item] => item.to_string(),
+        [rest @ .., last] => format!("{} and {}", rest.join(", "), last),
+    }
+}
+
+/// Writes a comparison operator the way it's written in a query.
+fn operator(operator: ComparisonOperator) -> &'static str {
+    match operator {
+        ComparisonOperator::Equal => "is",
+        ComparisonOperator::NotEqual => "is not",
+        ComparisonOperator::LessThan => "is less than",
+        ComparisonOperator::LessThanOrEqual => "is at most",
+        ComparisonOperator::GreaterThan => "is greater than",
+        ComparisonOperator::GreaterThanOrEqual => "is at least",
+        ComparisonOperator::Contains(_) => "contains",
+        ComparisonOperator::StartsWith(_) => "starts with",
+        ComparisonOperator::EndsWith(_) => "ends with",
+        ComparisonOperator::Like(_) => "like",
+    }
+}
+
+/// Writes a value the way it's written in a query.
+fn value(value: Value) -> String {
+    match value {
+        Value::Text(text) => format!("\"{}\"", text),
+        Value::Number(number) => number.to_string(),
+        Value::Bool(value) => value.to_string(),
+        Value::Null => "null".to_string(),
+    }
+}
+
+impl EaqlWriter<'_> {
+    /// Appends a part of the query, space separated from the last.
+    fn part(&mut self, text: &str) {
+        if !self.eaql.is_empty() && !text.is_empty() {
+            self.eaql.push(' ');
+        }
+
+        self.eaql.push_str(text);
+    }
+
+    /// Outputs one side of some arithmetic, wrapped in parentheses
+    /// where it would otherwise be done in another order.
+    fn arithmetic_side(&mut self, node: &ArithmeticChild, grouped: bool) {
+        if grouped {
+            self.eaql.push('(');
+        }
+
+        self.visit_arithmetic(node);
+
+        if grouped {
+            self.eaql.push(')');
+        }
+    }
+
+    /// Runs `visit`, taking the EAQL it output rather than appending it.
+    fn capture(&mut self, visit: impl FnOnce(&mut Self)) -> String {
+        let outer: String = std::mem::take(&mut self.eaql);
+
+        visit(self);
+        std::mem::replace(&mut self.eaql, outer)
+    }
+}
+
+impl<'ast> Visitor<'ast> for EaqlWriter<'_> {
+    fn visit_query(&mut self, node: &'ast Query) {
+        walk_query(self, node);
+
+        if self.style.capitalize {
+            let mut chars = self.eaql.chars();
+
+            if let Some(first) = chars.next() {
+                self.eaql = first.to_uppercase().chain(chars).collect();
+            }
+        }
+
+        self.eaql.push_str(&self.style.end);
+    }
+
+    fn visit_get(&mut self, node: &'ast GetNode) {
+        self.part(&self.style.get.clone());
+        self.visit_column(node.columns());
+        self.visit_table(node.table());
+
+        if let Some(filter) = node.filter() {
+            self.visit_filter(filter);
+        }
+
+        if let Some(postprocessor) = node.postprocessor() {
+            self.visit_postprocessor(postprocessor);
+        }
+    }
+
+    fn visit_column(&mut self, node: &'ast ColumnNode) {
+        match node.is_wildcard() {
+            true => self.part(&self.style.wildcard.clone()),
+            false => self.part(&english_list(node.names())),
+        }
+    }
+
+    fn visit_table(&mut self, node: &'ast TableNode) {
+        self.part(&format!("from {}", node.name()));
+    }
+
+    fn visit_filter(&mut self, node: &'ast FilterNode) {
+        self.part(&self.style.filter.clone());
+        self.visit_condition(node.condition());
+    }
+
+    fn visit_condition(&mut self, node: &'ast ConditionNode) {
+        let condition: String = self.capture(|writer| {
+            writer.precedence = Precedence::Or;
+            walk_condition(writer, node);
+        });
+
+        self.part(&condition);
+    }
+
+    /// Only adds parentheses where the precedence wouldn't match the tree.
+    fn visit_condition_child(&mut self, node: &'ast ConditionChild) {
+        let node: &ConditionChild = node.simplify();
+        let parent: Precedence = std::mem::replace(&mut self.precedence, precedence(node));
+        let grouped: bool = self.precedence < parent;
+
+        if grouped {
+            self.eaql.push('(');
+        }
+
+        walk_condition_child(self, node);
+
+        if grouped {
+            self.eaql.push(')');
+        }
+
+        self.precedence = parent;
+    }
+
+    fn visit_operand(&mut self, node: &'ast OperandNode) {
+        self.visit_condition_child(node.left());
+        self.eaql.push_str(match node.operator() {
+            LogicalOperator::And => " and ",
+            LogicalOperator::Or => " or ",
+        });
+        self.visit_condition_child(node.right());
+    }
+
+    /// Negation only applies to an expression or parenthesized group.
+    fn visit_not(&mut self, node: &'ast NotNode) {
+        self.eaql.push_str("not ");
+        self.precedence = Precedence::Expression;
+        self.visit_condition_child(node.child());
+    }
+
+    fn visit_expression(&mut self, node: &'ast ExpressionNode) {
+        self.eaql.push_str(&format!(
+            "{} {} {}",
+            node.column(),
+            operator(node.operator()),
+            value(node.value())
+        ));
+
+        if node.operator().pattern_case() == Some(Case::Insensitive) {
+            self.eaql.push_str(" ignoring case");
+        }
+    }
+
+    fn visit_comparison(&mut self, node: &'ast ComparisonNode) {
+        // A plain `is` would read as part of a phrase with some column
+        // names (`x is empty`, `x is above`), so equality is spelled out
+        let operator: &str = match node.operator() {
+            ComparisonOperator::Equal => "is equal to",
+            ComparisonOperator::NotEqual => "is not equal to",
+            comparison => operator(comparison),
+        };
+
+        self.visit_arithmetic(node.left());
+        self.eaql.push_str(&format!(" {} ", operator));
+        self.visit_arithmetic(node.right());
+    }
+
+    fn visit_arithmetic(&mut self, node: &'ast ArithmeticChild) {
+        match node {
+            ArithmeticChild::Column(token) => self.eaql.push_str(&token.lexeme),
+            ArithmeticChild::Value(token) => self.eaql.push_str(&value(conditional::value(token))),
+            ArithmeticChild::Negate(_) | ArithmeticChild::Op(_) => walk_arithmetic(self, node),
+        }
+    }
+
+    fn visit_arithmetic_op(&mut self, node: &'ast ArithmeticNode) {
+        self.arithmetic_side(node.left(), node.grouped(false));
+        self.eaql
+            .push_str(&format!(" {} ", node.operator().symbol()));
+        self.arithmetic_side(node.right(), node.grouped(true));
+    }
+
+    fn visit_negate(&mut self, node: &'ast NegateNode) {
+        self.eaql.push('-');
+        self.arithmetic_side(node.child(), node.grouped());
+    }
+
+    fn visit_list(&mut self, node: &'ast ListNode) {
+        let values: Vec<String> = node.values().into_iter().map(value).collect();
+
+        self.eaql.push_str(&format!(
+            "{} {} ({})",
+            node.column(),
+            if node.negated() {
+                "is not one of"
+            } else {
+                "is one of"
+            },
+            values.join(", ")
+        ));
+    }
+
+    fn visit_range(&mut self, node: &'ast RangeNode) {
+        self.eaql.push_str(&format!(
+            "{} {} {} and {}",
+            node.column(),
+            if node.negated() {
+                "is not between"
+            } else {
+                "is between"
+            },
+            value(node.low()),
+            value(node.high())
+        ));
+    }
+
+    fn visit_bool(&mut self, node: &'ast BoolNode) {
+        self.error
+            .get_or_insert(EaqlError::Unsupported(Box::new(ErrorContext::new(
+                format!(
+                    "Conditions can't be written with a bare `{}` in EAQL",
+                    node.value()
+                ),
+            ))));
+    }
+
+    fn visit_postprocessor(&mut self, node: &'ast PostProcessorNode) {
+        let mut parts: Vec<String> = vec![];
+
+        if let Some(sort) = node.sort() {
+            parts.push(self.capture(|writer| writer.visit_sort(sort)));
+        }
+
+        if let Some(limit) = node.limit() {
+            parts.push(self.capture(|writer| writer.visit_limit(limit)));
+        }
+
+        if !parts.is_empty() {
+            self.part(&format!("{} {}", self.style.then, parts.join(" and ")));
+        }
+    }
+
+    fn visit_sort(&mut self, node: &'ast SortNode) {
+        let keys: Vec<String> = node
+            .keys()
+            .iter()
+            .map(|key| self.capture(|writer| writer.visit_sort_key(key)))
+            .collect();
+
+        self.part(&format!("sort by {}", english_list(&keys)));
+    }
+
+    /// Ascending is the default, so only descending is written out.
+    fn visit_sort_key(&mut self, node: &'ast SortKeyNode) {
+        match node.direction() {
+            SortDirection::Ascending => self.part(node.column()),
+            SortDirection::Descending => self.part(&format!("{} descending", node.column())),
+        }
+    }
+
+    fn visit_limit(&mut self, node: &'ast LimitNode) {
+        self.part(&format!("limit it to {}", node.value()));
+    }
+
+    fn visit_create(&mut self, node: &'ast CreateNode) {
+        self.part(&format!("{} database {}", self.style.create, node.name()));
+    }
+
+    fn visit_destroy(&mut self, node: &'ast DestroyNode) {
+        self.part(&format!(
+            "{} {} {}",
+            self.style.delete,
+            match node.names().len() {
+                1 => "database",
+                _ => "databases",
+            },
+            english_list(node.names())
+        ));
+    }
+
+    fn visit_use(&mut self, node: &'ast UseNode) {
+        self.part(&format!("{} database {}", self.style.using, node.name()));
+    }
+
+    fn visit_show(&mut self, _node: &'ast ShowNode) {
+        self.part(&format!("{} databases", self.style.show));
+    }
+}
+
+// Begin EAQL Generation Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_test_english_list() {
+        assert_eq!(english_list(&[]), "");
+        assert_eq!(english_list(&["a".to_string()]), "a");
+        assert_eq!(english_list(&["a".to_string(), "b".to_string()]), "a and b");
+        assert_eq!(
+            english_list(&["a".to_string(), "b".to_string(), "c".to_string()]),
+            "a, b and c"
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use crate::{
+    language::{
+        diagnostics,
+        errors::{EaqlError, ErrorContext},
+        lexer,
+        parser::parser::Query,
+        tokens::TokenType,
+    },
+    utils::{
+        colors::{AnsiColor, colorize},
+        io::{self, Format},
+        query::{process_query, process_query_with_warnings},
+    },
+};
+
+/// Which of the interchangeable keywords formatted queries are
+/// written with (i.e. `find` rather than `get`).
+#[derive(Debug, PartialEq, Clone)]
+pub struct Style {
+    pub(crate) get: String,
+    pub(crate) wildcard: String,
+    pub(crate) filter: String,
+    pub(crate) then: String,
+    pub(crate) create: String,
+    pub(crate) delete: String,
+    pub(crate) using: String,
+    pub(crate) show: String,
+    pub(crate) end: String,
+    pub(crate) capitalize: bool,
+}
+
+impl Default for Style {
+    fn default() -> Style {
+        Style {
+            get: "get".to_string(),
+            wildcard: "everything".to_string(),
+            filter: "where".to_string(),
+            then: "then".to_string(),
+            create: "create".to_string(),
+            delete: "delete".to_string(),
+            using: "use".to_string(),
+            show: "show".to_string(),
+            end: ".".to_string(),
+            capitalize: true,
+        }
+    }
+}
+
+impl Style {
+    /// Writes queries with the given keyword in place of the ones
+    /// meaning the same thing (i.e. `whenever` for `where`, or `!` to
+    /// end queries with).
+    ///
+    /// # Example
+    /// ```
+    /// use eaql::formatter::{Style, engine_with_style};
+    /// let mut style = Style::default();
+    /// style.prefer("find").unwrap();
+    /// style.prefer(";").unwrap();
+    /// assert_eq!(
+    ///     engine_with_style(&"retrieve all from drinks!", &style),
+    ///     Ok("Find everything from drinks;".to_string())
+    /// );
+    /// ```
+    ///
+    pub fn prefer(&mut self, keyword: &str) -> Result<(), EaqlError> {
+        let keyword: String = keyword.trim().to_lowercase();
+
+        let token_type: Option<TokenType> = lexer::scan_tokens(&keyword)
+            .ok()
+            .filter(|v| v.tokens.len() == 1)
+            .map(|v| v.tokens[0].token_type);
+
+        let preference: &mut String = match token_type {
+            Some(TokenType::Get) => &mut self.get,
+            Some(TokenType::WildcardKeyword) => &mut self.wildcard,
+            Some(TokenType::FilterKeyword) => &mut self.filter,
+            Some(TokenType::PostProcessorEntrance) => &mut self.then,
+            Some(TokenType::CreateKeyword) => &mut self.create,
+            Some(TokenType::DeleteKeyword) => &mut self.delete,
+            Some(TokenType::UseKeyword) => &mut self.using,
+            Some(TokenType::ShowKeyword) => &mut self.show,
+            Some(TokenType::EoqToken) => &mut self.end,
+            _ => {
+                return Err(EaqlError::InvalidValue(Box::new(ErrorContext::new(format!(
+                    "\"{}\" isn't a keyword that can be swapped for another",
+                    keyword
+                ))))
+                .with_hint("i.e. `find`, `all`, `whenever`, `after`, `make`, `remove`, `enter`, `list` or `!`"));
+            }
+        };
+
+        *preference = keyword;
+
+        Ok(())
+    }
+
+    /// Whether queries start with a capital letter, they do by default.
+    pub fn capitalize(&mut self, capitalize: bool) {
+        self.capitalize = capitalize;
+    }
+}
+
+/// Starts a Formatting loop that accepts queries from STDIN
+/// and outputs them rewritten as canonical EAQL. Preferred
+/// keywords can be set with `.prefer <keyword>`.
+pub fn repl_loop(format: Format) {
+    let mut style: Style = Style::default();
+
+    loop {
+        let Some(query) = io::query_stdin("formatter") else {
+            println!();
+            return;
+        };
+
+        if let Some(keyword) = query.strip_prefix(".prefer") {
+            if let Err(err) = style.prefer(keyword) {
+                eprintln!("{}", diagnostics::render_color(&err, &query));
+            }
+
+            continue;
+        }
+
+        let parsed: Query = match process_query_with_warnings(&query) {
+            Ok((state, warnings)) => {
+                for warning in warnings {
+                    eprintln!("{}", diagnostics::render_warning_color(&warning, &query));
+                }
+
+                state
+            }
+            Err(err) => {
+                eprintln!("{}", diagnostics::render_color(&err, &query));
+                continue;
+            }
+        };
+
+        if io::print_ast(format, &parsed) {
+            continue;
+        }
+
+        match parsed.to_eaql(&style) {
+            Ok(formatted) => println!(
+                "‣ {} {}",
+                colorize("Formatted Query:", AnsiColor::BrightBlack),
+                formatted
+            ),
+            Err(err) => eprintln!("{}", diagnostics::render_color(&err, &query)),
+        };
+    }
+}
+
+/// Format Input Query (String) as canonical EAQL
+///
+/// # Example
+/// ```
+/// use eaql::formatter::engine;
+/// assert_eq!(
+///     engine(&"find the name, price from drinks wherever price < 5 after limit 10;"),
+///     Ok("Get name and price from drinks where price is less than 5 then limit it to 10.".to_string())
+/// );
+/// ```
+///
+pub fn engine(query: &str) -> Result<String, EaqlError> {
+    engine_with_style(query, &Style::default())
+}
+
+/// Format Input Query (String) as canonical EAQL written with the
+/// keywords preferred by the style
+pub fn engine_with_style(query: &str, style: &Style) -> Result<String, EaqlError> {
+    let parsed: Query = process_query(&query.to_string())?;
+
+    parsed.to_eaql(style)
+}
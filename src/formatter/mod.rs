@@ -0,0 +1,3 @@
+pub(crate) mod eaql;
+pub mod formatter;
+pub use formatter::*;
@@ -0,0 +1,333 @@
+/*
+Public view of the Abstract Syntax Tree
+
+Re-exports every node a parsed query can be made of so tools can walk
+and inspect queries without reaching into the parser modules, along
+with a parse function going straight from a query string to its tree
+and a check for trees that were put together some other way.
+*/
+
+pub use crate::language::parser::{
+    arithmetic::{ArithmeticChild, ArithmeticNode, ArithmeticOperator, NegateNode},
+    conditional::{
+        BoolNode, Case, ComparisonNode, ComparisonOperator, ConditionChild, ConditionNode,
+        ExpressionNode, ListNode, LogicalOperator, NotNode, OperandNode, RangeNode, Value,
+    },
+    database::{CreateNode, DatabaseNode, DatabaseOperation, DestroyNode, ShowNode, UseNode},
+    get::{ColumnNode, FilterNode, GetNode, PartialGetNode, TableNode},
+    parser::{PartialQuery, Query, Statement},
+    postprocessor::{LimitNode, PostProcessorNode, SortDirection, SortKeyNode, SortNode},
+};
+
+use crate::{
+    language::{
+        errors::{EaqlError, ErrorContext},
+        lexer,
+        tokens::{Token, TokenType},
+        visitor::{Visitor, walk_arithmetic, walk_arithmetic_op, walk_comparison, walk_negate},
+    },
+    utils::query::{process_query, process_query_recovering, process_script},
+};
+
+/// Parse Input Query (String) into its Abstract Syntax Tree
+///
+/// # Example
+/// ```
+/// use eaql::language::ast::{parse, Statement};
+/// let query = parse("get name from drinks;").unwrap();
+/// match query.statement() {
+///     Statement::Get(get) => assert_eq!(get.table().name(), "drinks"),
+///     Statement::Database(_) => unreachable!(),
+/// }
+/// ```
+///
+pub fn parse(query: &str) -> Result<Query, EaqlError> {
+    process_query(&query.to_string())
+}
+
+/// Parse Input Query (String) as far as it can be, returning whatever
+/// could be parsed alongside every error in the query
+///
+/// # Example
+/// ```
+/// use eaql::language::ast::{parse_recovering, PartialQuery};
+/// let (partial, errors) = parse_recovering("get name drinks then limit it to ten;");
+/// assert_eq!(errors.len(), 2);
+///
+/// let PartialQuery::Get(get) = partial else { unreachable!() };
+/// assert_eq!(get.columns().unwrap().names(), ["name"]);
+/// ```
+///
+pub fn parse_recovering(query: &str) -> (PartialQuery, Vec<EaqlError>) {
+    process_query_recovering(&query.to_string())
+}
+
+/// Parse Input Script (String) of one or more queries into their
+/// Abstract Syntax Trees. Each query keeps the span it has in the
+/// script, and a query that can't be parsed doesn't stop the ones
+/// after it, the errors of all of them are returned together.
+///
+/// # Example
+/// ```
+/// use eaql::language::{ast::parse_script, tokens::Spanned};
+/// let queries = parse_script("get name from drinks; show databases;").unwrap();
+/// assert_eq!(queries.len(), 2);
+/// assert_eq!(queries[1].span().start, 22);
+///
+/// let errors = parse_script("get from drinks; show databases; get all drinks;").unwrap_err();
+/// assert_eq!(errors.len(), 2);
+/// ```
+///
+pub fn parse_script(script: &str) -> Result<Vec<Query>, Vec<EaqlError>> {
+    process_script(&script.to_string())
+}
+
+/// Serialize a parsed query (its full tree, spans included) to JSON
+///
+/// # Example
+/// ```
+/// use eaql::language::ast::{from_json, parse, to_json};
+/// let query = parse("get name from drinks;").unwrap();
+/// let json = to_json(&query).unwrap();
+/// assert_eq!(from_json(&json).unwrap().to_string(), query.to_string());
+/// ```
+///
+#[cfg(feature = "serde")]
+pub fn to_json(query: &Query) -> Result<String, serde_json::Error> {
+    serde_json::to_string_pretty(query)
+}
+
+/// Deserialize a query previously serialized with `to_json`, the
+/// tree read is checked the same way `check` does before it's returned
+/// since the JSON could have come from anywhere.
+#[cfg(feature = "serde")]
+pub fn from_json(json: &str) -> Result<Query, EaqlError> {
+    let query: Query = serde_json::from_str(json).map_err(|err| {
+        EaqlError::InvalidValue(Box::new(ErrorContext::new(format!(
+            "Couldn't read the query from JSON -> {}",
+            err
+        ))))
+    })?;
+
+    check(&query)?;
+    Ok(query)
+}
+
+/// Check a tree only holds what parsing a query could have produced,
+/// for trees that were edited or put together by hand. Names and values
+/// have to be read back by the lexer as written and operators have to be
+/// ones the node they're in is parsed with.
+///
+/// # Example
+/// ```
+/// use eaql::language::ast::{check, parse, Statement};
+///
+/// let mut query = parse("get name from drinks;").unwrap();
+/// assert!(check(&query).is_ok());
+///
+/// let Statement::Get(get) = query.statement_mut() else { unreachable!() };
+/// get.table_mut().set_name("drinks; drop table drinks");
+/// assert!(check(&query).is_err());
+/// ```
+///
+pub fn check(query: &Query) -> Result<(), EaqlError> {
+    let mut checker: Checker = Checker { error: None };
+    checker.visit_query(query);
+
+    match checker.error {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+const VALUES: &[TokenType] = &[TokenType::StringLiteral, TokenType::NumberLiteral];
+
+const COMPARISONS: &[TokenType] = &[
+    TokenType::Equal,
+    TokenType::NotEqual,
+    TokenType::Lt,
+    TokenType::Lte,
+    TokenType::Gt,
+    TokenType::Gte,
+];
+
+const PATTERNS: &[TokenType] = &[
+    TokenType::Contains,
+    TokenType::StartsWith,
+    TokenType::EndsWith,
+    TokenType::Like,
+];
+
+/// Keeps the first part of the tree that parsing couldn't have produced.
+struct Checker {
+    error: Option<EaqlError>,
+}
+
+impl Checker {
+    /// Names have to be read back as the one identifier they're written as.
+    fn identifier(&mut self, token: &Token) {
+        if token.token_type != TokenType::Identifier || !lexed(token) {
+            self.error
+                .get_or_insert(EaqlError::UnexpectedToken(Box::new(ErrorContext::new(
+                    format!("\"{}\" isn't a valid name.", token.lexeme),
+                ))));
+        }
+    }
+
+    fn name(&mut self, name: &str) {
+        self.identifier(&Token::new(
+            TokenType::Identifier,
+            &name.to_string(),
+            &name.to_string(),
+        ));
+    }
+
+    fn value(&mut self, token: &Token, types: &[TokenType]) {
+        if !types.contains(&token.token_type) || !lexed(token) {
+            self.error
+                .get_or_insert(EaqlError::InvalidValue(Box::new(ErrorContext::new(
+                    format!("\"{}\" isn't a valid value here.", token.lexeme),
+                ))));
+        }
+    }
+
+    /// Operators are written out by their type alone, so that's all that's checked.
+    fn operator(&mut self, token: &Token, types: &[TokenType]) {
+        if !types.contains(&token.token_type) {
+            self.error
+                .get_or_insert(EaqlError::UnexpectedToken(Box::new(ErrorContext::new(
+                    format!(
+                        "{:?} (\"{}\") isn't an operator that can be used here.",
+                        token.token_type, token.lexeme
+                    ),
+                ))));
+        }
+    }
+}
+
+/// Whether the lexer reads the token's lexeme back as that token alone.
+fn lexed(token: &Token) -> bool {
+    lexer::scan_tokens(&token.lexeme)
+        .is_ok_and(|lexer| lexer.tokens.len() == 1 && lexer.tokens[0] == *token)
+}
+
+impl<'ast> Visitor<'ast> for Checker {
+    fn visit_column(&mut self, node: &'ast ColumnNode) {
+        if !node.is_wildcard() {
+            node.names().iter().for_each(|name| self.name(name));
+        }
+    }
+
+    fn visit_table(&mut self, node: &'ast TableNode) {
+        self.name(node.name());
+    }
+
+    fn visit_expression(&mut self, node: &'ast ExpressionNode) {
+        let operator: TokenType = node.operator_token().token_type;
+        let pattern: bool = PATTERNS.contains(&operator);
+
+        self.identifier(node.identifier_token());
+        self.operator(
+            node.operator_token(),
+            &[
+                COMPARISONS,
+                PATTERNS,
+                &[TokenType::IsEmpty, TokenType::IsNotEmpty],
+            ]
+            .concat(),
+        );
+
+        // Only `is` and `is not` compare against true, false or null,
+        // and patterns are always text
+        match operator {
+            _ if pattern => self.value(node.value_token(), &[TokenType::StringLiteral]),
+            TokenType::Equal | TokenType::NotEqual | TokenType::IsEmpty | TokenType::IsNotEmpty => {
+                self.value(
+                    node.value_token(),
+                    &[VALUES, &[TokenType::BooleanLiteral, TokenType::NullLiteral]].concat(),
+                )
+            }
+            _ => self.value(node.value_token(), VALUES),
+        }
+
+        if let Some(token) = node.ignore_case_token() {
+            self.operator(
+                token,
+                if pattern {
+                    &[TokenType::IgnoreCase]
+                } else {
+                    &[]
+                },
+            );
+        }
+    }
+
+    fn visit_list(&mut self, node: &'ast ListNode) {
+        self.identifier(node.identifier_token());
+        self.operator(
+            node.operator_token(),
+            &[TokenType::OneOf, TokenType::NotOneOf],
+        );
+        node.value_tokens()
+            .iter()
+            .for_each(|token| self.value(token, VALUES));
+    }
+
+    fn visit_range(&mut self, node: &'ast RangeNode) {
+        self.identifier(node.identifier_token());
+        self.operator(
+            node.operator_token(),
+            &[TokenType::Between, TokenType::NotBetween],
+        );
+        node.value_tokens()
+            .into_iter()
+            .for_each(|token| self.value(token, VALUES));
+    }
+
+    fn visit_comparison(&mut self, node: &'ast ComparisonNode) {
+        self.operator(node.operator_token(), COMPARISONS);
+        walk_comparison(self, node);
+    }
+
+    fn visit_arithmetic(&mut self, node: &'ast ArithmeticChild) {
+        match node {
+            ArithmeticChild::Column(token) => self.identifier(token),
+            ArithmeticChild::Value(token) => self.value(token, VALUES),
+            ArithmeticChild::Negate(_) | ArithmeticChild::Op(_) => walk_arithmetic(self, node),
+        }
+    }
+
+    fn visit_arithmetic_op(&mut self, node: &'ast ArithmeticNode) {
+        self.operator(
+            node.operator_token(),
+            &[
+                TokenType::Plus,
+                TokenType::Minus,
+                TokenType::Star,
+                TokenType::Slash,
+            ],
+        );
+        walk_arithmetic_op(self, node);
+    }
+
+    fn visit_negate(&mut self, node: &'ast NegateNode) {
+        self.operator(node.minus_token(), &[TokenType::Minus]);
+        walk_negate(self, node);
+    }
+
+    fn visit_sort_key(&mut self, node: &'ast SortKeyNode) {
+        self.name(node.column());
+    }
+
+    fn visit_create(&mut self, node: &'ast CreateNode) {
+        self.name(node.name());
+    }
+
+    fn visit_destroy(&mut self, node: &'ast DestroyNode) {
+        node.names().iter().for_each(|name| self.name(name));
+    }
+
+    fn visit_use(&mut self, node: &'ast UseNode) {
+        self.name(node.name());
+    }
+}
@@ -0,0 +1,1119 @@
+/*
+Building Queries
+
+Puts a query together in code rather than writing it out as text. The
+builder assembles the same nodes the parser produces, so a built query
+is transpiled, validated and displayed exactly like a parsed one.
+
+Query::get(["name", "price"])
+    .from("drinks")
+    .filter(col("price").lt(5))
+    .limit(10)
+    .build()
+*/
+
+use crate::language::{
+    errors::{EaqlError, ErrorContext},
+    lexer,
+    parser::{
+        arithmetic::{ArithmeticChild, ArithmeticNode, ArithmeticOperator, NegateNode},
+        conditional::{
+            Case, ComparisonNode, ComparisonOperator, ConditionChild, ConditionNode,
+            ExpressionNode, ListNode, LogicalOperator, NotNode, OperandNode, RangeNode,
+        },
+        database::{CreateNode, DatabaseNode, DestroyNode, ShowNode, UseNode},
+        get::{ColumnNode, FilterNode, GetNode, TableNode},
+        parser::{Query, Statement},
+        postprocessor::{LimitNode, PostProcessorNode, SortDirection, SortKeyNode, SortNode},
+    },
+    tokens::{Token, TokenType},
+};
+use std::ops;
+
+/// A value a column is compared against.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Literal {
+    Text(String),
+    Number(String),
+    Bool(bool),
+    Null,
+}
+
+impl From<&str> for Literal {
+    fn from(value: &str) -> Literal {
+        Literal::Text(value.to_string())
+    }
+}
+
+impl From<String> for Literal {
+    fn from(value: String) -> Literal {
+        Literal::Text(value)
+    }
+}
+
+impl From<bool> for Literal {
+    fn from(value: bool) -> Literal {
+        Literal::Bool(value)
+    }
+}
+
+impl From<i32> for Literal {
+    fn from(value: i32) -> Literal {
+        Literal::Number(value.to_string())
+    }
+}
+
+impl From<i64> for Literal {
+    fn from(value: i64) -> Literal {
+        Literal::Number(value.to_string())
+    }
+}
+
+impl From<f64> for Literal {
+    fn from(value: f64) -> Literal {
+        Literal::Number(value.to_string())
+    }
+}
+
+/// A column to build a condition on, see `col`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Column {
+    name: String,
+}
+
+/// Starts a condition on the given column.
+///
+/// # Example
+/// ```
+/// use eaql::language::builder::col;
+/// let cheap_or_small = col("price").lt(5).or(col("size").eq("small"));
+/// ```
+///
+pub fn col(name: &str) -> Column {
+    Column {
+        name: name.to_string(),
+    }
+}
+
+impl Column {
+    fn compare(self, operator: ComparisonOperator, value: impl Into<Expr>) -> Condition {
+        Expr::from(self).compare(operator, value)
+    }
+
+    /// Column is equal to the value (`is`), which may be another
+    /// column or arithmetic.
+    pub fn eq(self, value: impl Into<Expr>) -> Condition {
+        self.compare(ComparisonOperator::Equal, value)
+    }
+
+    /// Column isn't equal to the value (`is not`).
+    pub fn ne(self, value: impl Into<Expr>) -> Condition {
+        self.compare(ComparisonOperator::NotEqual, value)
+    }
+
+    pub fn lt(self, value: impl Into<Expr>) -> Condition {
+        self.compare(ComparisonOperator::LessThan, value)
+    }
+
+    pub fn lte(self, value: impl Into<Expr>) -> Condition {
+        self.compare(ComparisonOperator::LessThanOrEqual, value)
+    }
+
+    pub fn gt(self, value: impl Into<Expr>) -> Condition {
+        self.compare(ComparisonOperator::GreaterThan, value)
+    }
+
+    pub fn gte(self, value: impl Into<Expr>) -> Condition {
+        self.compare(ComparisonOperator::GreaterThanOrEqual, value)
+    }
+
+    /// Column contains the text anywhere in it (`contains`).
+    pub fn contains(self, text: &str) -> Condition {
+        self.compare(ComparisonOperator::Contains(Case::Sensitive), text)
+    }
+
+    pub fn starts_with(self, text: &str) -> Condition {
+        self.compare(ComparisonOperator::StartsWith(Case::Sensitive), text)
+    }
+
+    pub fn ends_with(self, text: &str) -> Condition {
+        self.compare(ComparisonOperator::EndsWith(Case::Sensitive), text)
+    }
+
+    /// Column matches a pattern where `%` stands for any run of
+    /// characters, `_` for any single one and `\` escapes either.
+    pub fn like(self, pattern: &str) -> Condition {
+        self.compare(ComparisonOperator::Like(Case::Sensitive), pattern)
+    }
+
+    /// Column has no value (`is empty`).
+    pub fn empty(self) -> Condition {
+        self.compare(ComparisonOperator::Equal, Literal::Null)
+    }
+
+    pub fn not_empty(self) -> Condition {
+        self.compare(ComparisonOperator::NotEqual, Literal::Null)
+    }
+
+    /// Column is equal to any of the values (`is one of`).
+    pub fn one_of<T: Into<Literal>>(self, values: impl IntoIterator<Item = T>) -> Condition {
+        Condition::OneOf(
+            self.name,
+            values.into_iter().map(Into::into).collect(),
+            false,
+        )
+    }
+
+    /// Column is equal to none of the values (`is not one of`).
+    pub fn not_one_of<T: Into<Literal>>(self, values: impl IntoIterator<Item = T>) -> Condition {
+        Condition::OneOf(
+            self.name,
+            values.into_iter().map(Into::into).collect(),
+            true,
+        )
+    }
+
+    /// Column is within the range, both ends included (`is between`).
+    pub fn between(self, low: impl Into<Literal>, high: impl Into<Literal>) -> Condition {
+        Condition::Between(self.name, low.into(), high.into(), false)
+    }
+
+    pub fn not_between(self, low: impl Into<Literal>, high: impl Into<Literal>) -> Condition {
+        Condition::Between(self.name, low.into(), high.into(), true)
+    }
+}
+
+/// One side of a comparison, a column or value with any arithmetic
+/// done on it. Built from columns and values with `+`, `-`, `*` and `/`.
+///
+/// # Example
+/// ```
+/// use eaql::language::builder::col;
+/// let over_budget = (col("price") * col("quantity")).gt(col("budget") + 5);
+/// ```
+///
+#[derive(Debug, PartialEq, Clone)]
+pub enum Expr {
+    Column(String),
+    Value(Literal),
+    Negate(Box<Expr>),
+    Arithmetic(Box<Expr>, ArithmeticOperator, Box<Expr>),
+}
+
+impl From<Column> for Expr {
+    fn from(value: Column) -> Expr {
+        Expr::Column(value.name)
+    }
+}
+
+impl From<Literal> for Expr {
+    fn from(value: Literal) -> Expr {
+        Expr::Value(value)
+    }
+}
+
+impl From<&str> for Expr {
+    fn from(value: &str) -> Expr {
+        Expr::Value(value.into())
+    }
+}
+
+impl From<String> for Expr {
+    fn from(value: String) -> Expr {
+        Expr::Value(value.into())
+    }
+}
+
+impl From<bool> for Expr {
+    fn from(value: bool) -> Expr {
+        Expr::Value(value.into())
+    }
+}
+
+impl From<i32> for Expr {
+    fn from(value: i32) -> Expr {
+        Expr::Value(value.into())
+    }
+}
+
+impl From<i64> for Expr {
+    fn from(value: i64) -> Expr {
+        Expr::Value(value.into())
+    }
+}
+
+impl From<f64> for Expr {
+    fn from(value: f64) -> Expr {
+        Expr::Value(value.into())
+    }
+}
+
+impl Expr {
+    /// A column compared against a value on its own is kept as the
+    /// plain comparison the parser would have produced for it.
+    pub(crate) fn compare(self, operator: ComparisonOperator, other: impl Into<Expr>) -> Condition {
+        match (self, other.into()) {
+            (Expr::Column(column), Expr::Value(value)) => {
+                Condition::Compare(column, operator, value)
+            }
+            (left, right) => Condition::Arithmetic(left, operator, right),
+        }
+    }
+
+    pub fn eq(self, other: impl Into<Expr>) -> Condition {
+        self.compare(ComparisonOperator::Equal, other)
+    }
+
+    pub fn ne(self, other: impl Into<Expr>) -> Condition {
+        self.compare(ComparisonOperator::NotEqual, other)
+    }
+
+    pub fn lt(self, other: impl Into<Expr>) -> Condition {
+        self.compare(ComparisonOperator::LessThan, other)
+    }
+
+    pub fn lte(self, other: impl Into<Expr>) -> Condition {
+        self.compare(ComparisonOperator::LessThanOrEqual, other)
+    }
+
+    pub fn gt(self, other: impl Into<Expr>) -> Condition {
+        self.compare(ComparisonOperator::GreaterThan, other)
+    }
+
+    pub fn gte(self, other: impl Into<Expr>) -> Condition {
+        self.compare(ComparisonOperator::GreaterThanOrEqual, other)
+    }
+
+    fn arithmetic(self, operator: ArithmeticOperator, other: impl Into<Expr>) -> Expr {
+        Expr::Arithmetic(Box::new(self), operator, Box::new(other.into()))
+    }
+
+    /// How tightly the side binds when written out, anything but
+    /// arithmetic binds tighter than every operator.
+    fn binding(&self) -> u8 {
+        match self {
+            Expr::Arithmetic(_, operator, _) => operator.binding(),
+            _ => 2,
+        }
+    }
+
+    /// Writes the side out the way it would appear in a query, with
+    /// the same parentheses the parsed side would be written with.
+    fn literal(&self) -> String {
+        let wrap = |child: &Expr, grouped: bool| -> String {
+            match grouped {
+                true => format!("({})", child.literal()),
+                false => child.literal(),
+            }
+        };
+
+        match self {
+            Expr::Column(column) => column.to_string(),
+            Expr::Value(value) => literal(value),
+            // Two minuses in a row would start a comment in SQL
+            Expr::Negate(child) => format!(
+                "-{}",
+                wrap(
+                    child,
+                    child.binding() < 2 || child.literal().starts_with('-')
+                )
+            ),
+            Expr::Arithmetic(left, operator, right) => format!(
+                "{} {} {}",
+                wrap(left, left.binding() < operator.binding()),
+                operator.symbol(),
+                wrap(right, right.binding() <= operator.binding())
+            ),
+        }
+    }
+
+    /// Converts the side into the one the parser would have produced.
+    fn into_child(self) -> Result<ArithmeticChild, EaqlError> {
+        match self {
+            Expr::Column(column) => Ok(ArithmeticChild::Column(Token::new(
+                TokenType::Identifier,
+                &identifier(&column)?,
+                &column,
+            ))),
+            Expr::Value(value) => Ok(ArithmeticChild::Value(plain_value(value)?)),
+            Expr::Negate(child) => Ok(ArithmeticChild::Negate(Box::new(NegateNode::new(
+                Token::new(TokenType::Minus, &"".to_string(), &"-".to_string()),
+                child.into_child()?,
+            )))),
+            Expr::Arithmetic(left, operator, right) => {
+                Ok(ArithmeticChild::Op(Box::new(ArithmeticNode::new(
+                    Token::new(
+                        arithmetic_type(operator),
+                        &"".to_string(),
+                        &operator.symbol().to_string(),
+                    ),
+                    left.into_child()?,
+                    right.into_child()?,
+                ))))
+            }
+        }
+    }
+}
+
+impl<T: Into<Expr>> ops::Add<T> for Expr {
+    type Output = Expr;
+
+    fn add(self, other: T) -> Expr {
+        self.arithmetic(ArithmeticOperator::Add, other)
+    }
+}
+
+impl<T: Into<Expr>> ops::Sub<T> for Expr {
+    type Output = Expr;
+
+    fn sub(self, other: T) -> Expr {
+        self.arithmetic(ArithmeticOperator::Subtract, other)
+    }
+}
+
+impl<T: Into<Expr>> ops::Mul<T> for Expr {
+    type Output = Expr;
+
+    fn mul(self, other: T) -> Expr {
+        self.arithmetic(ArithmeticOperator::Multiply, other)
+    }
+}
+
+impl<T: Into<Expr>> ops::Div<T> for Expr {
+    type Output = Expr;
+
+    fn div(self, other: T) -> Expr {
+        self.arithmetic(ArithmeticOperator::Divide, other)
+    }
+}
+
+impl ops::Neg for Expr {
+    type Output = Expr;
+
+    fn neg(self) -> Expr {
+        Expr::Negate(Box::new(self))
+    }
+}
+
+impl<T: Into<Expr>> ops::Add<T> for Column {
+    type Output = Expr;
+
+    fn add(self, other: T) -> Expr {
+        Expr::from(self) + other
+    }
+}
+
+impl<T: Into<Expr>> ops::Sub<T> for Column {
+    type Output = Expr;
+
+    fn sub(self, other: T) -> Expr {
+        Expr::from(self) - other
+    }
+}
+
+impl<T: Into<Expr>> ops::Mul<T> for Column {
+    type Output = Expr;
+
+    fn mul(self, other: T) -> Expr {
+        Expr::from(self) * other
+    }
+}
+
+impl<T: Into<Expr>> ops::Div<T> for Column {
+    type Output = Expr;
+
+    fn div(self, other: T) -> Expr {
+        Expr::from(self) / other
+    }
+}
+
+impl ops::Neg for Column {
+    type Output = Expr;
+
+    fn neg(self) -> Expr {
+        -Expr::from(self)
+    }
+}
+
+/// A condition to filter rows by, conditions are combined with
+/// `and`, `or` and negated with `!`.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Condition {
+    Compare(String, ComparisonOperator, Literal),
+    /// Column, the values it's compared against and whether it's negated.
+    OneOf(String, Vec<Literal>, bool),
+    /// Column, both ends of the range and whether it's negated.
+    Between(String, Literal, Literal, bool),
+    /// Comparison where either side is more than a column compared
+    /// against a value, i.e. `price * 2 > cost`.
+    Arithmetic(Expr, ComparisonOperator, Expr),
+    And(Box<Condition>, Box<Condition>),
+    Or(Box<Condition>, Box<Condition>),
+    Not(Box<Condition>),
+}
+
+impl Condition {
+    pub fn and(self, other: Condition) -> Condition {
+        Condition::And(Box::new(self), Box::new(other))
+    }
+
+    pub fn or(self, other: Condition) -> Condition {
+        Condition::Or(Box::new(self), Box::new(other))
+    }
+
+    /// Matches text against the pattern without telling upper and
+    /// lower case apart, any other condition is left as it is.
+    ///
+    /// # Example
+    /// ```
+    /// use eaql::language::builder::col;
+    /// let latte = col("name").contains("latte").ignoring_case();
+    /// ```
+    ///
+    pub fn ignoring_case(self) -> Condition {
+        let Condition::Compare(column, operator, value) = self else {
+            return self;
+        };
+
+        let operator: ComparisonOperator = match operator {
+            ComparisonOperator::Contains(_) => ComparisonOperator::Contains(Case::Insensitive),
+            ComparisonOperator::StartsWith(_) => ComparisonOperator::StartsWith(Case::Insensitive),
+            ComparisonOperator::EndsWith(_) => ComparisonOperator::EndsWith(Case::Insensitive),
+            ComparisonOperator::Like(_) => ComparisonOperator::Like(Case::Insensitive),
+            operator => operator,
+        };
+
+        Condition::Compare(column, operator, value)
+    }
+
+    /// How tightly the condition binds when written out, mirroring
+    /// the precedence used when transpiling.
+    fn binding(&self) -> u8 {
+        match self {
+            Condition::Or(_, _) => 0,
+            Condition::And(_, _) => 1,
+            Condition::Not(_) => 2,
+            Condition::Compare(_, _, _)
+            | Condition::OneOf(_, _, _)
+            | Condition::Between(_, _, _, _)
+            | Condition::Arithmetic(_, _, _) => 3,
+        }
+    }
+
+    /// Writes the condition out the way it would appear in a query,
+    /// parenthesizing children that bind looser than their parent.
+    fn literal(&self) -> String {
+        let wrap = |child: &Condition| -> String {
+            if child.binding() < self.binding() {
+                format!("({})", child.literal())
+            } else {
+                child.literal()
+            }
+        };
+
+        match self {
+            Condition::Compare(column, operator, value) => format!(
+                "{} {} {}{}",
+                column,
+                operator_lexeme(*operator),
+                literal(value),
+                match operator.pattern_case() {
+                    Some(Case::Insensitive) => " ignoring case",
+                    _ => "",
+                }
+            ),
+            Condition::OneOf(column, values, negated) => format!(
+                "{} {} ({})",
+                column,
+                if *negated {
+                    "is not one of"
+                } else {
+                    "is one of"
+                },
+                values
+                    .iter()
+                    .map(literal)
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Condition::Between(column, low, high, negated) => format!(
+                "{} {} {} and {}",
+                column,
+                if *negated {
+                    "is not between"
+                } else {
+                    "is between"
+                },
+                literal(low),
+                literal(high)
+            ),
+            Condition::Arithmetic(left, operator, right) => format!(
+                "{} {} {}",
+                left.literal(),
+                operator_lexeme(*operator),
+                right.literal()
+            ),
+            Condition::And(left, right) => format!("{} and {}", wrap(left), wrap(right)),
+            Condition::Or(left, right) => format!("{} or {}", wrap(left), wrap(right)),
+            Condition::Not(child) => format!("not {}", wrap(child)),
+        }
+    }
+
+    /// Converts the condition into the node the parser would have
+    /// produced for it at the given depth.
+    fn into_child(self, depth: u16) -> Result<ConditionChild, EaqlError> {
+        match self {
+            Condition::Compare(column, operator, value) => {
+                Ok(ConditionChild::Expr(Box::new(ExpressionNode::new(
+                    Token::new(TokenType::Identifier, &identifier(&column)?, &column),
+                    Token::new(
+                        operator_type(operator),
+                        &"".to_string(),
+                        &operator_lexeme(operator).to_string(),
+                    ),
+                    compared_value(operator, value)?,
+                    match operator.pattern_case() {
+                        Some(Case::Insensitive) => Some(Token::new(
+                            TokenType::IgnoreCase,
+                            &"".to_string(),
+                            &"ignoring case".to_string(),
+                        )),
+                        _ => None,
+                    },
+                    depth,
+                ))))
+            }
+            Condition::OneOf(column, values, negated) => {
+                if values.is_empty() {
+                    return Err(EaqlError::InvalidValue(Box::new(ErrorContext::new(format!(
+                        "Query builder was given no values for \"{}\" to be one of",
+                        column
+                    ))))
+                    .with_hint("a list needs at least one value, i.e. `[\"coffee\"]`"));
+                }
+
+                Ok(ConditionChild::List(Box::new(ListNode::new(
+                    Token::new(TokenType::Identifier, &identifier(&column)?, &column),
+                    match negated {
+                        true => Token::new(
+                            TokenType::NotOneOf,
+                            &"".to_string(),
+                            &"is not one of".to_string(),
+                        ),
+                        false => {
+                            Token::new(TokenType::OneOf, &"".to_string(), &"is one of".to_string())
+                        }
+                    },
+                    values
+                        .into_iter()
+                        .map(plain_value)
+                        .collect::<Result<Vec<Token>, EaqlError>>()?,
+                    depth,
+                ))))
+            }
+            Condition::Between(column, low, high, negated) => {
+                Ok(ConditionChild::Range(Box::new(RangeNode::new(
+                    Token::new(TokenType::Identifier, &identifier(&column)?, &column),
+                    match negated {
+                        true => Token::new(
+                            TokenType::NotBetween,
+                            &"".to_string(),
+                            &"is not between".to_string(),
+                        ),
+                        false => Token::new(
+                            TokenType::Between,
+                            &"".to_string(),
+                            &"is between".to_string(),
+                        ),
+                    },
+                    plain_value(low)?,
+                    plain_value(high)?,
+                    depth,
+                ))))
+            }
+            Condition::Arithmetic(left, operator, right) => {
+                if operator.pattern_case().is_some() {
+                    return Err(EaqlError::InvalidValue(Box::new(ErrorContext::new(format!(
+                        "Query builder was given arithmetic to match with \"{}\"",
+                        operator_lexeme(operator)
+                    ))))
+                    .with_hint("only a single column can be matched against a pattern"));
+                }
+
+                Ok(ConditionChild::Compare(Box::new(ComparisonNode::new(
+                    left.into_child()?,
+                    Token::new(
+                        operator_type(operator),
+                        &"".to_string(),
+                        &operator_lexeme(operator).to_string(),
+                    ),
+                    right.into_child()?,
+                    depth,
+                ))))
+            }
+            Condition::And(left, right) => Ok(ConditionChild::Op(Box::new(OperandNode::new(
+                LogicalOperator::And,
+                left.into_child(depth + 1)?,
+                right.into_child(depth + 1)?,
+                depth,
+            )))),
+            Condition::Or(left, right) => Ok(ConditionChild::Op(Box::new(OperandNode::new(
+                LogicalOperator::Or,
+                left.into_child(depth + 1)?,
+                right.into_child(depth + 1)?,
+                depth,
+            )))),
+            Condition::Not(child) => Ok(ConditionChild::Not(Box::new(NotNode::new(
+                child.into_child(depth + 1)?,
+                depth,
+            )))),
+        }
+    }
+}
+
+impl ops::Not for Condition {
+    type Output = Condition;
+
+    fn not(self) -> Condition {
+        Condition::Not(Box::new(self))
+    }
+}
+
+fn operator_type(operator: ComparisonOperator) -> TokenType {
+    match operator {
+        ComparisonOperator::Equal => TokenType::Equal,
+        ComparisonOperator::NotEqual => TokenType::NotEqual,
+        ComparisonOperator::LessThan => TokenType::Lt,
+        ComparisonOperator::LessThanOrEqual => TokenType::Lte,
+        ComparisonOperator::GreaterThan => TokenType::Gt,
+        ComparisonOperator::GreaterThanOrEqual => TokenType::Gte,
+        ComparisonOperator::Contains(_) => TokenType::Contains,
+        ComparisonOperator::StartsWith(_) => TokenType::StartsWith,
+        ComparisonOperator::EndsWith(_) => TokenType::EndsWith,
+        ComparisonOperator::Like(_) => TokenType::Like,
+    }
+}
+
+fn arithmetic_type(operator: ArithmeticOperator) -> TokenType {
+    match operator {
+        ArithmeticOperator::Add => TokenType::Plus,
+        ArithmeticOperator::Subtract => TokenType::Minus,
+        ArithmeticOperator::Multiply => TokenType::Star,
+        ArithmeticOperator::Divide => TokenType::Slash,
+    }
+}
+
+fn operator_lexeme(operator: ComparisonOperator) -> &'static str {
+    match operator {
+        ComparisonOperator::Equal => "=",
+        ComparisonOperator::NotEqual => "!=",
+        ComparisonOperator::LessThan => "<",
+        ComparisonOperator::LessThanOrEqual => "<=",
+        ComparisonOperator::GreaterThan => ">",
+        ComparisonOperator::GreaterThanOrEqual => ">=",
+        ComparisonOperator::Contains(_) => "contains",
+        ComparisonOperator::StartsWith(_) => "starts with",
+        ComparisonOperator::EndsWith(_) => "ends with",
+        ComparisonOperator::Like(_) => "like",
+    }
+}
+
+/// Writes a value the way it's written in a query.
+fn literal(value: &Literal) -> String {
+    match value {
+        Literal::Text(text) => format!("\"{}\"", text),
+        Literal::Number(number) => number.to_string(),
+        Literal::Bool(value) => value.to_string(),
+        Literal::Null => "null".to_string(),
+    }
+}
+
+/// Makes sure a table or column name is one the lexer would read
+/// back as a single identifier, keywords and punctuation aren't.
+fn identifier(name: &str) -> Result<String, EaqlError> {
+    match lexer::scan_tokens(&name.to_string()) {
+        Ok(lexer)
+            if lexer.tokens.len() == 1
+                && lexer.tokens[0].token_type == TokenType::Identifier
+                && lexer.tokens[0].literal == name =>
+        {
+            Ok(name.to_string())
+        }
+        _ => Err(
+            EaqlError::UnexpectedToken(Box::new(ErrorContext::new(format!(
+                "Query builder was given \"{}\", which isn't a valid name",
+                name
+            ))))
+            .with_hint("names are a single word that isn't a keyword, i.e. `price`"),
+        ),
+    }
+}
+
+/// Builds the token a value would have been lexed into.
+fn value_token(value: Literal) -> Result<Token, EaqlError> {
+    match value {
+        Literal::Text(text) if !text.contains('"') => Ok(Token::new(
+            TokenType::StringLiteral,
+            &text,
+            &format!("\"{}\"", text),
+        )),
+        Literal::Number(number)
+            if lexer::scan_tokens(&number).is_ok_and(|lexer| {
+                lexer.tokens.len() == 1
+                    && lexer.tokens[0].token_type == TokenType::NumberLiteral
+                    && lexer.tokens[0].literal == number
+            }) =>
+        {
+            Ok(Token::new(TokenType::NumberLiteral, &number, &number))
+        }
+        Literal::Bool(value) => Ok(Token::new(
+            TokenType::BooleanLiteral,
+            &"".to_string(),
+            &value.to_string(),
+        )),
+        Literal::Null => Ok(Token::new(
+            TokenType::NullLiteral,
+            &"".to_string(),
+            &"null".to_string(),
+        )),
+        Literal::Text(value) | Literal::Number(value) => {
+            Err(EaqlError::InvalidValue(Box::new(ErrorContext::new(format!(
+                "Query builder was given the value {}, which can't be written in a query",
+                value
+            ))))
+            .with_hint("text can't contain `\"` and numbers must be finite"))
+        }
+    }
+}
+
+/// Builds the token of a value compared with the operator, only text
+/// can be matched against a pattern and only `is` or `is not` compare
+/// against true, false or null.
+fn compared_value(operator: ComparisonOperator, value: Literal) -> Result<Token, EaqlError> {
+    match (operator.pattern_case(), value) {
+        (_, value @ (Literal::Bool(_) | Literal::Null))
+            if !matches!(
+                operator,
+                ComparisonOperator::Equal | ComparisonOperator::NotEqual
+            ) =>
+        {
+            Err(EaqlError::InvalidValue(Box::new(ErrorContext::new(format!(
+                "Query builder was given {} to compare with \"{}\"",
+                literal(&value),
+                operator_lexeme(operator)
+            ))))
+            .with_hint("`true`, `false` and `null` are compared with `eq` or `ne`"))
+        }
+        (Some(_), Literal::Number(number)) => {
+            Err(EaqlError::InvalidValue(Box::new(ErrorContext::new(format!(
+                "Query builder was given the number {} to match with \"{}\"",
+                number,
+                operator_lexeme(operator)
+            ))))
+            .with_hint("only text can be matched against a pattern"))
+        }
+        (_, value) => value_token(value),
+    }
+}
+
+/// Builds the token of a value in a list, range or arithmetic, which
+/// are only ever text or numbers.
+fn plain_value(value: Literal) -> Result<Token, EaqlError> {
+    match value {
+        Literal::Bool(_) | Literal::Null => {
+            Err(EaqlError::InvalidValue(Box::new(ErrorContext::new(format!(
+                "Query builder was given {} in a list, range or arithmetic",
+                literal(&value)
+            ))))
+            .with_hint("lists, ranges and arithmetic only hold text and numbers"))
+        }
+        value => value_token(value),
+    }
+}
+
+/// Fluent builder for `get` queries, started with `Query::get` or
+/// `Query::get_all` and finished with `build`.
+#[derive(Debug, Clone)]
+pub struct GetBuilder {
+    // None retrieves every column
+    columns: Option<Vec<String>>,
+    table: Option<String>,
+    filter: Option<Condition>,
+    sort: Vec<(String, SortDirection)>,
+    limit: Option<i32>,
+}
+
+impl Query {
+    /// Starts building a query retrieving the given columns.
+    ///
+    /// # Example
+    /// ```
+    /// use eaql::language::{ast::Query, builder::col};
+    /// use eaql::transpiler::dialect::Generic;
+    ///
+    /// let query = Query::get(["name", "price"])
+    ///     .from("drinks")
+    ///     .filter(col("price").lt(5))
+    ///     .limit(10)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     query.transpile_raw(&Generic).unwrap(),
+    ///     "SELECT name, price FROM drinks WHERE price < 5 LIMIT 10"
+    /// );
+    /// ```
+    ///
+    pub fn get<I, S>(columns: I) -> GetBuilder
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        GetBuilder {
+            columns: Some(
+                columns
+                    .into_iter()
+                    .map(|v| v.as_ref().to_string())
+                    .collect(),
+            ),
+            ..Query::get_all()
+        }
+    }
+
+    /// Starts building a query retrieving every column.
+    pub fn get_all() -> GetBuilder {
+        GetBuilder {
+            columns: None,
+            table: None,
+            filter: None,
+            sort: vec![],
+            limit: None,
+        }
+    }
+
+    /// Builds a query creating a database.
+    pub fn create_database(name: &str) -> Result<Query, EaqlError> {
+        let mut database: DatabaseNode = DatabaseNode::new("create database".to_string(), 1);
+
+        database._create = Some(CreateNode::new(&identifier(name)?, 2));
+
+        Ok(Query::new(Statement::Database(database), 0))
+    }
+
+    /// Builds a query deleting every one of the given databases.
+    pub fn delete_databases<I, S>(names: I) -> Result<Query, EaqlError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let names: Vec<String> = names
+            .into_iter()
+            .map(|v| identifier(v.as_ref()))
+            .collect::<Result<Vec<String>, EaqlError>>()?;
+
+        if names.is_empty() {
+            return Err(EaqlError::UnexpectedEnd(Box::new(ErrorContext::new(
+                "Query builder needs at least one database to delete".to_string(),
+            ))));
+        }
+
+        let mut database: DatabaseNode = DatabaseNode::new("delete databases".to_string(), 1);
+
+        database._destroy = Some(DestroyNode::new(names.clone(), names.join(", "), 2));
+
+        Ok(Query::new(Statement::Database(database), 0))
+    }
+
+    /// Builds a query switching to a database.
+    pub fn use_database(name: &str) -> Result<Query, EaqlError> {
+        let mut database: DatabaseNode = DatabaseNode::new("use database".to_string(), 1);
+
+        database._use = Some(UseNode::new(&identifier(name)?, 2));
+
+        Ok(Query::new(Statement::Database(database), 0))
+    }
+
+    /// Builds a query listing every database.
+    pub fn show_databases() -> Query {
+        let mut database: DatabaseNode = DatabaseNode::new("show databases".to_string(), 1);
+
+        database._show = Some(ShowNode::new(2));
+
+        Query::new(Statement::Database(database), 0)
+    }
+}
+
+impl GetBuilder {
+    /// Table to retrieve from, required.
+    pub fn from(mut self, table: &str) -> GetBuilder {
+        self.table = Some(table.to_string());
+        self
+    }
+
+    /// Only retrieve rows matching the condition, filtering more
+    /// than once requires every condition to match.
+    pub fn filter(mut self, condition: Condition) -> GetBuilder {
+        self.filter = Some(match self.filter {
+            Some(filter) => filter.and(condition),
+            None => condition,
+        });
+        self
+    }
+
+    /// Sorts by the column, sorting more than once breaks ties with
+    /// the later columns.
+    pub fn sort_by(mut self, column: &str, direction: SortDirection) -> GetBuilder {
+        self.sort.push((column.to_string(), direction));
+        self
+    }
+
+    pub fn limit(mut self, limit: i32) -> GetBuilder {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Assembles the query, failing if no table or columns were given
+    /// or a name or value couldn't have been written in a query.
+    pub fn build(self) -> Result<Query, EaqlError> {
+        let Some(table) = self.table else {
+            return Err(EaqlError::UnexpectedEnd(Box::new(ErrorContext::new(
+                "Query builder needs a table to retrieve from".to_string(),
+            )))
+            .with_hint("call `from`, i.e. `Query::get([\"name\"]).from(\"drinks\")`"));
+        };
+
+        let columns: ColumnNode = match self.columns {
+            None => ColumnNode::new(vec![], "get all".to_string(), 2),
+            Some(columns) if columns.is_empty() => {
+                return Err(EaqlError::UnexpectedEnd(Box::new(ErrorContext::new(
+                    "Query builder needs at least one column to retrieve".to_string(),
+                )))
+                .with_hint("name a column, or call `Query::get_all` to retrieve every column"));
+            }
+            Some(columns) => ColumnNode::new(
+                columns
+                    .iter()
+                    .map(|v| identifier(v))
+                    .collect::<Result<Vec<String>, EaqlError>>()?,
+                format!("get {}", columns.join(", ")),
+                2,
+            ),
+        };
+
+        let table: TableNode = TableNode::new(&identifier(&table)?, format!("from {}", table), 2);
+
+        let filter: Option<FilterNode> = match self.filter {
+            Some(condition) => {
+                let literal: String = condition.literal();
+
+                Some(FilterNode::new(
+                    ConditionNode::new(condition.into_child(4)?, literal, 3),
+                    "where".to_string(),
+                    2,
+                ))
+            }
+            None => None,
+        };
+
+        let sort: Option<SortNode> = match self.sort.is_empty() {
+            true => None,
+            false => Some(SortNode::new(
+                self.sort
+                    .iter()
+                    .map(|(column, direction)| {
+                        Ok(SortKeyNode::new(&identifier(column)?, *direction, 4))
+                    })
+                    .collect::<Result<Vec<SortKeyNode>, EaqlError>>()?,
+                format!(
+                    "sort by {}",
+                    self.sort
+                        .iter()
+                        .map(|(column, direction)| match direction {
+                            SortDirection::Ascending => column.to_string(),
+                            SortDirection::Descending => format!("{} descending", column),
+                        })
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ),
+                3,
+            )),
+        };
+
+        let limit: Option<LimitNode> = self
+            .limit
+            .map(|limit| LimitNode::new(limit, format!("limit {}", limit), 3));
+
+        let postprocessor: Option<PostProcessorNode> = match (&limit, &sort) {
+            (None, None) => None,
+            _ => Some(PostProcessorNode::new(limit, sort, 2)),
+        };
+
+        Ok(Query::new(
+            Statement::Get(GetNode::new(columns, table, filter, postprocessor, 1)),
+            0,
+        ))
+    }
+}
+
+// Begin Builder Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_test_condition_literal() {
+        let condition: Condition = (!col("a").eq("x").or(col("b").lt(1))).and(col("c").gte(2.5));
+
+        assert_eq!(
+            condition.literal(),
+            "not (a = \"x\" or b < 1) and c >= 2.5".to_string()
+        );
+    }
+
+    #[test]
+    fn unit_test_condition_depths() {
+        let ConditionChild::Op(and) = col("a").eq(1).and(!col("b").eq(2)).into_child(4).unwrap()
+        else {
+            panic!("Expected an operand at the root of the condition");
+        };
+        let ConditionChild::Not(not) = and.right() else {
+            panic!("Expected a negation on the right");
+        };
+        let ConditionChild::Expr(expression) = not.child() else {
+            panic!("Expected an expression under the negation");
+        };
+
+        assert_eq!(and.depth(), 4);
+        assert_eq!(not.depth(), 5);
+        assert_eq!(expression.depth(), 6);
+    }
+
+    #[test]
+    fn unit_test_condition_arithmetic() {
+        let condition: Condition = (col("a") - (col("b") - 1) * -col("c")).gt(-(-Expr::from(2)));
+
+        assert_eq!(condition.literal(), "a - (b - 1) * -c > -(-2)".to_string());
+
+        // A column compared against a value stays a plain comparison
+        assert_eq!(col("a").eq(1), Expr::from(col("a")).eq(1));
+        assert!(matches!(
+            col("a").lt(col("b")).into_child(4),
+            Ok(ConditionChild::Compare(_))
+        ));
+        assert!(
+            Expr::from(Literal::Null)
+                .eq(col("a"))
+                .into_child(4)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn unit_test_identifier() {
+        assert!(identifier("price").is_ok());
+        assert!(identifier("where").is_err());
+        assert!(identifier("price;").is_err());
+        assert!(identifier("").is_err());
+    }
+}
@@ -0,0 +1,293 @@
+/*
+Rendering errors for people
+
+Turns an EaqlError or Warning into a rustc-style report: the message,
+the line of the query it happened on with the offending part underlined,
+a note on what was expected there and, when the offending word looks
+like a misspelled keyword, a "did you mean" suggestion.
+*/
+
+use crate::{
+    language::{
+        errors::{EaqlError, ErrorContext, Warning},
+        tokens::{Position, SYSTEM_KEYWORDS, TokenType},
+    },
+    utils::colors::{AnsiColor, colorize},
+};
+
+/// Outputs the error rendered against the query it came from.
+///
+/// # Example
+/// ```
+/// use eaql::{language::diagnostics::render, validator::engine};
+/// let query: &str = "retreive name from drinks;";
+/// assert_eq!(
+///     render(&engine(query).unwrap_err(), query),
+///     "error: Couldn't determine requested action. Please review documentation for valid query actions.
+///  --> query:1:1
+///   |
+/// 1 | retreive name from drinks;
+///   | ^^^^^^^^
+///   |
+///   = note: queries start with an action like `get`, `create`, `delete`, `use` or `show`
+///   = help: did you mean `retrieve`?"
+/// );
+/// ```
+///
+pub fn render(error: &EaqlError, query: &str) -> String {
+    build(
+        ("error", AnsiColor::BrightRed),
+        error.context(),
+        suggestion(error),
+        query,
+        &|text, _| text.to_string(),
+    )
+}
+
+/// Outputs the error rendered against the query it came from, color
+/// coded for the REPLs.
+pub fn render_color(error: &EaqlError, query: &str) -> String {
+    build(
+        ("error", AnsiColor::BrightRed),
+        error.context(),
+        suggestion(error),
+        query,
+        &|text, color| colorize(text, color),
+    )
+}
+
+/// Outputs the warning rendered against the query it came from.
+pub fn render_warning(warning: &Warning, query: &str) -> String {
+    build(
+        ("warning", AnsiColor::BrightYellow),
+        warning.context(),
+        None,
+        query,
+        &|text, _| text.to_string(),
+    )
+}
+
+/// Outputs the warning rendered against the query it came from, color
+/// coded for the REPLs.
+pub fn render_warning_color(warning: &Warning, query: &str) -> String {
+    build(
+        ("warning", AnsiColor::BrightYellow),
+        warning.context(),
+        None,
+        query,
+        &|text, color| colorize(text, color),
+    )
+}
+
+/// Suggestion for the token that caused the error, if it looks like
+/// a misspelled keyword.
+pub fn suggestion(error: &EaqlError) -> Option<String> {
+    let token = error.token()?;
+
+    if !matches!(
+        error,
+        EaqlError::UnexpectedToken(_) | EaqlError::UnknownAction(_)
+    ) || token.token_type != TokenType::Identifier
+    {
+        return None;
+    }
+
+    closest_keyword(&token.lexeme).map(|keyword| format!("did you mean `{}`?", keyword))
+}
+
+/// Finds the keyword closest to the given word, as long as it's close
+/// enough to pass for a typo of it.
+pub fn closest_keyword(word: &str) -> Option<&'static str> {
+    let word: String = word.to_lowercase();
+    let allowed: usize = if word.chars().count() <= 4 { 1 } else { 2 };
+
+    SYSTEM_KEYWORDS
+        .iter()
+        .filter(|(_, token_type)| **token_type != TokenType::NullToken)
+        .map(|(keyword, _)| (edit_distance(&word, keyword), *keyword))
+        .filter(|(distance, _)| *distance > 0 && *distance <= allowed)
+        .min()
+        .map(|(_, keyword)| keyword)
+}
+
+// Number of single character insertions, deletions, substitutions and
+// swaps of neighbouring characters needed to turn one word into another
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut table: Vec<Vec<usize>> = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in table.iter_mut().enumerate() {
+        row[0] = i;
+    }
+
+    table[0] = (0..=b.len()).collect();
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost: usize = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+
+            table[i][j] = (table[i - 1][j] + 1)
+                .min(table[i][j - 1] + 1)
+                .min(table[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                table[i][j] = table[i][j].min(table[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    table[a.len()][b.len()]
+}
+
+fn build(
+    label: (&str, AnsiColor),
+    context: &ErrorContext,
+    suggestion: Option<String>,
+    query: &str,
+    paint: &dyn Fn(&str, AnsiColor) -> String,
+) -> String {
+    let mut lines: Vec<String> = vec![format!("{}: {}", paint(label.0, label.1), context.message)];
+    let mut notes: Vec<String> = vec![];
+
+    if let Some(hint) = &context.hint {
+        notes.push(format!(
+            "{}: {}",
+            paint("note", AnsiColor::BrightBlue),
+            hint
+        ));
+    }
+
+    if let Some(suggestion) = suggestion {
+        notes.push(format!(
+            "{}: {}",
+            paint("help", AnsiColor::BrightGreen),
+            suggestion
+        ));
+    }
+
+    let Some((position, width)) = locate(context, query) else {
+        lines.extend(notes.iter().map(|v| format!("  = {}", v)));
+        return lines.join("\n");
+    };
+
+    let source: &str = query
+        .split('\n')
+        .nth(position.line - 1)
+        .unwrap_or("")
+        .trim_end_matches('\r');
+    let line_number: String = position.line.to_string();
+    let gutter: String = " ".repeat(line_number.len());
+    let bar: String = paint("|", AnsiColor::BrightBlue);
+
+    // Tabs are kept so the underline lines up however they're displayed
+    let padding: String = source
+        .chars()
+        .take(position.column - 1)
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+
+    lines.push(format!(
+        "{} query:{}:{}",
+        paint(&format!("{}-->", gutter), AnsiColor::BrightBlue),
+        position.line,
+        position.column
+    ));
+    lines.push(format!("{} {}", gutter, bar));
+    lines.push(format!(
+        "{} {} {}",
+        paint(&line_number, AnsiColor::BrightBlue),
+        bar,
+        source
+    ));
+    lines.push(format!(
+        "{} {} {}{}",
+        gutter,
+        bar,
+        padding,
+        paint(&"^".repeat(width), label.1)
+    ));
+
+    if !notes.is_empty() {
+        lines.push(format!("{} {}", gutter, bar));
+        lines.extend(
+            notes
+                .iter()
+                .map(|v| format!("{} {} {}", gutter, paint("=", AnsiColor::BrightBlue), v)),
+        );
+    }
+
+    lines.join("\n")
+}
+
+// Where the underline starts and how many characters it covers, kept
+// to the line the error starts on
+fn locate(context: &ErrorContext, query: &str) -> Option<(Position, usize)> {
+    let Some(span) = context.span else {
+        return context.position.map(|position| (position, 1));
+    };
+
+    let position: Position = Position::default().advance(query.get(..span.start)?);
+    let width: usize = query
+        .get(span.start..span.end)?
+        .chars()
+        .take_while(|c| *c != '\n')
+        .count();
+
+    Some((position, width.max(1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::tokens::Span;
+
+    #[test]
+    fn unit_test_closest_keyword() {
+        assert_eq!(closest_keyword("retreive"), Some("retrieve"));
+        assert_eq!(closest_keyword("frm"), Some("from"));
+        assert_eq!(closest_keyword("WHRE"), Some("where"));
+        assert_eq!(closest_keyword("drinks"), None);
+        assert_eq!(closest_keyword("from"), None);
+    }
+
+    #[test]
+    fn unit_test_render_multiline() {
+        let query: &str = "get name\nfrom drinks\nwhere price >> 5;";
+        let mut context: ErrorContext = ErrorContext::new("Bad comparison".to_string());
+        context.span = Some(Span::new(33, 35));
+
+        assert_eq!(
+            render(&EaqlError::UnexpectedToken(Box::new(context)), query),
+            "error: Bad comparison
+ --> query:3:13
+  |
+3 | where price >> 5;
+  |             ^^"
+        );
+    }
+
+    #[test]
+    fn unit_test_render_without_location() {
+        let error: EaqlError = EaqlError::Unsupported(Box::new(
+            ErrorContext::new("Not here".to_string()).with_hint("try again"),
+        ));
+
+        assert_eq!(render(&error, ""), "error: Not here\n  = note: try again");
+    }
+
+    #[test]
+    fn unit_test_render_warning() {
+        let query: &str = "get all from drinks then limit it to 0;";
+        let warning: Warning = Warning::new("Nothing".to_string(), Span::new(25, 38));
+
+        assert_eq!(
+            render_warning(&warning, query),
+            "warning: Nothing
+ --> query:1:26
+  |
+1 | get all from drinks then limit it to 0;
+  |                          ^^^^^^^^^^^^^"
+        );
+    }
+}
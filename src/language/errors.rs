@@ -0,0 +1,211 @@
+/*
+Errors raised while processing a query
+
+Each kind of error is its own variant so callers can tell them
+apart, while the context keeps the human friendly message along
+with where in the query things went wrong.
+*/
+
+use crate::language::tokens::{Position, Span, Token};
+use std::fmt;
+
+/// What went wrong and where, shared by every kind of error.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ErrorContext {
+    pub message: String,
+    pub token: Option<Token>,
+    pub span: Option<Span>,
+    pub position: Option<Position>,
+    pub hint: Option<String>,
+}
+
+/// The context is boxed to keep errors, and every `Result` holding one,
+/// small on the happy path.
+#[derive(Debug, PartialEq, Clone)]
+pub enum EaqlError {
+    /// Part of the query isn't a valid token (i.e. an unterminated string).
+    UnknownToken(Box<ErrorContext>),
+    /// The query isn't terminated with `;`, `.` or `!`.
+    MissingEndOfQuery(Box<ErrorContext>),
+    /// The query ended while more of it was expected.
+    UnexpectedEnd(Box<ErrorContext>),
+    /// A token was found where the grammar doesn't allow it.
+    UnexpectedToken(Box<ErrorContext>),
+    /// The query doesn't start with a known action (i.e. `get`).
+    UnknownAction(Box<ErrorContext>),
+    /// Parentheses closed without being opened or left unclosed.
+    UnbalancedParen(Box<ErrorContext>),
+    /// A filter without any conditions.
+    EmptyCondition(Box<ErrorContext>),
+    /// A limit that isn't a whole number.
+    InvalidLimit(Box<ErrorContext>),
+    /// A sort repeated or given an unknown direction.
+    InvalidSort(Box<ErrorContext>),
+    /// A value or name that can't be safely put in the SQL.
+    InvalidValue(Box<ErrorContext>),
+    /// The targeted dialect has no way of expressing the query.
+    Unsupported(Box<ErrorContext>),
+}
+
+/// Something that doesn't stop a query from working, but probably
+/// isn't what was meant (i.e. limiting the output to 0 rows).
+#[derive(Debug, PartialEq, Clone)]
+pub struct Warning {
+    context: ErrorContext,
+}
+
+impl ErrorContext {
+    /// Context for errors that can't be pinned to a token.
+    pub fn new(message: String) -> ErrorContext {
+        ErrorContext {
+            message,
+            token: None,
+            span: None,
+            position: None,
+            hint: None,
+        }
+    }
+
+    /// Context for errors caused by the given token.
+    pub fn at(message: String, token: &Token) -> ErrorContext {
+        ErrorContext {
+            message,
+            token: Some(token.clone()),
+            span: Some(token.span),
+            position: Some(token.position),
+            hint: None,
+        }
+    }
+
+    /// Context for errors found right after the given token, used
+    /// when something is missing rather than wrong.
+    pub fn after(message: String, token: &Token) -> ErrorContext {
+        ErrorContext {
+            message,
+            token: Some(token.clone()),
+            span: Some(Span::new(token.span.end, token.span.end)),
+            position: Some(token.position.advance(&token.lexeme)),
+            hint: None,
+        }
+    }
+
+    /// Attaches a short explanation of what was expected instead.
+    pub fn with_hint(mut self, hint: &str) -> ErrorContext {
+        self.hint = Some(hint.to_string());
+        self
+    }
+}
+
+impl EaqlError {
+    pub fn context(&self) -> &ErrorContext {
+        match self {
+            EaqlError::UnknownToken(context)
+            | EaqlError::MissingEndOfQuery(context)
+            | EaqlError::UnexpectedEnd(context)
+            | EaqlError::UnexpectedToken(context)
+            | EaqlError::UnknownAction(context)
+            | EaqlError::UnbalancedParen(context)
+            | EaqlError::EmptyCondition(context)
+            | EaqlError::InvalidLimit(context)
+            | EaqlError::InvalidSort(context)
+            | EaqlError::InvalidValue(context)
+            | EaqlError::Unsupported(context) => context,
+        }
+    }
+
+    fn context_mut(&mut self) -> &mut ErrorContext {
+        match self {
+            EaqlError::UnknownToken(context)
+            | EaqlError::MissingEndOfQuery(context)
+            | EaqlError::UnexpectedEnd(context)
+            | EaqlError::UnexpectedToken(context)
+            | EaqlError::UnknownAction(context)
+            | EaqlError::UnbalancedParen(context)
+            | EaqlError::EmptyCondition(context)
+            | EaqlError::InvalidLimit(context)
+            | EaqlError::InvalidSort(context)
+            | EaqlError::InvalidValue(context)
+            | EaqlError::Unsupported(context) => context,
+        }
+    }
+
+    /// Attaches a short explanation of what was expected instead.
+    pub fn with_hint(mut self, hint: &str) -> EaqlError {
+        self.context_mut().hint = Some(hint.to_string());
+        self
+    }
+
+    /// Human friendly description of the error.
+    pub fn message(&self) -> &str {
+        &self.context().message
+    }
+
+    /// The token that caused the error, if there was one.
+    pub fn token(&self) -> Option<&Token> {
+        self.context().token.as_ref()
+    }
+
+    /// Byte offsets of the error within the original query.
+    pub fn span(&self) -> Option<Span> {
+        self.context().span
+    }
+
+    /// Line and column the error starts at within the original query.
+    pub fn position(&self) -> Option<Position> {
+        self.context().position
+    }
+
+    /// What the query was expected to look like where it went wrong.
+    pub fn hint(&self) -> Option<&str> {
+        self.context().hint.as_deref()
+    }
+}
+
+impl Warning {
+    /// Warning about the part of the query covered by the span.
+    pub fn new(message: String, span: Span) -> Warning {
+        let mut context: ErrorContext = ErrorContext::new(message);
+        context.span = Some(span);
+
+        Warning { context }
+    }
+
+    pub fn context(&self) -> &ErrorContext {
+        &self.context
+    }
+
+    /// Human friendly description of the warning.
+    pub fn message(&self) -> &str {
+        &self.context.message
+    }
+
+    /// Byte offsets of the warning within the original query.
+    pub fn span(&self) -> Option<Span> {
+        self.context.span
+    }
+
+    /// Suggestion for what was probably meant instead.
+    pub fn hint(&self) -> Option<&str> {
+        self.context.hint.as_deref()
+    }
+
+    /// Attaches a suggestion for what was probably meant instead.
+    pub fn with_hint(mut self, hint: &str) -> Warning {
+        self.context.hint = Some(hint.to_string());
+        self
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl fmt::Display for EaqlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl std::error::Error for EaqlError {}
@@ -1,8 +1,14 @@
 use crate::language::tokens::{
-    IDENTIFER_STOPS, SINGLE_DOUBLE_START_TOKENS, SINGLE_START_TOKENS, SYSTEM_KEYWORDS, Token,
-    TokenType,
+    ARITHMETIC_PHRASES, COMPARISON_PHRASES, IDENTIFER_STOPS, MODIFIER_PHRASES, Position,
+    SINGLE_DOUBLE_START_TOKENS, SINGLE_START_TOKENS, SYSTEM_KEYWORDS, Span, Token, TokenType,
 };
-use std::fmt;
+use std::{fmt, iter::Peekable, str::CharIndices};
+
+/// Characters of the query yet to be lexed, alongside their byte
+/// offsets. Everything is read through this in a single pass, so
+/// lexing takes time linear in the length of the query and never
+/// splits a multi-byte character.
+type Cursor<'q> = Peekable<CharIndices<'q>>;
 
 #[derive(Debug)]
 pub struct Lexer {
@@ -10,251 +16,314 @@ pub struct Lexer {
 }
 
 impl Lexer {
-    // Look ahead for one token
-    fn peek_one(query: &String, current: &usize) -> Option<char> {
-        if current + 1 >= query.len() {
-            return None;
-        }
-
-        return query.chars().nth(*current + 1);
+    // Byte offset of the next character, the end of the query once
+    // everything has been read
+    fn offset(query: &str, chars: &mut Cursor) -> usize {
+        chars.peek().map_or(query.len(), |(idx, _)| *idx)
     }
 
-    fn peek_decimal(query: &String, start: &mut usize, current: &mut usize) {
-        loop {
-            if *current >= query.len() {
-                // Fix edge case where query ends with decimal
-                // (i.e. 2. becomes a number literal but should be a number and eoq token)
-                if *current > 0 {
-                    let prev = query.as_bytes()[*current - 1];
-
-                    if matches!(prev, b'.' | b'!' | b';') {
-                        *current -= 1;
-                    }
-                }
-
-                return;
-            }
-
-            *current += 1;
-
-            let slice = &query.as_bytes()[*start..*current];
-            let s = match std::str::from_utf8(slice) {
-                Ok(s) => s,
-                Err(_) => {
-                    *current -= 1;
-                    return;
-                }
-            };
+    // Look ahead for the character after the next one
+    fn peek_one(chars: &Cursor) -> Option<char> {
+        chars.clone().nth(1).map(|(_, c)| c)
+    }
 
-            if s.parse::<f64>().is_err() {
-                *current -= 1;
-                return;
-            }
-        }
+    fn take_while(chars: &mut Cursor, predicate: impl Fn(char) -> bool) {
+        while chars.next_if(|(_, c)| predicate(*c)).is_some() {}
     }
 
-    fn peek_string(query: &String, current: &mut usize, token_type: &mut TokenType) -> () {
-        loop {
-            if *current + 1 >= query.len() {
-                *token_type = TokenType::UnknownToken;
-                return;
-            }
+    fn is_identifier_stop(c: char) -> bool {
+        IDENTIFER_STOPS.contains(&c) || c.is_whitespace()
+    }
 
-            *current += 1;
+    fn peek_decimal(chars: &mut Cursor) {
+        Lexer::take_while(chars, |c| c.is_ascii_digit());
 
-            if query.chars().nth(*current).unwrap() == '\"' {
-                return;
-            }
+        // A '.' is only part of the number when digits follow it, otherwise
+        // it ends the query (i.e. `limit it to 2.`)
+        if chars.peek().is_some_and(|(_, c)| *c == '.')
+            && Lexer::peek_one(chars).is_some_and(|c| c.is_ascii_digit())
+        {
+            chars.next();
+            Lexer::take_while(chars, |c| c.is_ascii_digit());
         }
     }
 
-    fn peek_identifier(
-        query: &String,
-        start: &mut usize,
-        current: &mut usize,
-        token_type: &mut TokenType,
-    ) -> () {
-        loop {
-            if *current >= query.len() {
-                return;
-            }
-
-            if IDENTIFER_STOPS.contains(&query.chars().nth(*current).unwrap()) {
-                let tmp = &query[*start..*current];
+    // Reads up to and including the closing quote, returns whether
+    // there was one
+    fn peek_string(chars: &mut Cursor) -> bool {
+        chars.next();
 
-                if let Some(keyword_token) = SYSTEM_KEYWORDS.get(tmp.to_lowercase().as_str()) {
-                    *token_type = keyword_token.clone();
-                }
-
-                return;
-            }
+        chars.any(|(_, c)| c == '\"')
+    }
 
-            *current += 1;
-        }
+    fn peek_identifier(chars: &mut Cursor) {
+        Lexer::take_while(chars, |c| !Lexer::is_identifier_stop(c));
     }
 
-    fn handle_single_token(_query: &String, c: char, current: &mut usize) -> Result<Token, String> {
+    fn handle_single_token(chars: &mut Cursor, c: char) -> Token {
         let token_type: TokenType = match c {
             n if [';', '!', '.'].contains(&n) => TokenType::EoqToken,
             ')' => TokenType::CloseParen,
             '(' => TokenType::OpenParen,
             ',' => TokenType::Comma,
+            '+' => TokenType::Plus,
+            '*' => TokenType::Star,
+            '/' => TokenType::Slash,
             _ => TokenType::UnknownToken,
         };
 
-        *current += 1;
+        chars.next();
 
-        return Ok(Token::new(token_type, &"".to_string(), &c.to_string()));
+        return Token::new(token_type, &"".to_string(), &c.to_string());
     }
 
     /* Handle slightly complex tokens like '>' which might be
     succeeded by '=' */
-    fn handle_single_double_token(
-        query: &String,
-        c: char,
-        current: &mut usize,
-    ) -> Result<Token, String> {
-        let slice_start = *current;
-        let peeked_token: Option<char> = Lexer::peek_one(query, current);
-        let token_type: TokenType = if c == '>' {
-            if peeked_token == Some('=') {
-                *current += 2;
-                TokenType::Gte
-            } else {
-                *current += 1;
-                TokenType::Gt
-            }
-        } else if c == '<' {
-            if peeked_token == Some('=') {
-                *current += 2;
-                TokenType::Lte
-            } else {
-                *current += 1;
-                TokenType::Lt
-            }
-        } else if c == '=' {
-            *current += 1;
-            TokenType::Equal
-        } else {
-            TokenType::UnknownToken
+    fn handle_single_double_token(query: &str, chars: &mut Cursor, c: char) -> Token {
+        let slice_start: usize = Lexer::offset(query, chars);
+        let peeked_token: Option<char> = Lexer::peek_one(chars);
+
+        chars.next();
+
+        let token_type: TokenType = match (c, peeked_token) {
+            ('>', Some('=')) => TokenType::Gte,
+            ('>', _) => TokenType::Gt,
+            ('<', Some('=')) => TokenType::Lte,
+            ('<', Some('>')) => TokenType::NotEqual,
+            ('<', _) => TokenType::Lt,
+            ('=', _) => TokenType::Equal,
+            ('!', Some('=')) => TokenType::NotEqual,
+            _ => TokenType::UnknownToken,
         };
 
-        let slice_end: usize = *current;
+        // Two character operators take the peeked character too
+        if matches!(
+            (c, peeked_token),
+            ('>', Some('=')) | ('<', Some('=' | '>')) | ('!', Some('='))
+        ) {
+            chars.next();
+        }
 
-        return Ok(Token::new(
+        return Token::new(
             token_type,
             &"".to_string(),
-            &query[slice_start..slice_end].to_string(),
-        ));
+            &query[slice_start..Lexer::offset(query, chars)].to_string(),
+        );
     }
 
-    fn handle_default(
-        query: &String,
-        c: char,
-        current: &mut usize,
-        start: &mut usize,
-    ) -> Result<Token, String> {
-        let mut token_type: TokenType;
+    fn handle_default(query: &str, chars: &mut Cursor, c: char) -> Token {
+        let token_type: TokenType;
         let literal: String;
-        let slice_start: usize = *current;
+        let slice_start: usize = Lexer::offset(query, chars);
 
-        if c == ' ' {
+        if c.is_whitespace() {
             // Whitespace
             token_type = TokenType::WhitespaceToken;
-            literal = " ".to_string()
-        } else if c.is_digit(10)
-            || (c == '-' && Lexer::peek_one(query, &current).is_some_and(|x: char| x.is_digit(10)))
+            literal = " ".to_string();
+
+            Lexer::take_while(chars, |c| c.is_whitespace());
+        } else if c.is_ascii_digit()
+            || (c == '-' && Lexer::peek_one(chars).is_some_and(|x: char| x.is_ascii_digit()))
         {
             // Number literals
             token_type = TokenType::NumberLiteral;
 
-            if c == '-' && Lexer::peek_one(query, &current).is_some_and(|x: char| x.is_digit(10)) {
-                *current += 1;
+            if c == '-' {
+                chars.next();
             }
 
-            Lexer::peek_decimal(query, start, current);
+            Lexer::peek_decimal(chars);
 
-            literal = query[*start..*current].to_string();
-            *current -= 1;
-        } else if c == '\"' {
-            token_type = TokenType::StringLiteral;
-
-            Lexer::peek_string(query, current, &mut token_type);
+            literal = query[slice_start..Lexer::offset(query, chars)].to_string();
+        } else if c == '-' {
+            // A '-' starting a word without a number after it is a minus,
+            // inside a word it's part of the name (i.e. `drinks-2024`)
+            token_type = TokenType::Minus;
+            literal = "".to_string();
 
-            if token_type == TokenType::UnknownToken {
-                *current += 1; // We weren't able to increment in the loop
-                return Ok(Token::new(
-                    token_type,
+            chars.next();
+        } else if c == '\"' {
+            // A string without a closing quote swallows the rest of the query
+            if !Lexer::peek_string(chars) {
+                return Token::new(
+                    TokenType::UnknownToken,
                     &"".to_string(),
-                    &query[slice_start..*current].to_string(),
-                ));
+                    &query[slice_start..].to_string(),
+                );
             }
 
-            literal = query[*start + 1..*current].to_string();
+            let slice_end: usize = Lexer::offset(query, chars);
+
+            token_type = TokenType::StringLiteral;
+            literal = query[slice_start + 1..slice_end - 1].to_string();
         } else {
             // This is where we handle an identifier, or keyword
-            token_type = TokenType::Identifier;
+            Lexer::peek_identifier(chars);
 
-            Lexer::peek_identifier(query, start, current, &mut token_type);
+            let word: &str = &query[slice_start..Lexer::offset(query, chars)];
 
             // Keywords don't need literals
-            literal = if token_type == TokenType::Identifier {
-                query[*start..*current].to_string()
-            } else {
-                "".to_string()
+            match SYSTEM_KEYWORDS.get(word.to_lowercase().as_str()) {
+                Some(keyword_token) => {
+                    token_type = *keyword_token;
+                    literal = "".to_string();
+                }
+                None => {
+                    token_type = TokenType::Identifier;
+                    literal = word.to_string();
+                }
             };
-
-            *current -= 1;
         }
 
-        *current += 1;
-        let slice_end: usize = *current;
-
-        return Ok(Token::new(
+        return Token::new(
             token_type,
             &literal,
-            &query[slice_start..slice_end].to_string(),
-        ));
+            &query[slice_start..Lexer::offset(query, chars)].to_string(),
+        );
     }
 
-    fn next_token(query: &String, current: &mut usize, start: &mut usize) -> Result<Token, String> {
-        let c: char = query.chars().nth(*current).unwrap();
-
-        if SINGLE_START_TOKENS.contains(&c) {
-            return Lexer::handle_single_token(query, c, current);
+    fn next_token(query: &str, chars: &mut Cursor, c: char) -> Token {
+        // '!' is an end-of-query token unless it starts a '!='
+        if c == '!' && Lexer::peek_one(chars) == Some('=') {
+            return Lexer::handle_single_double_token(query, chars, c);
+        } else if SINGLE_START_TOKENS.contains(&c) {
+            return Lexer::handle_single_token(chars, c);
         } else if SINGLE_DOUBLE_START_TOKENS.contains(&c) {
-            return Lexer::handle_single_double_token(query, c, current);
+            return Lexer::handle_single_double_token(query, chars, c);
         } else {
-            return Lexer::handle_default(query, c, current, start);
+            return Lexer::handle_default(query, chars, c);
+        }
+    }
+
+    // Number of tokens from the start of `tokens` making up the phrase
+    fn phrase_length(tokens: &[Token], phrase: &str) -> Option<usize> {
+        let mut length: usize = 0;
+
+        for word in phrase.split(' ') {
+            if !tokens
+                .get(length)
+                .is_some_and(|v| v.lexeme.eq_ignore_ascii_case(word))
+            {
+                return None;
+            }
+
+            length += 1;
         }
+
+        Some(length)
+    }
+
+    // Joins the words of comparisons written out in English (i.e.
+    // `is greater than`) into a single token
+    fn join_phrases(query: &str, tokens: Vec<Token>) -> Vec<Token> {
+        let mut joined: Vec<Token> = Vec::with_capacity(tokens.len());
+        let mut idx: usize = 0;
+
+        while idx < tokens.len() {
+            let phrase: Option<(usize, TokenType)> = COMPARISON_PHRASES
+                .iter()
+                .chain(MODIFIER_PHRASES)
+                .chain(ARITHMETIC_PHRASES)
+                .filter_map(|(phrase, token_type)| {
+                    Some((Lexer::phrase_length(&tokens[idx..], phrase)?, *token_type))
+                })
+                .max_by_key(|(length, _)| *length);
+
+            match phrase {
+                Some((length, token_type)) => {
+                    let first: &Token = &tokens[idx];
+                    let last: &Token = &tokens[idx + length - 1];
+
+                    joined.push(Token {
+                        span: first.span.to(last.span),
+                        position: first.position,
+                        ..Token::new(
+                            token_type,
+                            &"".to_string(),
+                            &query[first.span.start..last.span.end].to_string(),
+                        )
+                    });
+                    idx += length;
+                }
+                None => {
+                    joined.push(tokens[idx].clone());
+                    idx += 1;
+                }
+            };
+        }
+
+        joined
+    }
+
+    // A negative number straight after something that could be
+    // subtracted from (i.e. `price -2`) is a subtraction instead, so the
+    // '-' is split off into a minus of its own
+    fn split_minus(tokens: Vec<Token>) -> Vec<Token> {
+        let mut split: Vec<Token> = Vec::with_capacity(tokens.len());
+
+        for token in tokens {
+            let subtracted: bool = split.last().is_some_and(|v: &Token| {
+                matches!(
+                    v.token_type,
+                    TokenType::Identifier
+                        | TokenType::NumberLiteral
+                        | TokenType::StringLiteral
+                        | TokenType::CloseParen
+                )
+            });
+
+            match token.lexeme.strip_prefix('-') {
+                Some(number) if subtracted && token.token_type == TokenType::NumberLiteral => {
+                    split.push(Token {
+                        span: Span::new(token.span.start, token.span.start + 1),
+                        position: token.position,
+                        ..Token::new(TokenType::Minus, &"".to_string(), &"-".to_string())
+                    });
+                    split.push(Token {
+                        span: Span::new(token.span.start + 1, token.span.end),
+                        position: token.position.advance("-"),
+                        ..Token::new(
+                            TokenType::NumberLiteral,
+                            &number.to_string(),
+                            &number.to_string(),
+                        )
+                    });
+                }
+                _ => split.push(token),
+            }
+        }
+
+        split
     }
 
     pub fn new(query: &String) -> Result<Lexer, String> {
         let mut toks: Vec<Token> = vec![];
-        let mut warnings: Vec<String> = vec![];
-        let mut start: usize = 0;
-        let mut current: usize = 0;
+        let mut chars: Cursor = query.char_indices().peekable();
+        let mut position: Position = Position::default();
 
-        while current < query.len() {
-            let token: Result<Token, String> = Lexer::next_token(query, &mut current, &mut start);
+        while let Some(&(start, c)) = chars.peek() {
+            let mut token: Token = Lexer::next_token(query, &mut chars, c);
+            let end: usize = Lexer::offset(query, &mut chars);
 
-            if token.is_err() {
-                warnings.push(token.err().unwrap());
-            } else {
-                toks.push(token.unwrap());
-            }
+            token.span = Span::new(start, end);
+            token.position = position;
+
+            position = position.advance(&query[start..end]);
 
-            start = current;
+            if token.token_type != TokenType::WhitespaceToken {
+                toks.push(token);
+            }
         }
 
+        // Ignored words can still be part of a phrase (i.e. `equal to`),
+        // so they're only dropped once phrases have been joined
         Ok(Lexer {
-            tokens: toks
-                .into_iter()
-                .filter(|x: &Token| {
-                    x.token_type != TokenType::WhitespaceToken
-                        && x.token_type != TokenType::NullToken
-                })
-                .collect(),
+            tokens: Lexer::split_minus(
+                Lexer::join_phrases(query, toks)
+                    .into_iter()
+                    .filter(|x: &Token| x.token_type != TokenType::NullToken)
+                    .collect(),
+            ),
         })
     }
 }
@@ -280,7 +349,7 @@ mod tests {
         let input: String = "()!.;,".to_string();
         let test_lexer: Result<Lexer, String> = Lexer::new(&input);
 
-        assert!(!test_lexer.is_err());
+        assert!(test_lexer.is_ok());
 
         let expected: Vec<Token> = vec![
             Token::new(TokenType::OpenParen, &"".to_string(), &"(".to_string()),
@@ -296,10 +365,10 @@ mod tests {
 
     #[test]
     fn unit_test_basic_single_double_tokens() {
-        let input: String = "<><=>==".to_string();
+        let input: String = "< ><=>==".to_string();
         let test_lexer: Result<Lexer, String> = Lexer::new(&input);
 
-        assert!(!test_lexer.is_err());
+        assert!(test_lexer.is_ok());
 
         let expected: Vec<Token> = vec![
             Token::new(TokenType::Lt, &"".to_string(), &"<".to_string()),
@@ -312,12 +381,28 @@ mod tests {
         assert_eq!(expected, test_lexer.unwrap().tokens);
     }
 
+    #[test]
+    fn unit_test_basic_not_equal_tokens() {
+        let input: String = "!=<>!".to_string();
+        let test_lexer: Result<Lexer, String> = Lexer::new(&input);
+
+        assert!(test_lexer.is_ok());
+
+        let expected: Vec<Token> = vec![
+            Token::new(TokenType::NotEqual, &"".to_string(), &"!=".to_string()),
+            Token::new(TokenType::NotEqual, &"".to_string(), &"<>".to_string()),
+            Token::new(TokenType::EoqToken, &"".to_string(), &"!".to_string()),
+        ];
+
+        assert_eq!(expected, test_lexer.unwrap().tokens);
+    }
+
     #[test]
     fn unit_test_basic_string_literal_base() {
         let input: String = "\"Hi1234\"".to_string();
         let test_lexer: Result<Lexer, String> = Lexer::new(&input);
 
-        assert!(!test_lexer.is_err());
+        assert!(test_lexer.is_ok());
 
         let expected: Vec<Token> = vec![Token::new(
             TokenType::StringLiteral,
@@ -333,7 +418,7 @@ mod tests {
         let input: String = "\"Hi1234".to_string();
         let test_lexer: Result<Lexer, String> = Lexer::new(&input);
 
-        assert!(!test_lexer.is_err());
+        assert!(test_lexer.is_ok());
 
         let expected: Vec<Token> = vec![Token::new(
             TokenType::UnknownToken,
@@ -349,7 +434,7 @@ mod tests {
         let input: String = "\"".to_string();
         let test_lexer: Result<Lexer, String> = Lexer::new(&input);
 
-        assert!(!test_lexer.is_err());
+        assert!(test_lexer.is_ok());
 
         let expected: Vec<Token> = vec![Token::new(
             TokenType::UnknownToken,
@@ -365,7 +450,7 @@ mod tests {
         let input: String = "\"\"".to_string();
         let test_lexer: Result<Lexer, String> = Lexer::new(&input);
 
-        assert!(!test_lexer.is_err());
+        assert!(test_lexer.is_ok());
 
         let expected: Vec<Token> = vec![Token::new(
             TokenType::StringLiteral,
@@ -381,7 +466,7 @@ mod tests {
         let input: String = "1234".to_string();
         let test_lexer: Result<Lexer, String> = Lexer::new(&input);
 
-        assert!(!test_lexer.is_err());
+        assert!(test_lexer.is_ok());
 
         let expected: Vec<Token> = vec![Token::new(
             TokenType::NumberLiteral,
@@ -397,7 +482,7 @@ mod tests {
         let input: String = "12.".to_string();
         let test_lexer: Result<Lexer, String> = Lexer::new(&input);
 
-        assert!(!test_lexer.is_err());
+        assert!(test_lexer.is_ok());
 
         let expected: Vec<Token> = vec![
             Token::new(
@@ -416,7 +501,7 @@ mod tests {
         let input: String = "12.34".to_string();
         let test_lexer: Result<Lexer, String> = Lexer::new(&input);
 
-        assert!(!test_lexer.is_err());
+        assert!(test_lexer.is_ok());
 
         let expected: Vec<Token> = vec![Token::new(
             TokenType::NumberLiteral,
@@ -432,7 +517,7 @@ mod tests {
         let input: String = "-12.34".to_string();
         let test_lexer: Result<Lexer, String> = Lexer::new(&input);
 
-        assert!(!test_lexer.is_err());
+        assert!(test_lexer.is_ok());
 
         let expected: Vec<Token> = vec![Token::new(
             TokenType::NumberLiteral,
@@ -448,7 +533,7 @@ mod tests {
         let input: String = "get all from place.".to_string();
         let test_lexer: Result<Lexer, String> = Lexer::new(&input);
 
-        assert!(!test_lexer.is_err());
+        assert!(test_lexer.is_ok());
 
         let expected: Vec<Token> = vec![
             Token::new(TokenType::Get, &"".to_string(), &"get".to_string()),
@@ -476,7 +561,7 @@ mod tests {
                 .to_string();
         let test_lexer: Result<Lexer, String> = Lexer::new(&input);
 
-        assert!(!test_lexer.is_err());
+        assert!(test_lexer.is_ok());
 
         let expected: Vec<Token> = vec![
             Token::new(TokenType::Get, &"".to_string(), &"retrieve".to_string()),
@@ -524,4 +609,248 @@ mod tests {
 
         assert_eq!(expected, test_lexer.unwrap().tokens);
     }
+
+    #[test]
+    fn unit_test_token_spans() {
+        let input: String = "get name >= \"x y\";".to_string();
+        let test_lexer: Lexer = Lexer::new(&input).unwrap();
+
+        assert_eq!(
+            test_lexer
+                .tokens
+                .iter()
+                .map(|v| (v.span.start, v.span.end))
+                .collect::<Vec<(usize, usize)>>(),
+            vec![(0, 3), (4, 8), (9, 11), (12, 17), (17, 18)]
+        );
+
+        for token in &test_lexer.tokens {
+            assert_eq!(&input[token.span.start..token.span.end], token.lexeme);
+        }
+    }
+
+    #[test]
+    fn unit_test_token_positions() {
+        let input: String = "get name\n  from drinks;".to_string();
+        let test_lexer: Lexer = Lexer::new(&input).unwrap();
+
+        assert_eq!(
+            test_lexer
+                .tokens
+                .iter()
+                .map(|v| (v.position.line, v.position.column))
+                .collect::<Vec<(usize, usize)>>(),
+            vec![(1, 1), (1, 5), (2, 3), (2, 8), (2, 14)]
+        );
+    }
+
+    #[test]
+    fn unit_test_edge_number_literal_decimal_mid_query() {
+        let input: String = "12. 3.5!".to_string();
+        let test_lexer: Result<Lexer, String> = Lexer::new(&input);
+
+        assert!(test_lexer.is_ok());
+
+        let expected: Vec<Token> = vec![
+            Token::new(
+                TokenType::NumberLiteral,
+                &"12".to_string(),
+                &"12".to_string(),
+            ),
+            Token::new(TokenType::EoqToken, &"".to_string(), &".".to_string()),
+            Token::new(
+                TokenType::NumberLiteral,
+                &"3.5".to_string(),
+                &"3.5".to_string(),
+            ),
+            Token::new(TokenType::EoqToken, &"".to_string(), &"!".to_string()),
+        ];
+
+        assert_eq!(expected, test_lexer.unwrap().tokens);
+    }
+
+    #[test]
+    fn unit_test_edge_keyword_end_of_query() {
+        let input: String = "get all".to_string();
+        let test_lexer: Result<Lexer, String> = Lexer::new(&input);
+
+        assert!(test_lexer.is_ok());
+
+        let expected: Vec<Token> = vec![
+            Token::new(TokenType::Get, &"".to_string(), &"get".to_string()),
+            Token::new(
+                TokenType::WildcardKeyword,
+                &"".to_string(),
+                &"all".to_string(),
+            ),
+        ];
+
+        assert_eq!(expected, test_lexer.unwrap().tokens);
+    }
+
+    #[test]
+    fn unit_test_unicode() {
+        let input: String =
+            "get caf\u{e9}, \u{2615}\nfrom b\u{fc}cher where x is \"na\u{ef}ve\";".to_string();
+        let test_lexer: Lexer = Lexer::new(&input).unwrap();
+
+        assert_eq!(
+            test_lexer
+                .tokens
+                .iter()
+                .map(|v| v.literal.as_str())
+                .collect::<Vec<&str>>(),
+            vec![
+                "",
+                "caf\u{e9}",
+                "",
+                "\u{2615}",
+                "",
+                "b\u{fc}cher",
+                "",
+                "x",
+                "",
+                "na\u{ef}ve",
+                ""
+            ]
+        );
+
+        // Spans are in bytes, positions in characters
+        for token in &test_lexer.tokens {
+            assert_eq!(&input[token.span.start..token.span.end], token.lexeme);
+        }
+
+        assert_eq!(test_lexer.tokens[5].span, Span::new(20, 27));
+        assert_eq!(
+            (
+                test_lexer.tokens[5].position.line,
+                test_lexer.tokens[5].position.column
+            ),
+            (2, 6)
+        );
+    }
+
+    #[test]
+    fn unit_test_comparison_phrases() {
+        let input: String = "a is at least 5 or b is equal to 2 or c is at".to_string();
+        let test_lexer: Lexer = Lexer::new(&input).unwrap();
+
+        assert_eq!(
+            test_lexer
+                .tokens
+                .iter()
+                .map(|v| (v.token_type, v.lexeme.as_str()))
+                .collect::<Vec<(TokenType, &str)>>(),
+            vec![
+                (TokenType::Identifier, "a"),
+                (TokenType::Gte, "is at least"),
+                (TokenType::NumberLiteral, "5"),
+                (TokenType::Or, "or"),
+                (TokenType::Identifier, "b"),
+                (TokenType::Equal, "is equal to"),
+                (TokenType::NumberLiteral, "2"),
+                (TokenType::Or, "or"),
+                (TokenType::Identifier, "c"),
+                (TokenType::Equal, "is"),
+                (TokenType::Identifier, "at"),
+            ]
+        );
+        assert_eq!(test_lexer.tokens[1].span, Span::new(2, 13));
+        assert_eq!(test_lexer.tokens[5].span, Span::new(21, 32));
+    }
+
+    #[test]
+    fn unit_test_empty_values() {
+        let input: String = "a Is Not Empty or b = False and c is nothing".to_string();
+        let test_lexer: Lexer = Lexer::new(&input).unwrap();
+
+        assert_eq!(
+            test_lexer
+                .tokens
+                .iter()
+                .map(|v| (v.token_type, v.lexeme.as_str()))
+                .collect::<Vec<(TokenType, &str)>>(),
+            vec![
+                (TokenType::Identifier, "a"),
+                (TokenType::IsNotEmpty, "Is Not Empty"),
+                (TokenType::Or, "or"),
+                (TokenType::Identifier, "b"),
+                (TokenType::Equal, "="),
+                (TokenType::BooleanLiteral, "False"),
+                (TokenType::And, "and"),
+                (TokenType::Identifier, "c"),
+                (TokenType::Equal, "is"),
+                (TokenType::NullLiteral, "nothing"),
+            ]
+        );
+    }
+
+    #[test]
+    fn unit_test_arithmetic() {
+        let input: String =
+            "a*-2 - b / c times d multiplied by -e plus drinks-2024 minus f".to_string();
+        let test_lexer: Lexer = Lexer::new(&input).unwrap();
+
+        assert_eq!(
+            test_lexer
+                .tokens
+                .iter()
+                .map(|v| (v.token_type, v.lexeme.as_str()))
+                .collect::<Vec<(TokenType, &str)>>(),
+            vec![
+                (TokenType::Identifier, "a"),
+                (TokenType::Star, "*"),
+                (TokenType::NumberLiteral, "-2"),
+                (TokenType::Minus, "-"),
+                (TokenType::Identifier, "b"),
+                (TokenType::Slash, "/"),
+                (TokenType::Identifier, "c"),
+                (TokenType::Star, "times"),
+                (TokenType::Identifier, "d"),
+                (TokenType::Star, "multiplied by"),
+                (TokenType::Minus, "-"),
+                (TokenType::Identifier, "e"),
+                (TokenType::Plus, "plus"),
+                (TokenType::Identifier, "drinks-2024"),
+                (TokenType::Minus, "minus"),
+                (TokenType::Identifier, "f"),
+            ]
+        );
+    }
+
+    #[test]
+    fn unit_test_arithmetic_subtracting_negative_numbers() {
+        let input: String = "price -2 > 1 -1.5 and (a)-3 = price-2 or b is -4".to_string();
+        let test_lexer: Lexer = Lexer::new(&input).unwrap();
+
+        assert_eq!(
+            test_lexer
+                .tokens
+                .iter()
+                .map(|v| (v.token_type, v.lexeme.as_str(), v.span))
+                .collect::<Vec<(TokenType, &str, Span)>>(),
+            vec![
+                (TokenType::Identifier, "price", Span::new(0, 5)),
+                (TokenType::Minus, "-", Span::new(6, 7)),
+                (TokenType::NumberLiteral, "2", Span::new(7, 8)),
+                (TokenType::Gt, ">", Span::new(9, 10)),
+                (TokenType::NumberLiteral, "1", Span::new(11, 12)),
+                (TokenType::Minus, "-", Span::new(13, 14)),
+                (TokenType::NumberLiteral, "1.5", Span::new(14, 17)),
+                (TokenType::And, "and", Span::new(18, 21)),
+                (TokenType::OpenParen, "(", Span::new(22, 23)),
+                (TokenType::Identifier, "a", Span::new(23, 24)),
+                (TokenType::CloseParen, ")", Span::new(24, 25)),
+                (TokenType::Minus, "-", Span::new(25, 26)),
+                (TokenType::NumberLiteral, "3", Span::new(26, 27)),
+                (TokenType::Equal, "=", Span::new(28, 29)),
+                // A '-' inside a word is still part of the name
+                (TokenType::Identifier, "price-2", Span::new(30, 37)),
+                (TokenType::Or, "or", Span::new(38, 40)),
+                (TokenType::Identifier, "b", Span::new(41, 42)),
+                (TokenType::Equal, "is", Span::new(43, 45)),
+                (TokenType::NumberLiteral, "-4", Span::new(46, 48)),
+            ]
+        );
+    }
 }
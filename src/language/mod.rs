@@ -1,3 +1,8 @@
+pub mod ast;
+pub mod builder;
+pub mod diagnostics;
+pub mod errors;
 pub mod lexer;
 pub mod parser;
 pub mod tokens;
+pub mod visitor;
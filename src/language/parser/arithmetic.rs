@@ -0,0 +1,381 @@
+use std::fmt;
+
+use crate::language::{
+    errors::{EaqlError, ErrorContext},
+    parser::helpers::{valid_until_warning, validate_length},
+    tokens::{Span, Spanned, Token, TokenType},
+};
+
+/// One side of a comparison, a column or value with any arithmetic
+/// done on it (`price * quantity`).
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ArithmeticChild {
+    Column(Token),
+    Value(Token),
+    Negate(Box<NegateNode>),
+    Op(Box<ArithmeticNode>),
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArithmeticNode {
+    _operator: Token,
+
+    _ls: ArithmeticChild,
+    _rs: ArithmeticChild,
+}
+
+/// A side negated with a leading minus (`-price`).
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NegateNode {
+    _minus: Token,
+    _child: ArithmeticChild,
+}
+
+/// How the two sides of an arithmetic node are combined.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ArithmeticOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl ArithmeticOperator {
+    /// How tightly the operator binds, multiplying and dividing are
+    /// done before adding and subtracting.
+    pub(crate) fn binding(&self) -> u8 {
+        match self {
+            ArithmeticOperator::Add | ArithmeticOperator::Subtract => 0,
+            ArithmeticOperator::Multiply | ArithmeticOperator::Divide => 1,
+        }
+    }
+
+    pub(crate) fn symbol(&self) -> &'static str {
+        match self {
+            ArithmeticOperator::Add => "+",
+            ArithmeticOperator::Subtract => "-",
+            ArithmeticOperator::Multiply => "*",
+            ArithmeticOperator::Divide => "/",
+        }
+    }
+}
+
+impl ArithmeticChild {
+    /// Takes current node type and given the current location in the
+    /// query defined by the borrowed index, makes an attempt to parse
+    /// this node and associated subnodes for the Abstract Syntax Tree.
+    pub fn parse(tokens: &Vec<Token>, idx: &mut usize) -> Result<ArithmeticChild, EaqlError> {
+        let mut child: ArithmeticChild = parse_term(tokens, idx)?;
+
+        // Adding and subtracting are done left to right once everything
+        // multiplied or divided on either side has been
+        while matches!(
+            tokens.get(*idx).map(|v| v.token_type),
+            Some(TokenType::Plus | TokenType::Minus)
+        ) {
+            let operator: Token = tokens[*idx].clone();
+            *idx += 1;
+
+            child = ArithmeticChild::Op(Box::new(ArithmeticNode {
+                _operator: operator,
+                _ls: child,
+                _rs: parse_term(tokens, idx)?,
+            }));
+        }
+
+        Ok(child)
+    }
+
+    /// Whether this is more than a column or value on its own.
+    pub fn is_arithmetic(&self) -> bool {
+        matches!(self, ArithmeticChild::Negate(_) | ArithmeticChild::Op(_))
+    }
+}
+
+fn parse_term(tokens: &Vec<Token>, idx: &mut usize) -> Result<ArithmeticChild, EaqlError> {
+    let mut child: ArithmeticChild = parse_factor(tokens, idx)?;
+
+    while matches!(
+        tokens.get(*idx).map(|v| v.token_type),
+        Some(TokenType::Star | TokenType::Slash)
+    ) {
+        let operator: Token = tokens[*idx].clone();
+        *idx += 1;
+
+        child = ArithmeticChild::Op(Box::new(ArithmeticNode {
+            _operator: operator,
+            _ls: child,
+            _rs: parse_factor(tokens, idx)?,
+        }));
+    }
+
+    Ok(child)
+}
+
+fn parse_factor(tokens: &Vec<Token>, idx: &mut usize) -> Result<ArithmeticChild, EaqlError> {
+    validate_length(tokens, idx, true)?;
+
+    let token: &Token = &tokens[*idx];
+
+    match token.token_type {
+        TokenType::Identifier => {
+            *idx += 1;
+            Ok(ArithmeticChild::Column(token.clone()))
+        }
+        TokenType::StringLiteral | TokenType::NumberLiteral => {
+            *idx += 1;
+            Ok(ArithmeticChild::Value(token.clone()))
+        }
+        TokenType::Minus => {
+            *idx += 1;
+
+            Ok(ArithmeticChild::Negate(Box::new(NegateNode {
+                _minus: token.clone(),
+                _child: parse_factor(tokens, idx)?,
+            })))
+        }
+        // These parentheses only ever group arithmetic, conditions are
+        // grouped before a comparison is ever reached
+        TokenType::OpenParen => {
+            *idx += 1;
+
+            let child: ArithmeticChild = ArithmeticChild::parse(tokens, idx)?;
+            validate_length(tokens, idx, true)?;
+
+            if tokens[*idx].token_type != TokenType::CloseParen {
+                return Err(EaqlError::UnbalancedParen(Box::new(
+                    ErrorContext::at(
+                        format!(
+                            "Arithmetic in parentheses was never closed, got -> {}",
+                            tokens[*idx].lexeme
+                        ),
+                        &tokens[*idx],
+                    )
+                    .with_hint("every `(` needs a matching `)`"),
+                )));
+            }
+
+            *idx += 1;
+            Ok(child)
+        }
+        TokenType::BooleanLiteral | TokenType::NullLiteral => {
+            Err(EaqlError::InvalidValue(Box::new(
+                ErrorContext::at(
+                    format!(
+                        "\"{}\" can't be used in arithmetic or compared to a column.",
+                        token.lexeme
+                    ),
+                    token,
+                )
+                .with_hint("compare a single column against it instead, i.e. `price is null`"),
+            )))
+        }
+        _ => Err(valid_until_warning(tokens, idx)
+            .with_hint("expected a column, number or text, i.e. `price * 2`")),
+    }
+}
+
+impl ArithmeticNode {
+    pub fn operator(&self) -> ArithmeticOperator {
+        match self._operator.token_type {
+            TokenType::Plus => ArithmeticOperator::Add,
+            TokenType::Minus => ArithmeticOperator::Subtract,
+            TokenType::Star => ArithmeticOperator::Multiply,
+            TokenType::Slash => ArithmeticOperator::Divide,
+            _ => unreachable!("Arithmetic is only parsed with arithmetic operators"),
+        }
+    }
+
+    pub fn left(&self) -> &ArithmeticChild {
+        &self._ls
+    }
+
+    pub fn right(&self) -> &ArithmeticChild {
+        &self._rs
+    }
+
+    pub fn left_mut(&mut self) -> &mut ArithmeticChild {
+        &mut self._ls
+    }
+
+    pub fn right_mut(&mut self) -> &mut ArithmeticChild {
+        &mut self._rs
+    }
+
+    /// Whether a side has to be wrapped in parentheses when written
+    /// out to keep the order the arithmetic is done in.
+    pub(crate) fn grouped(&self, right: bool) -> bool {
+        let side: &ArithmeticChild = if right { &self._rs } else { &self._ls };
+
+        match side {
+            // Either side binding looser has to be grouped, as does a
+            // right side binding the same (i.e. `a - (b - c)`)
+            ArithmeticChild::Op(child) if right => {
+                child.operator().binding() <= self.operator().binding()
+            }
+            ArithmeticChild::Op(child) => child.operator().binding() < self.operator().binding(),
+            _ => false,
+        }
+    }
+
+    pub(crate) fn operator_token(&self) -> &Token {
+        &self._operator
+    }
+
+    /// Builds arithmetic from its operator token, it should look like
+    /// the one the lexer would have produced.
+    pub(crate) fn new(
+        operator: Token,
+        left: ArithmeticChild,
+        right: ArithmeticChild,
+    ) -> ArithmeticNode {
+        ArithmeticNode {
+            _operator: operator,
+            _ls: left,
+            _rs: right,
+        }
+    }
+}
+
+impl NegateNode {
+    /// The side negated.
+    pub fn child(&self) -> &ArithmeticChild {
+        &self._child
+    }
+
+    pub fn child_mut(&mut self) -> &mut ArithmeticChild {
+        &mut self._child
+    }
+
+    /// Whether the side negated has to be wrapped in parentheses when
+    /// written out. Anything starting with a minus is, since two in a
+    /// row would start a comment in SQL.
+    pub(crate) fn grouped(&self) -> bool {
+        match &self._child {
+            ArithmeticChild::Column(_) => false,
+            ArithmeticChild::Value(token) => token.lexeme.starts_with('-'),
+            ArithmeticChild::Negate(_) | ArithmeticChild::Op(_) => true,
+        }
+    }
+
+    pub(crate) fn minus_token(&self) -> &Token {
+        &self._minus
+    }
+
+    pub(crate) fn new(minus: Token, child: ArithmeticChild) -> NegateNode {
+        NegateNode {
+            _minus: minus,
+            _child: child,
+        }
+    }
+}
+
+// Display functions
+impl fmt::Display for ArithmeticChild {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let side =
+            |f: &mut fmt::Formatter<'_>, child: &ArithmeticChild, grouped: bool| match grouped {
+                true => write!(f, "({})", child),
+                false => write!(f, "{}", child),
+            };
+
+        match self {
+            ArithmeticChild::Column(token) | ArithmeticChild::Value(token) => {
+                write!(f, "{}", token.lexeme)
+            }
+            ArithmeticChild::Negate(node) => {
+                write!(f, "-")?;
+                side(f, node.child(), node.grouped())
+            }
+            ArithmeticChild::Op(node) => {
+                side(f, node.left(), node.grouped(false))?;
+                write!(f, " {} ", node.operator().symbol())?;
+                side(f, node.right(), node.grouped(true))
+            }
+        }
+    }
+}
+
+impl Spanned for ArithmeticChild {
+    fn span(&self) -> Span {
+        match self {
+            ArithmeticChild::Column(token) | ArithmeticChild::Value(token) => token.span,
+            ArithmeticChild::Negate(node) => node._minus.span.to(node._child.span()),
+            ArithmeticChild::Op(node) => node._ls.span().to(node._rs.span()),
+        }
+    }
+}
+
+// Begin Arithmetic Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::lexer::scan_tokens;
+
+    fn parse(query: &str) -> Result<ArithmeticChild, EaqlError> {
+        let tokens: Vec<Token> = scan_tokens(&query.to_string()).unwrap().tokens;
+
+        ArithmeticChild::parse(&tokens, &mut 0)
+    }
+
+    #[test]
+    fn unit_test_arithmetic_precedence() {
+        let ArithmeticChild::Op(node) = parse("price + tax * 2").unwrap() else {
+            panic!("Expected arithmetic at the root");
+        };
+
+        assert_eq!(node.operator(), ArithmeticOperator::Add);
+        assert!(matches!(node.left(), ArithmeticChild::Column(_)));
+        assert!(matches!(
+            node.right(),
+            ArithmeticChild::Op(right) if right.operator() == ArithmeticOperator::Multiply
+        ));
+
+        // Operators binding the same are done left to right
+        let ArithmeticChild::Op(node) = parse("a - b - c").unwrap() else {
+            panic!("Expected arithmetic at the root");
+        };
+
+        assert!(matches!(node.left(), ArithmeticChild::Op(_)));
+        assert!(matches!(node.right(), ArithmeticChild::Column(_)));
+    }
+
+    #[test]
+    fn unit_test_arithmetic_display() {
+        assert_eq!(
+            parse("(a + b) * c").unwrap().to_string(),
+            "(a + b) * c".to_string()
+        );
+        assert_eq!(
+            parse("a minus (b plus c) divided by 2")
+                .unwrap()
+                .to_string(),
+            "a - (b + c) / 2".to_string()
+        );
+        assert_eq!(
+            parse("a - (b - c)").unwrap().to_string(),
+            "a - (b - c)".to_string()
+        );
+        assert_eq!(
+            parse("((a times b)) - - -5").unwrap().to_string(),
+            "a * b - -(-5)".to_string()
+        );
+    }
+
+    #[test]
+    fn unit_test_arithmetic_error() {
+        assert!(parse("price *").is_err());
+        assert!(parse("(price + 1").is_err());
+        assert!(parse("price + null").is_err());
+        assert!(parse("* price").is_err());
+    }
+
+    #[test]
+    fn unit_test_arithmetic_span() {
+        assert_eq!(parse("-price * 2").unwrap().span(), Span::new(0, 10));
+    }
+}
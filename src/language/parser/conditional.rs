@@ -2,28 +2,42 @@ use std::fmt;
 
 use crate::{
     language::{
-        parser::helpers::{get_tab, valid_until_warning, validate_length},
-        tokens::{Token, TokenType},
+        errors::{EaqlError, ErrorContext},
+        parser::{
+            arithmetic::ArithmeticChild,
+            display::TreeWriter,
+            helpers::{peek_one, span_between, valid_until_warning, validate_length},
+        },
+        tokens::{COMPARISON_PHRASES, Span, Spanned, Token, TokenType},
+        visitor::Visitor,
     },
-    utils::logger,
+    transpiler::{dialect::Dialect, sql},
 };
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConditionNode {
     _condition: ConditionChild,
     _literal: String,
 
+    _span: Span,
     _depth: u16,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConditionChild {
     Op(Box<OperandNode>),
     Expr(Box<ExpressionNode>),
     Bool(Box<BoolNode>),
+    Not(Box<NotNode>),
+    List(Box<ListNode>),
+    Range(Box<RangeNode>),
+    Compare(Box<ComparisonNode>),
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OperandNode {
     _type: String,
 
@@ -34,21 +48,205 @@ pub struct OperandNode {
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExpressionNode {
     _identifier: Token,
     _comparison_operator: Token,
     _literal: Token,
+    #[cfg_attr(feature = "serde", serde(default))]
+    _ignore_case: Option<Token>,
+
+    _depth: u16,
+}
+
+/// Two sides compared where either is more than a column or value on
+/// its own (`price > cost` or `price * quantity > 100`).
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ComparisonNode {
+    _ls: ArithmeticChild,
+    _comparison_operator: Token,
+    _rs: ArithmeticChild,
+
+    _depth: u16,
+}
+
+/// A column compared against a list of values (`is one of (...)`).
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ListNode {
+    _identifier: Token,
+    _operator: Token,
+    _values: Vec<Token>,
+
+    _span: Span,
+    _depth: u16,
+}
+
+/// A column compared against a range of values (`is between x and y`),
+/// both ends included.
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RangeNode {
+    _identifier: Token,
+    _operator: Token,
+    _low: Token,
+    _high: Token,
+
+    _depth: u16,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NotNode {
+    _child: ConditionChild,
 
     _depth: u16,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BoolNode {
     _value: bool,
 
     _depth: u16,
 }
 
+/// How two conditions are joined together.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum LogicalOperator {
+    And,
+    Or,
+}
+
+/// How a column is compared against a value.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ComparisonOperator {
+    Equal,
+    NotEqual,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    /// Text containing the value anywhere in it.
+    Contains(Case),
+    StartsWith(Case),
+    EndsWith(Case),
+    /// Text matching a pattern where `%` stands for any run of
+    /// characters and `_` for any single one.
+    Like(Case),
+}
+
+/// Whether matching text against a pattern tells upper and lower
+/// case apart.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Case {
+    Sensitive,
+    Insensitive,
+}
+
+impl ComparisonOperator {
+    /// How text is matched when the value is a pattern, `None` for
+    /// every other comparison.
+    pub fn pattern_case(&self) -> Option<Case> {
+        match self {
+            ComparisonOperator::Contains(case)
+            | ComparisonOperator::StartsWith(case)
+            | ComparisonOperator::EndsWith(case)
+            | ComparisonOperator::Like(case) => Some(*case),
+            _ => None,
+        }
+    }
+}
+
+/// A value a column is compared against, as written in the query.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Value<'a> {
+    Text(&'a str),
+    Number(&'a str),
+    Bool(bool),
+    /// No value at all (`null`, `nothing` or `is empty`).
+    Null,
+}
+
+impl OperandNode {
+    pub fn operator(&self) -> LogicalOperator {
+        match self._type.as_str() {
+            "AND" => LogicalOperator::And,
+            _ => LogicalOperator::Or,
+        }
+    }
+
+    pub fn left(&self) -> &ConditionChild {
+        &self._ls
+    }
+
+    pub fn right(&self) -> &ConditionChild {
+        &self._rs
+    }
+
+    pub fn left_mut(&mut self) -> &mut ConditionChild {
+        &mut self._ls
+    }
+
+    pub fn right_mut(&mut self) -> &mut ConditionChild {
+        &mut self._rs
+    }
+
+    pub(crate) fn depth(&self) -> u16 {
+        self._depth
+    }
+
+    pub(crate) fn new(
+        operator: LogicalOperator,
+        left: ConditionChild,
+        right: ConditionChild,
+        depth: u16,
+    ) -> OperandNode {
+        OperandNode {
+            _type: match operator {
+                LogicalOperator::And => "AND".to_string(),
+                LogicalOperator::Or => "OR".to_string(),
+            },
+            _ls: left,
+            _rs: right,
+            _depth: depth,
+        }
+    }
+}
+
+impl NotNode {
+    /// The condition negated.
+    pub fn child(&self) -> &ConditionChild {
+        &self._child
+    }
+
+    pub fn child_mut(&mut self) -> &mut ConditionChild {
+        &mut self._child
+    }
+
+    pub(crate) fn depth(&self) -> u16 {
+        self._depth
+    }
+
+    pub(crate) fn new(child: ConditionChild, depth: u16) -> NotNode {
+        NotNode {
+            _child: child,
+            _depth: depth,
+        }
+    }
+}
+
+impl BoolNode {
+    pub fn value(&self) -> bool {
+        self._value
+    }
+
+    pub(crate) fn depth(&self) -> u16 {
+        self._depth
+    }
+}
+
 fn update_depths(node: &mut ConditionChild) -> () {
     match node {
         ConditionChild::Op(state) => {
@@ -59,6 +257,46 @@ fn update_depths(node: &mut ConditionChild) -> () {
         }
         ConditionChild::Bool(state) => state._depth += 1,
         ConditionChild::Expr(state) => state._depth += 1,
+        ConditionChild::Not(state) => {
+            state._depth += 1;
+            update_depths(&mut state._child);
+        }
+        ConditionChild::List(state) => state._depth += 1,
+        ConditionChild::Range(state) => state._depth += 1,
+        ConditionChild::Compare(state) => state._depth += 1,
+    }
+}
+
+/// Makes sure the token just consumed (an `and`, `or` or opening
+/// parentheses) is followed by a condition, so a dangling operator
+/// can't quietly be filled in by the padding around it.
+fn expect_operand(tokens: &Vec<Token>, idx: &usize) -> Result<(), EaqlError> {
+    validate_length(tokens, idx, true)?;
+
+    match tokens[*idx].token_type {
+        TokenType::Identifier
+        | TokenType::StringLiteral
+        | TokenType::NumberLiteral
+        | TokenType::Minus
+        | TokenType::OpenParen
+        | TokenType::Not => Ok(()),
+        _ => {
+            let operator: &Token = &tokens[idx.saturating_sub(1)];
+
+            Err(EaqlError::EmptyCondition(Box::new(
+                ErrorContext::at(
+                    format!(
+                        "Expected a condition after \"{}\", got -> {}",
+                        operator.lexeme, tokens[*idx].lexeme
+                    ),
+                    &tokens[*idx],
+                )
+                .with_hint(&format!(
+                    "expected a condition like `price < 5` after `{}`",
+                    operator.lexeme
+                )),
+            )))
+        }
     }
 }
 
@@ -70,11 +308,11 @@ fn handle_open_paren(
     closing_paren: &mut bool,
     opened_paren: &mut u16,
     closing_or: &mut bool,
-) -> Result<ConditionChild, String> {
+) -> Result<ConditionChild, EaqlError> {
     let mut ret: ConditionChild = ConditionChild::Op(Box::new(OperandNode {
         _type: "OR".to_string(),
         _depth: depth,
-        _ls: match recurse_down(
+        _ls: recurse_down(
             tokens,
             idx,
             depth + 1,
@@ -83,11 +321,8 @@ fn handle_open_paren(
             closing_paren,
             opened_paren,
             closing_or,
-        ) {
-            Ok(node) => node,
-            Err(msg) => return Err(msg),
-        },
-        _rs: match recurse_down(
+        )?,
+        _rs: recurse_down(
             tokens,
             idx,
             depth + 1,
@@ -96,27 +331,23 @@ fn handle_open_paren(
             closing_paren,
             opened_paren,
             closing_or,
-        ) {
-            Ok(node) => node,
-            Err(msg) => return Err(msg),
-        },
+        )?,
     }));
 
     match tokens[*idx].token_type {
         TokenType::And => {
             *idx += 1;
             *closing_paren = false;
+            expect_operand(tokens, idx)?;
 
-            // This really only matters if we're debugging
-            // just makes the depths line up.
-            if logger::LOG_LEVEL <= logger::DEBUG.0 {
-                update_depths(&mut ret);
-            }
+            // The group becomes the left side of a new operator
+            // one level up, so its depths need to line up with that.
+            update_depths(&mut ret);
 
             return Ok(ConditionChild::Op(Box::new(OperandNode {
                 _type: "AND".to_string(),
                 _ls: ret,
-                _rs: match recurse_down(
+                _rs: recurse_down(
                     tokens,
                     idx,
                     depth + 1,
@@ -125,10 +356,7 @@ fn handle_open_paren(
                     closing_paren,
                     opened_paren,
                     closing_or,
-                ) {
-                    Ok(node) => node,
-                    Err(msg) => return Err(msg),
-                },
+                )?,
 
                 _depth: depth,
             })));
@@ -136,38 +364,25 @@ fn handle_open_paren(
         TokenType::Or => {
             *idx += 1;
             *closing_paren = false;
+            expect_operand(tokens, idx)?;
 
-            // This really only matters if we're debugging
-            // just makes the depths line up.
-            if logger::LOG_LEVEL <= logger::DEBUG.0 {
-                update_depths(&mut ret);
-            }
-
-            return Ok(ConditionChild::Op(Box::new(OperandNode {
-                _type: "OR".to_string(),
-                _ls: ret,
-                _rs: match recurse_down(
-                    tokens,
-                    idx,
-                    depth + 1,
-                    "OR".to_string(),
-                    finished,
-                    closing_paren,
-                    opened_paren,
-                    closing_or,
-                ) {
-                    Ok(node) => node,
-                    Err(msg) => return Err(msg),
-                },
+            // "or" binds looser than any "and" the group is part of, so
+            // it's handled like any other "or", closing out every "and"
+            // until one of the "or"s above picks it up.
+            *closing_or = true;
 
-                _depth: depth,
-            })));
+            return Ok(ret);
         }
         TokenType::CloseParen => {
             if *opened_paren == 0 {
-                return Err(
-                    "Closing parentheses found with unmatched opening in conditional!".to_string(),
-                );
+                return Err(EaqlError::UnbalancedParen(Box::new(
+                    ErrorContext::at(
+                        "Closing parentheses found with unmatched opening in conditional!"
+                            .to_string(),
+                        &tokens[*idx],
+                    )
+                    .with_hint("every `)` needs a matching `(`"),
+                )));
             }
 
             *opened_paren -= 1;
@@ -188,12 +403,15 @@ fn handle_open_paren(
             return Ok(ret);
         }
         _ => {
-            return Err(format!(
-                "Something went wrong parsing a nested conditional: Expected a closing parentheses, \
+            return Err(EaqlError::UnexpectedToken(Box::new(ErrorContext::at(
+                format!(
+                    "Something went wrong parsing a nested conditional: Expected a closing parentheses, \
         post-processor entrance, end-of-query, 'and' or 'or', \
         but got '{}' instead.",
-                tokens[*idx].lexeme
-            ));
+                    tokens[*idx].lexeme
+                ),
+                &tokens[*idx],
+            ))));
         }
     }
 }
@@ -206,11 +424,11 @@ fn handle_and(
     closing_paren: &mut bool,
     opened_paren: &mut u16,
     closing_or: &mut bool,
-) -> Result<ConditionChild, String> {
+) -> Result<ConditionChild, EaqlError> {
     Ok(ConditionChild::Op(Box::new(OperandNode {
         _type: "AND".to_string(),
         _depth: depth,
-        _ls: match recurse_down(
+        _ls: recurse_down(
             tokens,
             idx,
             depth + 1,
@@ -219,11 +437,8 @@ fn handle_and(
             closing_paren,
             opened_paren,
             closing_or,
-        ) {
-            Ok(node) => node,
-            Err(msg) => return Err(msg),
-        },
-        _rs: match recurse_down(
+        )?,
+        _rs: recurse_down(
             tokens,
             idx,
             depth + 1,
@@ -232,13 +447,154 @@ fn handle_and(
             closing_paren,
             opened_paren,
             closing_or,
-        ) {
-            Ok(node) => node,
-            Err(msg) => return Err(msg),
-        },
+        )?,
     })))
 }
 
+/// Parses the comparison starting at the column name, going by the
+/// word after the column to tell lists and ranges apart from plain
+/// expressions. Lists and ranges read every value they're given
+/// themselves, so the `and` in `between 2 and 5` is used up before
+/// it could be mistaken for joining two conditions.
+fn parse_comparison(
+    tokens: &Vec<Token>,
+    idx: &mut usize,
+    depth: u16,
+) -> Result<ConditionChild, EaqlError> {
+    if has_arithmetic(tokens, idx) {
+        return Ok(ConditionChild::Compare(Box::new(ComparisonNode::parse(
+            tokens, idx, depth,
+        )?)));
+    }
+
+    match peek_one(tokens, idx) {
+        TokenType::OneOf | TokenType::NotOneOf => Ok(ConditionChild::List(Box::new(
+            ListNode::parse(tokens, idx, depth)?,
+        ))),
+        TokenType::Between | TokenType::NotBetween => Ok(ConditionChild::Range(Box::new(
+            RangeNode::parse(tokens, idx, depth)?,
+        ))),
+        _ => Ok(ConditionChild::Expr(Box::new(ExpressionNode::parse(
+            tokens, idx, depth,
+        )?))),
+    }
+}
+
+/// Whether the comparison starting at the index has more than a lone
+/// column on its left or a lone value on its right, which only a
+/// comparison node can hold.
+fn has_arithmetic(tokens: &Vec<Token>, idx: &usize) -> bool {
+    let arithmetic = |i: usize| -> bool {
+        matches!(
+            tokens.get(i).map(|v| v.token_type),
+            Some(TokenType::Plus | TokenType::Minus | TokenType::Star | TokenType::Slash)
+        )
+    };
+
+    if tokens[*idx].token_type != TokenType::Identifier || arithmetic(*idx + 1) {
+        return true;
+    }
+
+    let (value_idx, consumed): (usize, usize) = match tokens.get(*idx + 1) {
+        _ if is_not(tokens, &(*idx + 1)).is_some() => (*idx + 3, 2),
+        Some(token)
+            if matches!(
+                token.token_type,
+                TokenType::Equal
+                    | TokenType::NotEqual
+                    | TokenType::Lt
+                    | TokenType::Lte
+                    | TokenType::Gt
+                    | TokenType::Gte
+            ) =>
+        {
+            (*idx + 2, token.lexeme.split_whitespace().count())
+        }
+        _ => return false,
+    };
+
+    match tokens.get(value_idx).map(|v| v.token_type) {
+        // An unfinished English comparison (i.e. `is greater 5`) isn't
+        // mistaken for comparing against a column named `greater`
+        Some(TokenType::Identifier) => unfinished_phrase(tokens, *idx + 1, consumed).is_none(),
+        Some(TokenType::Minus | TokenType::OpenParen) => true,
+        Some(TokenType::StringLiteral | TokenType::NumberLiteral) => arithmetic(value_idx + 1),
+        _ => false,
+    }
+}
+
+/// Whether the parentheses opened at the index group arithmetic rather
+/// than conditions, which is the case when they're followed by more
+/// arithmetic or a comparison (i.e. `(price + tax) * 2 > 10`).
+fn arithmetic_group(tokens: &Vec<Token>, idx: &usize) -> bool {
+    let mut opened: usize = 0;
+
+    for (i, token) in tokens.iter().enumerate().skip(*idx) {
+        match token.token_type {
+            TokenType::OpenParen => opened += 1,
+            TokenType::CloseParen if opened == 1 => {
+                return matches!(
+                    tokens.get(i + 1).map(|v| v.token_type),
+                    Some(
+                        TokenType::Plus
+                            | TokenType::Minus
+                            | TokenType::Star
+                            | TokenType::Slash
+                            | TokenType::Equal
+                            | TokenType::NotEqual
+                            | TokenType::Lt
+                            | TokenType::Lte
+                            | TokenType::Gt
+                            | TokenType::Gte
+                    )
+                );
+            }
+            TokenType::CloseParen => opened -= 1,
+            TokenType::EoqToken => return false,
+            _ => {}
+        }
+    }
+
+    false
+}
+
+/// The single `!=` token "is not" is read as, if the tokens at the
+/// index are "is" followed by "not".
+fn is_not(tokens: &Vec<Token>, idx: &usize) -> Option<Token> {
+    match (tokens.get(*idx), tokens.get(*idx + 1)) {
+        (Some(is), Some(not))
+            if is.token_type == TokenType::Equal
+                && is.lexeme.to_lowercase() == "is"
+                && not.token_type == TokenType::Not =>
+        {
+            // "is not" is the english form of "!="
+            Some(Token {
+                span: Span::new(is.span.start, not.span.end),
+                ..Token::new(
+                    TokenType::NotEqual,
+                    &"".to_string(),
+                    &format!("{} {}", is.lexeme, not.lexeme),
+                )
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Reads a string or number literal, as compared against in lists
+/// and ranges.
+fn parse_value(tokens: &Vec<Token>, idx: &mut usize, hint: &str) -> Result<Token, EaqlError> {
+    validate_length(tokens, idx, true)?;
+
+    match tokens[*idx].token_type {
+        TokenType::StringLiteral | TokenType::NumberLiteral => {
+            *idx += 1;
+            Ok(tokens[*idx - 1].clone())
+        }
+        _ => Err(valid_until_warning(tokens, idx).with_hint(hint)),
+    }
+}
+
 fn handle_literal(
     tokens: &Vec<Token>,
     idx: &mut usize,
@@ -247,14 +603,9 @@ fn handle_literal(
     closing_paren: &mut bool,
     opened_paren: &mut u16,
     closing_or: &mut bool,
-) -> Result<ConditionChild, String> {
-    let ls: ConditionChild = ConditionChild::Expr(Box::new(
-        match ExpressionNode::parse(tokens, idx, depth + 1) {
-            Ok(node) => node,
-            Err(msg) => return Err(msg),
-        },
-    ));
-    let rs: ConditionChild = match recurse_down(
+) -> Result<ConditionChild, EaqlError> {
+    let ls: ConditionChild = parse_comparison(tokens, idx, depth + 1)?;
+    let rs: ConditionChild = recurse_down(
         tokens,
         idx,
         depth + 1,
@@ -263,10 +614,7 @@ fn handle_literal(
         closing_paren,
         opened_paren,
         closing_or,
-    ) {
-        Ok(node) => node,
-        Err(msg) => return Err(msg),
-    };
+    )?;
 
     return Ok(ConditionChild::Op(Box::new(OperandNode {
         _type: "AND".to_string(),
@@ -276,6 +624,118 @@ fn handle_literal(
     })));
 }
 
+/// Parses a parenthesized group on its own, used when the group
+/// needs to be treated as a single unit (i.e. when it's negated).
+/// Expects the index to be just after the opening parentheses and
+/// leaves it just after the matching closing parentheses.
+fn parse_group(
+    tokens: &Vec<Token>,
+    idx: &mut usize,
+    depth: u16,
+) -> Result<ConditionChild, EaqlError> {
+    let mut finished: bool = false;
+    let mut closing_paren: bool = false;
+    let mut opened_paren: u16 = 1;
+    let mut closing_or: bool = false;
+
+    let group: ConditionChild = ConditionChild::Op(Box::new(OperandNode {
+        _type: "OR".to_string(),
+        _depth: depth,
+        _ls: recurse_down(
+            tokens,
+            idx,
+            depth + 1,
+            "OR".to_string(),
+            &mut finished,
+            &mut closing_paren,
+            &mut opened_paren,
+            &mut closing_or,
+        )?,
+        _rs: recurse_down(
+            tokens,
+            idx,
+            depth + 1,
+            "OR".to_string(),
+            &mut finished,
+            &mut closing_paren,
+            &mut opened_paren,
+            &mut closing_or,
+        )?,
+    }));
+
+    if opened_paren != 0 {
+        return Err(EaqlError::UnbalancedParen(Box::new(
+            ErrorContext::after(
+                "Negated conditional had unclosed parentheses".to_string(),
+                &tokens[(*idx).min(tokens.len()) - 1],
+            )
+            .with_hint("every `(` needs a matching `)`"),
+        )));
+    }
+
+    Ok(group)
+}
+
+fn handle_not(
+    tokens: &Vec<Token>,
+    idx: &mut usize,
+    depth: u16,
+    finished: &mut bool,
+    closing_paren: &mut bool,
+    opened_paren: &mut u16,
+    closing_or: &mut bool,
+) -> Result<ConditionChild, EaqlError> {
+    validate_length(tokens, idx, true)?;
+
+    // Negation binds tighter than "and"/"or", so it only applies
+    // to the expression or parenthesized group directly after it
+    let child: ConditionChild = match tokens[*idx].token_type {
+        TokenType::OpenParen if !arithmetic_group(tokens, idx) => {
+            *idx += 1;
+            expect_operand(tokens, idx)?;
+            parse_group(tokens, idx, depth + 2)?
+        }
+        TokenType::Identifier
+        | TokenType::StringLiteral
+        | TokenType::NumberLiteral
+        | TokenType::Minus
+        | TokenType::OpenParen => parse_comparison(tokens, idx, depth + 2)?,
+        _ => {
+            return Err(EaqlError::UnexpectedToken(Box::new(
+                ErrorContext::at(
+                    format!(
+                        "Expected an expression or parentheses after \"not\", got -> {}",
+                        tokens[*idx].lexeme
+                    ),
+                    &tokens[*idx],
+                )
+                .with_hint("expected a condition like `price < 5` after `not`"),
+            )));
+        }
+    };
+
+    let rs: ConditionChild = recurse_down(
+        tokens,
+        idx,
+        depth + 1,
+        "AND".to_string(),
+        finished,
+        closing_paren,
+        opened_paren,
+        closing_or,
+    )?;
+
+    Ok(ConditionChild::Op(Box::new(OperandNode {
+        _type: "AND".to_string(),
+        _depth: depth,
+        _ls: ConditionChild::Not(Box::new(NotNode {
+            _child: child,
+            _depth: depth + 1,
+        })),
+        _rs: rs,
+    })))
+}
+
 fn handle_close(parent_node: &String, depth: u16) -> ConditionChild {
     // AND default to true, OR defaults to false
     if *parent_node == "AND" {
@@ -293,7 +753,7 @@ fn handle_close(parent_node: &String, depth: u16) -> ConditionChild {
 
 fn handle_or(closing_or: &mut bool, parent_node: &String, depth: u16) -> ConditionChild {
     *closing_or = true;
-    handle_close(&parent_node, depth)
+    handle_close(parent_node, depth)
 }
 
 fn handle_close_paren(
@@ -302,7 +762,7 @@ fn handle_close_paren(
     depth: u16,
 ) -> ConditionChild {
     *closing_paren = true;
-    handle_close(&parent_node, depth)
+    handle_close(parent_node, depth)
 }
 
 fn parse_child(
@@ -314,10 +774,11 @@ fn parse_child(
     closing_paren: &mut bool,
     opened_paren: &mut u16,
     closing_or: &mut bool,
-) -> Result<ConditionChild, String> {
+) -> Result<ConditionChild, EaqlError> {
     match tokens[*idx].token_type {
         TokenType::And => {
             *idx += 1;
+            expect_operand(tokens, idx)?;
             return handle_and(
                 tokens,
                 idx,
@@ -330,11 +791,13 @@ fn parse_child(
         }
         TokenType::Or => {
             *idx += 1;
+            expect_operand(tokens, idx)?;
             return Ok(handle_or(closing_or, &parent_node, depth));
         }
-        TokenType::OpenParen => {
+        TokenType::OpenParen if !arithmetic_group(tokens, idx) => {
             *idx += 1;
             *opened_paren += 1;
+            expect_operand(tokens, idx)?;
             return handle_open_paren(
                 tokens,
                 idx,
@@ -349,9 +812,14 @@ fn parse_child(
             *idx += 1;
 
             if *opened_paren == 0 {
-                return Err(
-                    "Closing parentheses found with unmatched opening in conditional!".to_string(),
-                );
+                return Err(EaqlError::UnbalancedParen(Box::new(
+                    ErrorContext::at(
+                        "Closing parentheses found with unmatched opening in conditional!"
+                            .to_string(),
+                        &tokens[*idx - 1],
+                    )
+                    .with_hint("every `)` needs a matching `(`"),
+                )));
             }
 
             *opened_paren -= 1;
@@ -359,9 +827,13 @@ fn parse_child(
         }
         TokenType::PostProcessorEntrance => {
             if *closing_paren {
-                return Err(
-                    "Found end of conditional, but there are unclosed parentheses!".to_string(),
-                );
+                return Err(EaqlError::UnbalancedParen(Box::new(
+                    ErrorContext::at(
+                        "Found end of conditional, but there are unclosed parentheses!".to_string(),
+                        &tokens[*idx],
+                    )
+                    .with_hint("every `(` needs a matching `)`"),
+                )));
             }
 
             *finished = true;
@@ -370,16 +842,36 @@ fn parse_child(
         }
         TokenType::EoqToken => {
             if *closing_paren {
-                return Err(
-                    "Found end of conditional, but there are unclosed parentheses!".to_string(),
-                );
+                return Err(EaqlError::UnbalancedParen(Box::new(
+                    ErrorContext::at(
+                        "Found end of conditional, but there are unclosed parentheses!".to_string(),
+                        &tokens[*idx],
+                    )
+                    .with_hint("every `(` needs a matching `)`"),
+                )));
             }
 
             *finished = true;
 
             return Ok(handle_close(&parent_node, depth));
         }
-        TokenType::Identifier => {
+        TokenType::Not => {
+            *idx += 1;
+            return handle_not(
+                tokens,
+                idx,
+                depth,
+                finished,
+                closing_paren,
+                opened_paren,
+                closing_or,
+            );
+        }
+        TokenType::Identifier
+        | TokenType::StringLiteral
+        | TokenType::NumberLiteral
+        | TokenType::Minus
+        | TokenType::OpenParen => {
             return handle_literal(
                 tokens,
                 idx,
@@ -391,10 +883,18 @@ fn parse_child(
             );
         }
         _ => {
-            return Err(format!(
-                "Unexpected token found while parsing conditional expression -> {}",
-                tokens[*idx].lexeme
-            ));
+            return Err(EaqlError::UnexpectedToken(Box::new(
+                ErrorContext::at(
+                    format!(
+                        "Unexpected token found while parsing conditional expression -> {}",
+                        tokens[*idx].lexeme
+                    ),
+                    &tokens[*idx],
+                )
+                .with_hint(
+                    "expected a condition like `price < 5`, joined to others with `and` or `or`",
+                ),
+            )));
         }
     }
 }
@@ -408,7 +908,7 @@ fn recurse_down(
     closing_paren: &mut bool,
     opened_paren: &mut u16,
     closing_or: &mut bool,
-) -> Result<ConditionChild, String> {
+) -> Result<ConditionChild, EaqlError> {
     // We aren't finished yet so we might need to parse
     // properly
     if *finished {
@@ -427,7 +927,7 @@ fn recurse_down(
             return Ok(ConditionChild::Op(Box::new(OperandNode {
                 _type: "OR".to_string(),
                 _depth: depth,
-                _ls: match recurse_down(
+                _ls: recurse_down(
                     tokens,
                     idx,
                     depth + 1,
@@ -436,11 +936,8 @@ fn recurse_down(
                     closing_paren,
                     opened_paren,
                     closing_or,
-                ) {
-                    Ok(node) => node,
-                    Err(msg) => return Err(msg),
-                },
-                _rs: match recurse_down(
+                )?,
+                _rs: recurse_down(
                     tokens,
                     idx,
                     depth + 1,
@@ -449,10 +946,7 @@ fn recurse_down(
                     closing_paren,
                     opened_paren,
                     closing_or,
-                ) {
-                    Ok(node) => node,
-                    Err(msg) => return Err(msg),
-                },
+                )?,
             })));
         }
 
@@ -475,44 +969,18 @@ impl ConditionNode {
     /// Reconstructs conditional literal from provided tokens
     /// and bounds.
     fn reconstruct_literal(tokens: &Vec<Token>, start_idx: usize, end_idx: usize) -> String {
-        // Reconstruct literal
-        let mut i: usize = start_idx;
         let mut literal = String::new();
 
-        while i < end_idx {
-            match tokens[i].token_type {
-                TokenType::OpenParen | TokenType::CloseParen => {
-                    literal.push_str(&tokens[i].lexeme);
-                    i += 1;
-                }
-                TokenType::And | TokenType::Or => {
-                    literal.push_str(&format!(" {} ", &tokens[i].lexeme));
-                    i += 1;
-                }
-                TokenType::Identifier => {
-                    let mut j = i;
-                    let mut parts = Vec::new();
-
-                    while parts.len() < 3 {
-                        parts.push(if tokens[j].token_type == TokenType::Equal {
-                            "=".to_string()
-                        } else {
-                            tokens[j].lexeme.clone()
-                        });
-                        j += 1;
-                    }
-
-                    if !parts.is_empty() {
-                        literal.push_str(&parts.join(" "));
-                    }
-
-                    i = j;
-                }
-                _ => {
-                    literal.push_str(&tokens[i].lexeme);
-                    i += 1;
-                }
+        for (i, token) in tokens[start_idx..end_idx].iter().enumerate() {
+            // Parentheses hug their contents, everything else is spaced
+            if i != 0
+                && token.token_type != TokenType::CloseParen
+                && tokens[start_idx + i - 1].token_type != TokenType::OpenParen
+            {
+                literal.push(' ');
             }
+
+            literal.push_str(&token.lexeme);
         }
 
         literal
@@ -525,17 +993,33 @@ impl ConditionNode {
         tokens: &Vec<Token>,
         idx: &mut usize,
         depth: u16,
-    ) -> Result<ConditionNode, String> {
+    ) -> Result<ConditionNode, EaqlError> {
         let mut finished: bool = false;
         let mut closing_paren: bool = false;
         let mut opened_paren: u16 = 0;
         let mut closing_or: bool = false;
         let start_idx: usize = *idx;
 
+        // A leading "and"/"or" has nothing on its left to join
+        if let Some(token) = tokens.get(*idx)
+            && matches!(token.token_type, TokenType::And | TokenType::Or)
+        {
+            return Err(EaqlError::EmptyCondition(Box::new(
+                ErrorContext::at(
+                    format!("Expected a condition before \"{}\"", token.lexeme),
+                    token,
+                )
+                .with_hint(&format!(
+                    "expected a condition like `price < 5` before `{}`",
+                    token.lexeme
+                )),
+            )));
+        }
+
         let ret: ConditionChild = ConditionChild::Op(Box::new(OperandNode {
             _type: "OR".to_string(),
             _depth: depth + 1,
-            _ls: match recurse_down(
+            _ls: recurse_down(
                 tokens,
                 idx,
                 depth + 2,
@@ -544,11 +1028,8 @@ impl ConditionNode {
                 &mut closing_paren,
                 &mut opened_paren,
                 &mut closing_or,
-            ) {
-                Ok(node) => node,
-                Err(msg) => return Err(msg),
-            },
-            _rs: match recurse_down(
+            )?,
+            _rs: recurse_down(
                 tokens,
                 idx,
                 depth + 2,
@@ -557,150 +1038,863 @@ impl ConditionNode {
                 &mut closing_paren,
                 &mut opened_paren,
                 &mut closing_or,
-            ) {
-                Ok(node) => node,
-                Err(msg) => return Err(msg),
-            },
+            )?,
         }));
 
         if opened_paren != 0 {
-            return Err("Conditional had unclosed parentheses".to_string());
+            return Err(EaqlError::UnbalancedParen(Box::new(
+                ErrorContext::after(
+                    "Conditional had unclosed parentheses".to_string(),
+                    &tokens[(*idx).min(tokens.len()) - 1],
+                )
+                .with_hint("every `(` needs a matching `)`"),
+            )));
+        }
+
+        if let ConditionChild::Bool(_) = ret.simplify() {
+            return Err(EaqlError::EmptyCondition(Box::new(
+                ErrorContext::after(
+                    "Filter keyword found, but no condition was provided after it!".to_string(),
+                    &tokens[start_idx.saturating_sub(1)],
+                )
+                .with_hint("expected a condition like `price < 5` after the filter"),
+            )));
         }
 
         return Ok(ConditionNode {
             _condition: ret,
+            _span: span_between(tokens, start_idx, *idx),
             _depth: depth,
             _literal: ConditionNode::reconstruct_literal(tokens, start_idx, *idx),
         });
     }
 
-    /// Outputs current AST node transpiled with color         
+    /// Outputs current AST node transpiled with color
     /// and it's raw query counterpart. Output are used by
     /// the Transpiler REPL.
-    pub fn transpile_color(&self) -> String {
-        format!("{}", self._literal)
+    pub fn transpile_color(&self, dialect: &dyn Dialect) -> Result<(String, String), EaqlError> {
+        Ok((
+            self._literal.clone(),
+            sql::transpile_color(dialect, |writer| writer.visit_condition(self))?.1,
+        ))
     }
 
     /// Outputs current AST node transpiled to raw SQL
-    pub fn transpile_raw(&self) -> String {
-        format!("{}", self._literal)
+    pub fn transpile_raw(&self, dialect: &dyn Dialect) -> Result<String, EaqlError> {
+        sql::transpile_raw(dialect, |writer| writer.visit_condition(self))
     }
-}
 
-impl ExpressionNode {
-    /// Takes current node type and given the current location in the
-    /// query defined by the borrowed index, makes an attempt to parse
-    /// this node and associated subnodes for the Abstract Syntax Tree.
-    pub fn parse(
-        tokens: &Vec<Token>,
-        idx: &mut usize,
-        depth: u16,
-    ) -> Result<ExpressionNode, String> {
-        validate_length(tokens, &(*idx + 2), true)?;
+    /// The root of the condition tree.
+    pub fn condition(&self) -> &ConditionChild {
+        &self._condition
+    }
 
-        let identifier: Token;
-        let comparison_operator: Token;
-        let literal: Token;
+    pub fn condition_mut(&mut self) -> &mut ConditionChild {
+        &mut self._condition
+    }
 
-        if tokens[*idx].token_type == TokenType::Identifier {
-            identifier = tokens[*idx].clone();
-            *idx += 1;
-        } else {
-            return Err(valid_until_warning(tokens, idx));
-        }
+    pub(crate) fn literal(&self) -> &str {
+        &self._literal
+    }
 
-        if vec![
-            TokenType::Equal,
-            TokenType::Lte,
+    pub(crate) fn depth(&self) -> u16 {
+        self._depth
+    }
+
+    pub(crate) fn new(condition: ConditionChild, literal: String, depth: u16) -> ConditionNode {
+        ConditionNode {
+            _condition: condition,
+            _literal: literal,
+
+            _span: Span::default(),
+            _depth: depth,
+        }
+    }
+}
+
+impl ConditionChild {
+    /// Skips over the identity booleans the parser pads the tree
+    /// with (`x AND true`, `x OR false`) so only meaningful nodes remain.
+    pub(crate) fn simplify(&self) -> &ConditionChild {
+        if let ConditionChild::Op(op) = self {
+            let identity: bool = op._type == "AND";
+
+            match (&op._ls, &op._rs) {
+                (ConditionChild::Bool(b), rs) if b._value == identity => return rs.simplify(),
+                (ls, ConditionChild::Bool(b)) if b._value == identity => return ls.simplify(),
+                _ => {}
+            }
+        }
+
+        self
+    }
+}
+
+/// Points out a comparison written in English that was left unfinished
+/// or could mean more than one thing (i.e. `is greater 5` or `is at 5`).
+/// `start` is where the comparison begins and `consumed` how many of
+/// its words were already read as the operator.
+fn unfinished_phrase(tokens: &Vec<Token>, start: usize, consumed: usize) -> Option<EaqlError> {
+    // The words written where the comparison is, alongside the index
+    // of the token each came from
+    let words: Vec<(String, usize)> = tokens[start..]
+        .iter()
+        .enumerate()
+        .take_while(|(_, v)| {
+            !matches!(
+                v.token_type,
+                TokenType::StringLiteral
+                    | TokenType::NumberLiteral
+                    | TokenType::BooleanLiteral
+                    | TokenType::NullLiteral
+                    | TokenType::EoqToken
+                    | TokenType::OpenParen
+                    | TokenType::CloseParen
+            )
+        })
+        .flat_map(|(i, v)| {
+            v.lexeme
+                .split_whitespace()
+                .map(move |word| (word.to_lowercase(), start + i))
+        })
+        .collect();
+
+    let starts_with = |phrase: &str, length: usize| -> bool {
+        let phrase: Vec<&str> = phrase.split(' ').collect();
+
+        phrase.len() > length
+            && words.len() >= length
+            && phrase[..length]
+                .iter()
+                .zip(&words)
+                .all(|(a, (b, _))| *a == b)
+    };
+
+    // Longest run of the written words some phrase starts with
+    let length: usize = (1..=words.len())
+        .rev()
+        .find(|length| {
+            COMPARISON_PHRASES
+                .iter()
+                .any(|(phrase, _)| starts_with(phrase, *length))
+        })
+        .filter(|length| *length > consumed)?;
+
+    let mut candidates: Vec<&str> = COMPARISON_PHRASES
+        .iter()
+        .map(|(phrase, _)| *phrase)
+        .filter(|phrase| starts_with(phrase, length))
+        .collect();
+    let written: &[(String, usize)] = &words[..length];
+
+    candidates.sort_by_key(|v| v.len());
+
+    // The words together aren't any known token, which also keeps them
+    // from being mistaken for a misspelled keyword
+    let token: Token = Token {
+        token_type: TokenType::UnknownToken,
+        span: tokens[start].span.to(tokens[written[length - 1].1].span),
+        ..tokens[start].clone()
+    };
+
+    Some(EaqlError::UnexpectedToken(Box::new(
+        ErrorContext::at(
+            format!(
+                "Couldn't tell which comparison \"{}\" was meant to be.",
+                written
+                    .iter()
+                    .map(|(word, _)| word.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(" ")
+            ),
+            &token,
+        )
+        .with_hint(&format!(
+            "did you mean {}?",
+            match candidates.split_last() {
+                Some((last, [])) => format!("`{}`", last),
+                Some((last, rest)) => format!("`{}` or `{}`", rest.join("`, `"), last),
+                None => String::new(),
+            }
+        )),
+    )))
+}
+
+impl ExpressionNode {
+    /// Takes current node type and given the current location in the
+    /// query defined by the borrowed index, makes an attempt to parse
+    /// this node and associated subnodes for the Abstract Syntax Tree.
+    pub fn parse(
+        tokens: &Vec<Token>,
+        idx: &mut usize,
+        depth: u16,
+    ) -> Result<ExpressionNode, EaqlError> {
+        validate_length(tokens, &(*idx + 2), true)?;
+
+        let identifier: Token;
+        let comparison_operator: Token;
+        let literal: Token;
+
+        if tokens[*idx].token_type == TokenType::Identifier {
+            identifier = tokens[*idx].clone();
+            *idx += 1;
+        } else {
+            return Err(valid_until_warning(tokens, idx)
+                .with_hint("expected a column name to compare, i.e. `price < 5`"));
+        }
+
+        let operator_idx: usize = *idx;
+
+        if let Some(token) = is_not(tokens, idx) {
+            comparison_operator = token;
+            *idx += 2;
+            validate_length(tokens, idx, true)?;
+        } else if [
+            TokenType::Equal,
+            TokenType::NotEqual,
+            TokenType::Lte,
             TokenType::Lt,
             TokenType::Gt,
             TokenType::Gte,
+            TokenType::Contains,
+            TokenType::StartsWith,
+            TokenType::EndsWith,
+            TokenType::Like,
+            TokenType::IsEmpty,
+            TokenType::IsNotEmpty,
         ]
         .contains(&tokens[*idx].token_type)
         {
             comparison_operator = tokens[*idx].clone();
             *idx += 1;
         } else {
-            return Err(valid_until_warning(tokens, idx));
+            return Err(unfinished_phrase(tokens, *idx, 0).unwrap_or_else(|| {
+                valid_until_warning(tokens, idx)
+                    .with_hint("expected a comparison like `is`, `>`, `<=`")
+            }));
         }
 
-        if tokens[*idx].token_type == TokenType::StringLiteral
-            || tokens[*idx].token_type == TokenType::NumberLiteral
+        let pattern: bool = matches!(
+            comparison_operator.token_type,
+            TokenType::Contains | TokenType::StartsWith | TokenType::EndsWith | TokenType::Like
+        );
+
+        let exact: bool = matches!(
+            comparison_operator.token_type,
+            TokenType::Equal | TokenType::NotEqual
+        );
+
+        if matches!(
+            comparison_operator.token_type,
+            TokenType::IsEmpty | TokenType::IsNotEmpty
+        ) {
+            // "is empty" has nothing written after it, it's compared
+            // against null the same as "is null" would be
+            literal = Token {
+                span: comparison_operator.span,
+                position: comparison_operator.position,
+                ..Token::new(TokenType::NullLiteral, &"".to_string(), &"null".to_string())
+            };
+        } else if pattern && tokens[*idx].token_type == TokenType::NumberLiteral {
+            return Err(EaqlError::InvalidValue(Box::new(
+                ErrorContext::at(
+                    format!(
+                        "Only text can be matched with \"{}\", got the number {} instead.",
+                        comparison_operator.lexeme, tokens[*idx].lexeme
+                    ),
+                    &tokens[*idx],
+                )
+                .with_hint(&format!(
+                    "write the value as text, i.e. `{} \"{}\"`",
+                    comparison_operator.lexeme, tokens[*idx].lexeme
+                )),
+            )));
+        } else if !exact
+            && matches!(
+                tokens[*idx].token_type,
+                TokenType::BooleanLiteral | TokenType::NullLiteral
+            )
         {
+            return Err(EaqlError::InvalidValue(Box::new(
+                ErrorContext::at(
+                    format!(
+                        "Only `is` and `is not` can compare against \"{}\", not \"{}\".",
+                        tokens[*idx].lexeme, comparison_operator.lexeme
+                    ),
+                    &tokens[*idx],
+                )
+                .with_hint("`true`, `false` and `null` are compared with `is` or `is not`"),
+            )));
+        } else if matches!(
+            tokens[*idx].token_type,
+            TokenType::StringLiteral
+                | TokenType::NumberLiteral
+                | TokenType::BooleanLiteral
+                | TokenType::NullLiteral
+        ) {
             literal = tokens[*idx].clone();
             *idx += 1;
         } else {
-            return Err(valid_until_warning(tokens, idx));
+            let consumed: usize = comparison_operator.lexeme.split_whitespace().count();
+
+            return Err(
+                unfinished_phrase(tokens, operator_idx, consumed).unwrap_or_else(|| {
+                    valid_until_warning(tokens, idx)
+                        .with_hint("expected a value to compare against, i.e. `5` or `\"large\"`")
+                }),
+            );
         }
 
+        // "ignoring case" can follow a pattern, but comparing any other
+        // way is always exact
+        let ignore_case: Option<Token> = match tokens.get(*idx) {
+            Some(token) if token.token_type == TokenType::IgnoreCase && pattern => {
+                *idx += 1;
+                Some(token.clone())
+            }
+            Some(token) if token.token_type == TokenType::IgnoreCase => {
+                return Err(EaqlError::UnexpectedToken(Box::new(
+                    ErrorContext::at(
+                        format!(
+                            "Only text matched against a pattern can ignore case, not \"{}\".",
+                            comparison_operator.lexeme
+                        ),
+                        token,
+                    )
+                    .with_hint(
+                        "`ignoring case` follows `contains`, `starts with`, `ends with` or `like`",
+                    ),
+                )));
+            }
+            _ => None,
+        };
+
         return Ok(ExpressionNode {
             _identifier: identifier,
             _comparison_operator: comparison_operator,
             _literal: literal,
+            _ignore_case: ignore_case,
 
             _depth: depth,
         });
     }
+
+    /// Outputs current AST node transpiled with color
+    /// and it's raw query counterpart. Output are used by
+    /// the Transpiler REPL.
+    pub fn transpile_color(&self, dialect: &dyn Dialect) -> Result<String, EaqlError> {
+        Ok(sql::transpile_color(dialect, |writer| writer.visit_expression(self))?.1)
+    }
+
+    /// Outputs current AST node transpiled to raw SQL
+    pub fn transpile_raw(&self, dialect: &dyn Dialect) -> Result<String, EaqlError> {
+        sql::transpile_raw(dialect, |writer| writer.visit_expression(self))
+    }
+
+    /// Name of the column compared.
+    pub fn column(&self) -> &str {
+        &self._identifier.lexeme
+    }
+
+    pub fn operator(&self) -> ComparisonOperator {
+        match self._comparison_operator.token_type {
+            TokenType::Equal => ComparisonOperator::Equal,
+            TokenType::NotEqual => ComparisonOperator::NotEqual,
+            TokenType::Lt => ComparisonOperator::LessThan,
+            TokenType::Lte => ComparisonOperator::LessThanOrEqual,
+            TokenType::Gt => ComparisonOperator::GreaterThan,
+            TokenType::Gte => ComparisonOperator::GreaterThanOrEqual,
+            TokenType::IsEmpty => ComparisonOperator::Equal,
+            TokenType::IsNotEmpty => ComparisonOperator::NotEqual,
+            TokenType::Contains => ComparisonOperator::Contains(self.case()),
+            TokenType::StartsWith => ComparisonOperator::StartsWith(self.case()),
+            TokenType::EndsWith => ComparisonOperator::EndsWith(self.case()),
+            TokenType::Like => ComparisonOperator::Like(self.case()),
+            _ => unreachable!("Expressions are only parsed with comparison operators"),
+        }
+    }
+
+    fn case(&self) -> Case {
+        match self._ignore_case {
+            Some(_) => Case::Insensitive,
+            None => Case::Sensitive,
+        }
+    }
+
+    /// The value the column is compared against.
+    pub fn value(&self) -> Value<'_> {
+        value(&self._literal)
+    }
+
+    pub(crate) fn identifier_token(&self) -> &Token {
+        &self._identifier
+    }
+
+    pub(crate) fn operator_token(&self) -> &Token {
+        &self._comparison_operator
+    }
+
+    pub(crate) fn value_token(&self) -> &Token {
+        &self._literal
+    }
+
+    pub(crate) fn ignore_case_token(&self) -> Option<&Token> {
+        self._ignore_case.as_ref()
+    }
+
+    pub(crate) fn depth(&self) -> u16 {
+        self._depth
+    }
+
+    /// Builds an expression from its three tokens, they should look
+    /// like the ones the lexer would have produced.
+    pub(crate) fn new(
+        identifier: Token,
+        comparison_operator: Token,
+        literal: Token,
+        ignore_case: Option<Token>,
+        depth: u16,
+    ) -> ExpressionNode {
+        ExpressionNode {
+            _identifier: identifier,
+            _comparison_operator: comparison_operator,
+            _literal: literal,
+            _ignore_case: ignore_case,
+
+            _depth: depth,
+        }
+    }
+}
+
+impl ListNode {
+    /// Takes current node type and given the current location in the
+    /// query defined by the borrowed index, makes an attempt to parse
+    /// this node and associated subnodes for the Abstract Syntax Tree.
+    pub fn parse(tokens: &Vec<Token>, idx: &mut usize, depth: u16) -> Result<ListNode, EaqlError> {
+        validate_length(tokens, &(*idx + 2), true)?;
+
+        let start_idx: usize = *idx;
+        let identifier: Token = tokens[*idx].clone();
+        let operator: Token = tokens[*idx + 1].clone();
+        let mut values: Vec<Token> = vec![];
+
+        *idx += 2;
+
+        if tokens[*idx].token_type != TokenType::OpenParen {
+            return Err(valid_until_warning(tokens, idx).with_hint(&format!(
+                "lists of values are wrapped in parentheses, i.e. `{} (\"coffee\", \"tea\")`",
+                operator.lexeme
+            )));
+        }
+
+        *idx += 1;
+
+        // Values are separated by commas, and the last may also be
+        // joined on with "and" or "or" the way it would be said
+        loop {
+            values.push(parse_value(
+                tokens,
+                idx,
+                "expected a value in the list, i.e. `5` or `\"tea\"`",
+            )?);
+            validate_length(tokens, idx, true)?;
+
+            match tokens[*idx].token_type {
+                TokenType::Comma => *idx += 1,
+                TokenType::And | TokenType::Or => {
+                    *idx += 1;
+                    values.push(parse_value(
+                        tokens,
+                        idx,
+                        "expected a value in the list, i.e. `5` or `\"tea\"`",
+                    )?);
+                    validate_length(tokens, idx, true)?;
+
+                    if tokens[*idx].token_type != TokenType::CloseParen {
+                        return Err(valid_until_warning(tokens, idx)
+                            .with_hint("the value after `and` or `or` ends the list with a `)`"));
+                    }
+
+                    *idx += 1;
+                    break;
+                }
+                TokenType::CloseParen => {
+                    *idx += 1;
+                    break;
+                }
+                _ => {
+                    return Err(EaqlError::UnbalancedParen(Box::new(
+                        ErrorContext::at(
+                            format!(
+                                "List of values was never closed, got -> {}",
+                                tokens[*idx].lexeme
+                            ),
+                            &tokens[*idx],
+                        )
+                        .with_hint("values are separated by `,` and the list ends with a `)`"),
+                    )));
+                }
+            }
+        }
+
+        Ok(ListNode {
+            _identifier: identifier,
+            _operator: operator,
+            _values: values,
+
+            _span: span_between(tokens, start_idx, *idx),
+            _depth: depth,
+        })
+    }
+
+    /// Name of the column compared.
+    pub fn column(&self) -> &str {
+        &self._identifier.lexeme
+    }
+
+    /// Whether the column must be none of the values (`is not one of`).
+    pub fn negated(&self) -> bool {
+        self._operator.token_type == TokenType::NotOneOf
+    }
+
+    pub fn values(&self) -> Vec<Value<'_>> {
+        self._values.iter().map(value).collect()
+    }
+
+    pub(crate) fn identifier_token(&self) -> &Token {
+        &self._identifier
+    }
+
+    pub(crate) fn operator_token(&self) -> &Token {
+        &self._operator
+    }
+
+    pub(crate) fn value_tokens(&self) -> &[Token] {
+        &self._values
+    }
+
+    pub(crate) fn depth(&self) -> u16 {
+        self._depth
+    }
+
+    /// Builds a list from its tokens, they should look like the ones
+    /// the lexer would have produced.
+    pub(crate) fn new(
+        identifier: Token,
+        operator: Token,
+        values: Vec<Token>,
+        depth: u16,
+    ) -> ListNode {
+        ListNode {
+            _identifier: identifier,
+            _operator: operator,
+            _values: values,
+
+            _span: Span::default(),
+            _depth: depth,
+        }
+    }
+}
+
+impl RangeNode {
+    /// Takes current node type and given the current location in the
+    /// query defined by the borrowed index, makes an attempt to parse
+    /// this node and associated subnodes for the Abstract Syntax Tree.
+    pub fn parse(tokens: &Vec<Token>, idx: &mut usize, depth: u16) -> Result<RangeNode, EaqlError> {
+        validate_length(tokens, &(*idx + 2), true)?;
+
+        let identifier: Token = tokens[*idx].clone();
+        let operator: Token = tokens[*idx + 1].clone();
+
+        *idx += 2;
+
+        let low: Token = parse_value(
+            tokens,
+            idx,
+            &format!(
+                "expected where the range starts, i.e. `{} 2 and 5`",
+                operator.lexeme
+            ),
+        )?;
+        validate_length(tokens, idx, true)?;
+
+        // This "and" belongs to the range rather than joining conditions
+        if tokens[*idx].token_type != TokenType::And {
+            return Err(valid_until_warning(tokens, idx).with_hint(&format!(
+                "ranges are written as `{} {} and ...`",
+                operator.lexeme, low.lexeme
+            )));
+        }
+
+        *idx += 1;
+
+        let high: Token = parse_value(
+            tokens,
+            idx,
+            &format!(
+                "expected where the range ends, i.e. `{} {} and 5`",
+                operator.lexeme, low.lexeme
+            ),
+        )?;
+
+        Ok(RangeNode {
+            _identifier: identifier,
+            _operator: operator,
+            _low: low,
+            _high: high,
+
+            _depth: depth,
+        })
+    }
+
+    /// Name of the column compared.
+    pub fn column(&self) -> &str {
+        &self._identifier.lexeme
+    }
+
+    /// Whether the column must be outside of the range (`is not between`).
+    pub fn negated(&self) -> bool {
+        self._operator.token_type == TokenType::NotBetween
+    }
+
+    pub fn low(&self) -> Value<'_> {
+        value(&self._low)
+    }
+
+    pub fn high(&self) -> Value<'_> {
+        value(&self._high)
+    }
+
+    pub(crate) fn identifier_token(&self) -> &Token {
+        &self._identifier
+    }
+
+    pub(crate) fn operator_token(&self) -> &Token {
+        &self._operator
+    }
+
+    /// The tokens of the low and high ends of the range.
+    pub(crate) fn value_tokens(&self) -> [&Token; 2] {
+        [&self._low, &self._high]
+    }
+
+    pub(crate) fn depth(&self) -> u16 {
+        self._depth
+    }
+
+    /// Builds a range from its tokens, they should look like the ones
+    /// the lexer would have produced.
+    pub(crate) fn new(
+        identifier: Token,
+        operator: Token,
+        low: Token,
+        high: Token,
+        depth: u16,
+    ) -> RangeNode {
+        RangeNode {
+            _identifier: identifier,
+            _operator: operator,
+            _low: low,
+            _high: high,
+
+            _depth: depth,
+        }
+    }
+}
+
+impl ComparisonNode {
+    /// Takes current node type and given the current location in the
+    /// query defined by the borrowed index, makes an attempt to parse
+    /// this node and associated subnodes for the Abstract Syntax Tree.
+    pub fn parse(
+        tokens: &Vec<Token>,
+        idx: &mut usize,
+        depth: u16,
+    ) -> Result<ComparisonNode, EaqlError> {
+        let ls: ArithmeticChild = ArithmeticChild::parse(tokens, idx)?;
+        let comparison_operator: Token;
+
+        validate_length(tokens, idx, true)?;
+
+        if let Some(token) = is_not(tokens, idx) {
+            comparison_operator = token;
+            *idx += 2;
+        } else if matches!(
+            tokens[*idx].token_type,
+            TokenType::Equal
+                | TokenType::NotEqual
+                | TokenType::Lt
+                | TokenType::Lte
+                | TokenType::Gt
+                | TokenType::Gte
+        ) {
+            comparison_operator = tokens[*idx].clone();
+            *idx += 1;
+        } else {
+            // Patterns, lists, ranges and emptiness only apply to a
+            // column on its own
+            return Err(valid_until_warning(tokens, idx).with_hint(
+                "arithmetic and columns are compared with `is`, `is not`, `<`, `<=`, `>` or `>=`",
+            ));
+        }
+
+        Ok(ComparisonNode {
+            _ls: ls,
+            _comparison_operator: comparison_operator,
+            _rs: ArithmeticChild::parse(tokens, idx)?,
+
+            _depth: depth,
+        })
+    }
+
+    pub fn left(&self) -> &ArithmeticChild {
+        &self._ls
+    }
+
+    pub fn right(&self) -> &ArithmeticChild {
+        &self._rs
+    }
+
+    pub fn left_mut(&mut self) -> &mut ArithmeticChild {
+        &mut self._ls
+    }
+
+    pub fn right_mut(&mut self) -> &mut ArithmeticChild {
+        &mut self._rs
+    }
+
+    pub fn operator(&self) -> ComparisonOperator {
+        match self._comparison_operator.token_type {
+            TokenType::Equal => ComparisonOperator::Equal,
+            TokenType::NotEqual => ComparisonOperator::NotEqual,
+            TokenType::Lt => ComparisonOperator::LessThan,
+            TokenType::Lte => ComparisonOperator::LessThanOrEqual,
+            TokenType::Gt => ComparisonOperator::GreaterThan,
+            TokenType::Gte => ComparisonOperator::GreaterThanOrEqual,
+            _ => unreachable!("Comparisons are only parsed with plain comparison operators"),
+        }
+    }
+
+    pub(crate) fn operator_token(&self) -> &Token {
+        &self._comparison_operator
+    }
+
+    pub(crate) fn depth(&self) -> u16 {
+        self._depth
+    }
+
+    /// Builds a comparison from its sides and operator token, it should
+    /// look like the one the lexer would have produced.
+    pub(crate) fn new(
+        left: ArithmeticChild,
+        comparison_operator: Token,
+        right: ArithmeticChild,
+        depth: u16,
+    ) -> ComparisonNode {
+        ComparisonNode {
+            _ls: left,
+            _comparison_operator: comparison_operator,
+            _rs: right,
+
+            _depth: depth,
+        }
+    }
+}
+
+/// The value a literal token was written as.
+pub(crate) fn value(token: &Token) -> Value<'_> {
+    match token.token_type {
+        TokenType::StringLiteral => Value::Text(&token.literal),
+        TokenType::BooleanLiteral => Value::Bool(token.lexeme.eq_ignore_ascii_case("true")),
+        TokenType::NullLiteral => Value::Null,
+        _ => Value::Number(&token.literal),
+    }
 }
 
 // Display functions
 impl fmt::Display for ConditionChild {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            ConditionChild::Op(node) => write!(f, "{node}"),
-            ConditionChild::Expr(node) => write!(f, "{node}"),
-            ConditionChild::Bool(node) => write!(f, "{node}"),
-        }
+        TreeWriter::write(f, |writer| writer.visit_condition_child(self))
     }
 }
 
 impl fmt::Display for ConditionNode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "\n{}(Condition){}{}",
-            get_tab(self._depth),
-            get_tab(self._depth + 1),
-            self._condition
-        )
+        TreeWriter::write(f, |writer| writer.visit_condition(self))
     }
 }
 
 impl fmt::Display for OperandNode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "\n{}(Operand::{}){}{}",
-            get_tab(self._depth),
-            self._type,
-            self._ls,
-            self._rs,
-        )
+        TreeWriter::write(f, |writer| writer.visit_operand(self))
+    }
+}
+
+impl fmt::Display for NotNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        TreeWriter::write(f, |writer| writer.visit_not(self))
     }
 }
 
 impl fmt::Display for BoolNode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "\n{}(Bool::{})", get_tab(self._depth), self._value,)
+        TreeWriter::write(f, |writer| writer.visit_bool(self))
     }
 }
 
 impl fmt::Display for ExpressionNode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "\n{}(Expression)
-{}variable: {:?}
-{}operator: {:?}
-{}value: {:?}",
-            get_tab(self._depth),
-            get_tab(self._depth + 1),
-            self._identifier.lexeme,
-            get_tab(self._depth + 1),
-            self._comparison_operator.token_type,
-            get_tab(self._depth + 1),
-            self._literal.literal
-        )
+        TreeWriter::write(f, |writer| writer.visit_expression(self))
+    }
+}
+
+impl fmt::Display for ListNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        TreeWriter::write(f, |writer| writer.visit_list(self))
+    }
+}
+
+impl fmt::Display for RangeNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        TreeWriter::write(f, |writer| writer.visit_range(self))
+    }
+}
+
+impl fmt::Display for ComparisonNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        TreeWriter::write(f, |writer| writer.visit_comparison(self))
+    }
+}
+
+impl Spanned for ConditionNode {
+    fn span(&self) -> Span {
+        self._span
+    }
+}
+
+impl Spanned for ExpressionNode {
+    fn span(&self) -> Span {
+        let end: &Token = self._ignore_case.as_ref().unwrap_or(&self._literal);
+
+        self._identifier.span.to(end.span)
+    }
+}
+
+impl Spanned for ListNode {
+    fn span(&self) -> Span {
+        self._span
+    }
+}
+
+impl Spanned for RangeNode {
+    fn span(&self) -> Span {
+        self._identifier.span.to(self._high.span)
+    }
+}
+
+impl Spanned for ComparisonNode {
+    fn span(&self) -> Span {
+        self._ls.span().to(self._rs.span())
     }
 }
 
@@ -708,6 +1902,7 @@ impl fmt::Display for ExpressionNode {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{language::lexer::scan_tokens, transpiler::dialect::Generic};
 
     #[test]
     fn unit_test_expression_parsing_normal() {
@@ -723,6 +1918,7 @@ mod tests {
             _identifier: Token::new(TokenType::Identifier, &"".to_string(), &"id".to_string()),
             _comparison_operator: Token::new(TokenType::Equal, &"".to_string(), &"is".to_string()),
             _literal: Token::new(TokenType::NumberLiteral, &"5".to_string(), &"5".to_string()),
+            _ignore_case: None,
 
             _depth: 0,
         };
@@ -751,4 +1947,136 @@ mod tests {
             Err(err) => assert!(true, "Output errored out -> {}", err),
         }
     }
+
+    #[test]
+    fn unit_test_expression_parsing_is_not() {
+        let input: Vec<Token> = vec![
+            Token::new(TokenType::Identifier, &"".to_string(), &"id".to_string()),
+            Token::new(TokenType::Equal, &"".to_string(), &"is".to_string()),
+            Token::new(TokenType::Not, &"".to_string(), &"not".to_string()),
+            Token::new(TokenType::NumberLiteral, &"5".to_string(), &"5".to_string()),
+        ];
+
+        let mut idx: usize = 0;
+        let depth: u16 = 0;
+        let expected: ExpressionNode = ExpressionNode {
+            _identifier: Token::new(TokenType::Identifier, &"".to_string(), &"id".to_string()),
+            _comparison_operator: Token::new(
+                TokenType::NotEqual,
+                &"".to_string(),
+                &"is not".to_string(),
+            ),
+            _literal: Token::new(TokenType::NumberLiteral, &"5".to_string(), &"5".to_string()),
+            _ignore_case: None,
+
+            _depth: 0,
+        };
+
+        match ExpressionNode::parse(&input, &mut idx, depth) {
+            Ok(val) => {
+                assert_eq!(expected, val);
+                assert_eq!(idx, 4);
+            }
+            Err(err) => assert!(false, "Output errored out -> {}", err),
+        }
+    }
+
+    #[test]
+    fn unit_test_condition_parsing_not_group() {
+        let input: Vec<Token> = vec![
+            Token::new(TokenType::Not, &"".to_string(), &"not".to_string()),
+            Token::new(TokenType::OpenParen, &"".to_string(), &"(".to_string()),
+            Token::new(TokenType::Identifier, &"a".to_string(), &"a".to_string()),
+            Token::new(TokenType::Equal, &"".to_string(), &"=".to_string()),
+            Token::new(TokenType::NumberLiteral, &"1".to_string(), &"1".to_string()),
+            Token::new(TokenType::Or, &"".to_string(), &"or".to_string()),
+            Token::new(TokenType::Identifier, &"b".to_string(), &"b".to_string()),
+            Token::new(TokenType::Equal, &"".to_string(), &"=".to_string()),
+            Token::new(TokenType::NumberLiteral, &"2".to_string(), &"2".to_string()),
+            Token::new(TokenType::CloseParen, &"".to_string(), &")".to_string()),
+            Token::new(TokenType::EoqToken, &"".to_string(), &".".to_string()),
+        ];
+
+        let mut idx: usize = 0;
+
+        match ConditionNode::parse(&input, &mut idx, 0) {
+            Ok(val) => assert_eq!(
+                val.transpile_raw(&Generic),
+                Ok("NOT (a = 1 OR b = 2)".to_string())
+            ),
+            Err(err) => assert!(false, "Output errored out -> {}", err),
+        }
+    }
+
+    #[test]
+    fn unit_test_condition_parsing_arithmetic_group() {
+        let tokens: Vec<Token> =
+            scan_tokens(&"(a + b) * 2 > c and (a = 1 or (b) = c);".to_string())
+                .unwrap()
+                .tokens;
+
+        // Parentheses followed by arithmetic or a comparison group arithmetic
+        assert!(arithmetic_group(&tokens, &0));
+        assert!(!arithmetic_group(&tokens, &10));
+        assert!(arithmetic_group(&tokens, &15));
+
+        match ConditionNode::parse(&tokens, &mut 0, 0) {
+            Ok(val) => assert_eq!(
+                val.transpile_raw(&Generic),
+                Ok("(a + b) * 2 > c AND (a = 1 OR b = c)".to_string())
+            ),
+            Err(err) => assert!(false, "Output errored out -> {}", err),
+        }
+    }
+
+    #[test]
+    fn unit_test_condition_parsing_group_depths() {
+        // Every operator's sides sit exactly one level below it
+        fn check(node: &ConditionChild) {
+            if let ConditionChild::Op(op) = node {
+                for side in [op.left(), op.right()] {
+                    let depth: u16 = match side {
+                        ConditionChild::Op(state) => state.depth(),
+                        ConditionChild::Bool(state) => state.depth(),
+                        ConditionChild::Expr(state) => state.depth(),
+                        ConditionChild::Not(state) => state.depth(),
+                        ConditionChild::List(state) => state.depth(),
+                        ConditionChild::Range(state) => state.depth(),
+                        ConditionChild::Compare(state) => state.depth(),
+                    };
+
+                    assert_eq!(depth, op.depth() + 1);
+                    check(side);
+                }
+            }
+        }
+
+        let tokens: Vec<Token> = scan_tokens(&"(a = 1 or b = 2) and (c = 3) or d = 4;".to_string())
+            .unwrap()
+            .tokens;
+
+        match ConditionNode::parse(&tokens, &mut 0, 0) {
+            Ok(val) => check(val.condition()),
+            Err(err) => assert!(false, "Output errored out -> {}", err),
+        }
+    }
+
+    #[test]
+    fn unit_test_condition_parsing_not_error() {
+        let input: Vec<Token> = vec![
+            Token::new(TokenType::Not, &"".to_string(), &"not".to_string()),
+            Token::new(TokenType::OpenParen, &"".to_string(), &"(".to_string()),
+            Token::new(TokenType::Identifier, &"a".to_string(), &"a".to_string()),
+            Token::new(TokenType::Equal, &"".to_string(), &"=".to_string()),
+            Token::new(TokenType::NumberLiteral, &"1".to_string(), &"1".to_string()),
+            Token::new(TokenType::EoqToken, &"".to_string(), &".".to_string()),
+        ];
+
+        let mut idx: usize = 0;
+
+        match ConditionNode::parse(&input, &mut idx, 0) {
+            Ok(_val) => assert!(false, "Output expected to error!"),
+            Err(err) => assert!(true, "Output errored out -> {}", err),
+        }
+    }
 }
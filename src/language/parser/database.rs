@@ -7,20 +7,21 @@ Make the database db_name.
 
 use crate::{
     language::{
+        errors::{EaqlError, ErrorContext},
         parser::{
-            helpers::{get_tab, peek_one, validate_length},
+            display::TreeWriter,
+            helpers::{peek_one, span_between, validate_length},
             parser::ImpliedAction,
         },
-        tokens::{Token, TokenType},
-    },
-    utils::{
-        colors::{AnsiColor, colorize},
-        logger,
+        tokens::{Span, Spanned, Token, TokenType},
+        visitor::Visitor,
     },
+    transpiler::{dialect::Dialect, sql},
 };
-use std::{fmt, usize};
+use std::fmt;
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DatabaseNode {
     pub _create: Option<CreateNode>,
     pub _destroy: Option<DestroyNode>,
@@ -28,45 +29,62 @@ pub struct DatabaseNode {
     pub _show: Option<ShowNode>,
 
     _literal: String,
+    _span: Span,
     _depth: u16,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShowNode {
     _depth: u16,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DestroyNode {
     databases: Vec<String>,
 
     _literal: String,
+    _span: Span,
     _depth: u16,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CreateNode {
     name: String,
 
     _literal: String,
+    _span: Span,
     _depth: u16,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UseNode {
     name: String,
 
     _literal: String,
+    _span: Span,
     _depth: u16,
 }
 
+/// One of the operations a DatabaseNode can hold.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum DatabaseOperation<'a> {
+    Create(&'a CreateNode),
+    Destroy(&'a DestroyNode),
+    Use(&'a UseNode),
+    Show(&'a ShowNode),
+}
+
 impl DatabaseNode {
     pub fn parse(
         tokens: &Vec<Token>,
         idx: &mut usize,
         depth: u16,
         action: ImpliedAction,
-    ) -> Result<DatabaseNode, String> {
+    ) -> Result<DatabaseNode, EaqlError> {
         validate_length(tokens, idx, true)?;
 
         let mut database_node: DatabaseNode = DatabaseNode {
@@ -82,9 +100,13 @@ impl DatabaseNode {
                     .collect::<Vec<&str>>()
                     .join(" ")
             },
+            _span: Span::default(),
             _depth: depth,
         };
 
+        // We subtract 2 from this so the action and database keywords are included
+        let start_idx: usize = *idx - 2;
+
         match action {
             ImpliedAction::Create => {
                 database_node._create = match CreateNode::parse(tokens, idx, depth + 1) {
@@ -111,65 +133,100 @@ impl DatabaseNode {
                 }
             }
             _ => {
-                return Err(format!(
-                    "Got unexpected action requested for database -> '{:?}'",
-                    action
-                ));
+                return Err(EaqlError::UnknownAction(Box::new(ErrorContext::at(
+                    format!(
+                        "Got unexpected action requested for database -> '{:?}'",
+                        action
+                    ),
+                    &tokens[*idx - 2],
+                ))));
             }
         };
 
         if tokens[*idx].token_type != TokenType::EoqToken {
-            return Err(format!(
-                "Unexpected token '{}', expected end-of-query token by this point.",
-                tokens[*idx].lexeme
-            ));
+            return Err(EaqlError::UnexpectedToken(Box::new(
+                ErrorContext::at(
+                    format!(
+                        "Unexpected token '{}', expected end-of-query token by this point.",
+                        tokens[*idx].lexeme
+                    ),
+                    &tokens[*idx],
+                )
+                .with_hint("expected `;`, `.` or `!` to end the query"),
+            )));
         }
 
+        database_node._span = span_between(tokens, start_idx, *idx);
+
         return Ok(database_node);
     }
 
     /// Outputs current AST node transpiled with color         
     /// and it's raw query counterpart. Output are used by
     /// the Transpiler REPL.
-    pub fn transpile_color(&self) -> (String, String) {
-        let pair: (String, (String, String)) =
-            match (&self._create, &self._destroy, &self._show, &self._use) {
-                (Some(op), _, _, _) => ("CREATE DATABASE ".to_string(), op.transpile_color()),
-                (_, Some(op), _, _) => ("DROP DATABASE ".to_string(), op.transpile_color()),
-                (_, _, Some(op), _) => ("SHOW DATABASES".to_string(), op.transpile_color()),
-                (_, _, _, Some(op)) => ("USE DATABASE ".to_string(), op.transpile_color()),
-                _ => logger::error("No database operation provided"),
-            };
-
-        (
-            colorize(&self._literal, AnsiColor::Yellow)
-                + if *&pair.1.0.len() != 0 { &" " } else { "" }
-                + &pair.1.0,
-            format!("{}{}", colorize(&pair.0, AnsiColor::Yellow), &pair.1.1),
-        )
+    pub fn transpile_color(&self, dialect: &dyn Dialect) -> Result<(String, String), EaqlError> {
+        sql::transpile_color(dialect, |writer| writer.visit_database(self))
     }
 
     /// Outputs current AST node transpile to raw SQL.
-    pub fn transpile_raw(&self) -> String {
+    pub fn transpile_raw(&self, dialect: &dyn Dialect) -> Result<String, EaqlError> {
+        sql::transpile_raw(dialect, |writer| writer.visit_database(self))
+    }
+
+    /// The operation requested on the database(s).
+    pub fn operation(&self) -> Option<DatabaseOperation<'_>> {
         match (&self._create, &self._destroy, &self._show, &self._use) {
-            (Some(op), _, _, _) => "CREATE DATABASE ".to_string() + &op.transpile_raw(),
-            (_, Some(op), _, _) => "DROP DATABASE ".to_string() + &op.transpile_raw(),
-            (_, _, Some(op), _) => "SHOW DATABASES".to_string() + &op.transpile_raw(),
-            (_, _, _, Some(op)) => "USE DATABASE ".to_string() + &op.transpile_raw(),
-            _ => logger::error("No database operation provided"),
+            (Some(op), _, _, _) => Some(DatabaseOperation::Create(op)),
+            (_, Some(op), _, _) => Some(DatabaseOperation::Destroy(op)),
+            (_, _, Some(op), _) => Some(DatabaseOperation::Show(op)),
+            (_, _, _, Some(op)) => Some(DatabaseOperation::Use(op)),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn literal(&self) -> &str {
+        &self._literal
+    }
+
+    pub(crate) fn depth(&self) -> u16 {
+        self._depth
+    }
+
+    /// Assembles a node without an operation yet, used by the
+    /// query builder.
+    pub(crate) fn new(literal: String, depth: u16) -> DatabaseNode {
+        DatabaseNode {
+            _create: None,
+            _destroy: None,
+            _use: None,
+            _show: None,
+
+            _literal: literal,
+            _span: Span::default(),
+            _depth: depth,
         }
     }
 }
 
 impl CreateNode {
-    pub fn parse(tokens: &Vec<Token>, idx: &mut usize, depth: u16) -> Result<CreateNode, String> {
+    pub fn parse(
+        tokens: &Vec<Token>,
+        idx: &mut usize,
+        depth: u16,
+    ) -> Result<CreateNode, EaqlError> {
         validate_length(tokens, idx, true)?;
 
         if tokens[*idx].token_type != TokenType::Identifier {
-            return Err(format!(
-                "Expected identifier, got '{:?}' instead!",
-                tokens[*idx].token_type
-            ));
+            return Err(EaqlError::UnexpectedToken(Box::new(
+                ErrorContext::at(
+                    format!(
+                        "Expected identifier, got '{:?}' instead!",
+                        tokens[*idx].token_type
+                    ),
+                    &tokens[*idx],
+                )
+                .with_hint("expected a database name"),
+            )));
         }
 
         *idx += 1;
@@ -179,6 +236,7 @@ impl CreateNode {
             name: tokens[*idx - 1].literal.clone(),
 
             _literal: tokens[*idx - 1].lexeme.clone(),
+            _span: span_between(tokens, *idx - 1, *idx),
             _depth: depth,
         });
     }
@@ -186,16 +244,36 @@ impl CreateNode {
     /// Outputs current AST node transpiled with color         
     /// and it's raw query counterpart. Output are used by
     /// the Transpiler REPL.
-    pub fn transpile_color(&self) -> (String, String) {
-        (
-            colorize(&self._literal.as_str(), AnsiColor::Blue),
-            colorize(&self.name.as_str(), AnsiColor::Blue),
-        )
+    pub fn transpile_color(&self, dialect: &dyn Dialect) -> Result<(String, String), EaqlError> {
+        sql::transpile_color(dialect, |writer| writer.visit_create(self))
     }
 
     /// Outputs current AST node transpiled to raw SQL.
-    pub fn transpile_raw(&self) -> String {
-        self.name.clone()
+    pub fn transpile_raw(&self, dialect: &dyn Dialect) -> Result<String, EaqlError> {
+        sql::transpile_raw(dialect, |writer| writer.visit_create(self))
+    }
+
+    /// Name of the database created.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn literal(&self) -> &str {
+        &self._literal
+    }
+
+    pub(crate) fn depth(&self) -> u16 {
+        self._depth
+    }
+
+    pub(crate) fn new(name: &str, depth: u16) -> CreateNode {
+        CreateNode {
+            name: name.to_string(),
+
+            _literal: name.to_string(),
+            _span: Span::default(),
+            _depth: depth,
+        }
     }
 }
 
@@ -204,34 +282,44 @@ impl DestroyNode {
         tokens: &Vec<Token>,
         dbs: &mut Vec<String>,
         idx: &mut usize,
-    ) -> Result<(), String> {
-        let next_token: TokenType = peek_one(tokens, &idx);
+    ) -> Result<(), EaqlError> {
+        let next_token: TokenType = peek_one(tokens, idx);
         if next_token != TokenType::And && next_token != TokenType::Comma {
             if tokens[*idx].token_type == TokenType::Identifier {
                 dbs.push(tokens[*idx].literal.clone());
                 *idx += 1;
                 return Ok(());
             }
-        } else if next_token == TokenType::And || next_token == TokenType::Comma {
-            if tokens[*idx].token_type == TokenType::Identifier {
-                dbs.push(tokens[*idx].literal.clone());
-                *idx += 2;
+        } else if (next_token == TokenType::And || next_token == TokenType::Comma)
+            && tokens[*idx].token_type == TokenType::Identifier
+        {
+            dbs.push(tokens[*idx].literal.clone());
+            *idx += 2;
 
-                DestroyNode::recurse_build(tokens, dbs, idx)?;
+            DestroyNode::recurse_build(tokens, dbs, idx)?;
 
-                return Ok(());
-            }
+            return Ok(());
         }
 
-        return Err("Something went wrong parsing database names, \
+        return Err(EaqlError::UnexpectedToken(Box::new(
+            ErrorContext::at(
+                "Something went wrong parsing database names, \
 make sure they're in a valid list notation."
-            .to_string());
+                    .to_string(),
+                &tokens[*idx],
+            )
+            .with_hint("expected database names separated by commas or `and`"),
+        )));
     }
 
     /// Takes current node type and given the current location in the
     /// query defined by the borrowed index, makes an attempt to parse
     /// this node and associated subnodes for the Abstract Syntax Tree.
-    pub fn parse(tokens: &Vec<Token>, idx: &mut usize, depth: u16) -> Result<DestroyNode, String> {
+    pub fn parse(
+        tokens: &Vec<Token>,
+        idx: &mut usize,
+        depth: u16,
+    ) -> Result<DestroyNode, EaqlError> {
         validate_length(tokens, idx, true)?;
 
         let start_idx: usize = *idx;
@@ -250,6 +338,7 @@ make sure they're in a valid list notation."
                     .collect::<Vec<&str>>()
                     .join(" ")
             },
+            _span: span_between(tokens, start_idx, *idx),
             _depth: depth,
         });
     }
@@ -257,28 +346,54 @@ make sure they're in a valid list notation."
     /// Outputs current AST node transpiled with color         
     /// and it's raw query counterpart. Output are used by
     /// the Transpiler REPL.
-    pub fn transpile_color(&self) -> (String, String) {
-        (
-            colorize(&self._literal.as_str(), AnsiColor::Blue),
-            colorize(&self.databases.join(", "), AnsiColor::Blue),
-        )
+    pub fn transpile_color(&self, dialect: &dyn Dialect) -> Result<(String, String), EaqlError> {
+        sql::transpile_color(dialect, |writer| writer.visit_destroy(self))
     }
 
-    /// Outputs current AST node transpile to raw SQL.
-    pub fn transpile_raw(&self) -> String {
-        self.databases.join(", ")
+    /// Outputs current AST node transpiled to raw SQL.
+    pub fn transpile_raw(&self, dialect: &dyn Dialect) -> Result<String, EaqlError> {
+        sql::transpile_raw(dialect, |writer| writer.visit_destroy(self))
+    }
+
+    /// Names of the databases destroyed.
+    pub fn names(&self) -> &[String] {
+        &self.databases
+    }
+
+    pub(crate) fn literal(&self) -> &str {
+        &self._literal
+    }
+
+    pub(crate) fn depth(&self) -> u16 {
+        self._depth
+    }
+
+    pub(crate) fn new(databases: Vec<String>, literal: String, depth: u16) -> DestroyNode {
+        DestroyNode {
+            databases,
+
+            _literal: literal,
+            _span: Span::default(),
+            _depth: depth,
+        }
     }
 }
 
 impl UseNode {
-    pub fn parse(tokens: &Vec<Token>, idx: &mut usize, depth: u16) -> Result<UseNode, String> {
+    pub fn parse(tokens: &Vec<Token>, idx: &mut usize, depth: u16) -> Result<UseNode, EaqlError> {
         validate_length(tokens, idx, true)?;
 
         if tokens[*idx].token_type != TokenType::Identifier {
-            return Err(format!(
-                "Expected identifier, got '{:?}' instead!",
-                tokens[*idx].token_type
-            ));
+            return Err(EaqlError::UnexpectedToken(Box::new(
+                ErrorContext::at(
+                    format!(
+                        "Expected identifier, got '{:?}' instead!",
+                        tokens[*idx].token_type
+                    ),
+                    &tokens[*idx],
+                )
+                .with_hint("expected a database name"),
+            )));
         }
 
         *idx += 1;
@@ -288,6 +403,7 @@ impl UseNode {
             name: tokens[*idx - 1].literal.clone(),
 
             _literal: tokens[*idx - 1].lexeme.clone(),
+            _span: span_between(tokens, *idx - 1, *idx),
             _depth: depth,
         });
     }
@@ -295,21 +411,41 @@ impl UseNode {
     /// Outputs current AST node transpiled with color         
     /// and it's raw query counterpart. Output are used by
     /// the Transpiler REPL.
-    pub fn transpile_color(&self) -> (String, String) {
-        (
-            colorize(&self._literal.as_str(), AnsiColor::Blue),
-            colorize(&self.name.as_str(), AnsiColor::Blue),
-        )
+    pub fn transpile_color(&self, dialect: &dyn Dialect) -> Result<(String, String), EaqlError> {
+        sql::transpile_color(dialect, |writer| writer.visit_use(self))
     }
 
     /// Outputs current AST node transpiled to raw SQL.
-    pub fn transpile_raw(&self) -> String {
-        self.name.clone()
+    pub fn transpile_raw(&self, dialect: &dyn Dialect) -> Result<String, EaqlError> {
+        sql::transpile_raw(dialect, |writer| writer.visit_use(self))
+    }
+
+    /// Name of the database switched to.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn literal(&self) -> &str {
+        &self._literal
+    }
+
+    pub(crate) fn depth(&self) -> u16 {
+        self._depth
+    }
+
+    pub(crate) fn new(name: &str, depth: u16) -> UseNode {
+        UseNode {
+            name: name.to_string(),
+
+            _literal: name.to_string(),
+            _span: Span::default(),
+            _depth: depth,
+        }
     }
 }
 
 impl ShowNode {
-    pub fn parse(tokens: &Vec<Token>, idx: &mut usize, depth: u16) -> Result<ShowNode, String> {
+    pub fn parse(tokens: &Vec<Token>, idx: &mut usize, depth: u16) -> Result<ShowNode, EaqlError> {
         validate_length(tokens, idx, true)?;
 
         return Ok(ShowNode { _depth: depth });
@@ -318,91 +454,83 @@ impl ShowNode {
     /// Outputs current AST node transpiled with color         
     /// and it's raw query counterpart. Output are used by
     /// the Transpiler REPL.
-    pub fn transpile_color(&self) -> (String, String) {
-        ("".to_string(), "".to_string())
+    pub fn transpile_color(&self, dialect: &dyn Dialect) -> Result<(String, String), EaqlError> {
+        sql::transpile_color(dialect, |writer| writer.visit_show(self))
     }
 
     /// Outputs current AST node transpiled to raw SQL.
-    pub fn transpile_raw(&self) -> String {
-        "".to_string()
+    pub fn transpile_raw(&self, dialect: &dyn Dialect) -> Result<String, EaqlError> {
+        sql::transpile_raw(dialect, |writer| writer.visit_show(self))
+    }
+
+    pub(crate) fn depth(&self) -> u16 {
+        self._depth
+    }
+
+    pub(crate) fn new(depth: u16) -> ShowNode {
+        ShowNode { _depth: depth }
     }
 }
 
 // Display Functions
 impl fmt::Display for DatabaseNode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "\n{}(DatabaseNode){}{}{}{}",
-            get_tab(self._depth),
-            self._create
-                .as_ref()
-                .map(|v| v as &dyn fmt::Display)
-                .unwrap_or(&""),
-            self._destroy
-                .as_ref()
-                .map(|v| v as &dyn fmt::Display)
-                .unwrap_or(&""),
-            self._show
-                .as_ref()
-                .map(|v| v as &dyn fmt::Display)
-                .unwrap_or(&""),
-            self._use
-                .as_ref()
-                .map(|v| v as &dyn fmt::Display)
-                .unwrap_or(&""),
-        )
+        TreeWriter::write(f, |writer| writer.visit_database(self))
     }
 }
 
 impl fmt::Display for CreateNode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "\n{}(CreateNode)
-{}name: {}",
-            get_tab(self._depth),
-            get_tab(self._depth + 1),
-            self.name
-        )
+        TreeWriter::write(f, |writer| writer.visit_create(self))
     }
 }
 
 impl fmt::Display for DestroyNode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "\n{}(DestroyNode)
-{}databases: {:?}",
-            get_tab(self._depth),
-            get_tab(self._depth + 1),
-            self.databases
-        )
+        TreeWriter::write(f, |writer| writer.visit_destroy(self))
     }
 }
 
 impl fmt::Display for UseNode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "\n{}(UseNode)
-{}name: {}",
-            get_tab(self._depth),
-            get_tab(self._depth + 1),
-            self.name
-        )
+        TreeWriter::write(f, |writer| writer.visit_use(self))
     }
 }
 
 impl fmt::Display for ShowNode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "\n{}(ShowNode)", get_tab(self._depth),)
+        TreeWriter::write(f, |writer| writer.visit_show(self))
+    }
+}
+
+impl Spanned for DatabaseNode {
+    fn span(&self) -> Span {
+        self._span
+    }
+}
+
+impl Spanned for DestroyNode {
+    fn span(&self) -> Span {
+        self._span
+    }
+}
+
+impl Spanned for CreateNode {
+    fn span(&self) -> Span {
+        self._span
+    }
+}
+
+impl Spanned for UseNode {
+    fn span(&self) -> Span {
+        self._span
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::transpiler::dialect::Generic;
 
     #[test]
     fn unit_test_show_normal() {
@@ -438,6 +566,7 @@ mod tests {
         let expected: UseNode = UseNode {
             name: "test_db".to_string(),
             _literal: "test_db".to_string(),
+            _span: Span::default(),
             _depth: depth,
         };
 
@@ -483,6 +612,7 @@ mod tests {
         let expected: CreateNode = CreateNode {
             name: "test_db".to_string(),
             _literal: "test_db".to_string(),
+            _span: Span::default(),
             _depth: depth,
         };
 
@@ -528,6 +658,7 @@ mod tests {
         let expected: DestroyNode = DestroyNode {
             databases: vec!["test_db".to_string()],
             _literal: "test_db".to_string(),
+            _span: Span::default(),
             _depth: depth,
         };
 
@@ -569,6 +700,7 @@ mod tests {
                 "test_db_3".to_string(),
             ],
             _literal: "test_db_1 , test_db_2 , test_db_3".to_string(),
+            _span: Span::default(),
             _depth: depth,
         };
 
@@ -626,4 +758,27 @@ mod tests {
             Err(_) => assert!(true, "Output expected to error and did."),
         }
     }
+
+    #[test]
+    fn unit_test_database_no_operation() {
+        let node: DatabaseNode = DatabaseNode {
+            _create: None,
+            _destroy: None,
+            _use: None,
+            _show: None,
+
+            _literal: "".to_string(),
+            _span: Span::default(),
+            _depth: 0,
+        };
+
+        assert!(matches!(
+            node.transpile_raw(&Generic),
+            Err(EaqlError::UnknownAction(_))
+        ));
+        assert!(matches!(
+            node.transpile_color(&Generic),
+            Err(EaqlError::UnknownAction(_))
+        ));
+    }
 }
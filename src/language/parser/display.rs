@@ -0,0 +1,280 @@
+/*
+Displaying the Abstract Syntax Tree
+
+Outputs a parsed tree one node per line, indented by how deep the node
+sits in the tree, by visiting it. This backs the Display implementation
+of every node and is what the `debug` log level prints after parsing.
+*/
+
+use crate::language::{
+    parser::{
+        conditional::{
+            BoolNode, ComparisonNode, ConditionNode, ExpressionNode, ListNode, LogicalOperator,
+            NotNode, OperandNode, RangeNode, Value,
+        },
+        database::{CreateNode, DatabaseNode, DestroyNode, ShowNode, UseNode},
+        get::{ColumnNode, FilterNode, GetNode, TableNode},
+        helpers::get_tab,
+        parser::Query,
+        postprocessor::{LimitNode, PostProcessorNode, SortKeyNode, SortNode},
+    },
+    visitor::{
+        Visitor, walk_condition, walk_database, walk_filter, walk_not, walk_operand,
+        walk_postprocessor, walk_query, walk_sort,
+    },
+};
+use std::fmt;
+
+pub(crate) struct TreeWriter<'a, 'f> {
+    f: &'a mut fmt::Formatter<'f>,
+    result: fmt::Result,
+}
+
+impl<'a, 'f> TreeWriter<'a, 'f> {
+    /// Writes whatever `visit` visits to the formatter.
+    pub(crate) fn write(
+        f: &'a mut fmt::Formatter<'f>,
+        visit: impl FnOnce(&mut TreeWriter<'a, 'f>),
+    ) -> fmt::Result {
+        let mut writer: TreeWriter = TreeWriter { f, result: Ok(()) };
+
+        visit(&mut writer);
+        writer.result
+    }
+
+    fn line(&mut self, args: fmt::Arguments) {
+        if self.result.is_ok() {
+            self.result = self.f.write_fmt(args);
+        }
+    }
+
+    /// Placeholder for optional nodes the query left out.
+    fn missing(&mut self, name: &str, depth: u16) {
+        self.line(format_args!(
+            "\n{}({})\n{}N/A",
+            get_tab(depth),
+            name,
+            get_tab(depth + 1)
+        ));
+    }
+}
+
+/// The value as it's shown in the tree.
+fn written(value: Value<'_>) -> &str {
+    match value {
+        Value::Text(value) | Value::Number(value) => value,
+        Value::Bool(true) => "true",
+        Value::Bool(false) => "false",
+        Value::Null => "null",
+    }
+}
+
+impl<'ast> Visitor<'ast> for TreeWriter<'_, '_> {
+    fn visit_query(&mut self, node: &'ast Query) {
+        self.line(format_args!("{}(Query)", get_tab(node.depth())));
+        walk_query(self, node);
+    }
+
+    fn visit_get(&mut self, node: &'ast GetNode) {
+        self.line(format_args!("\n{}(GetNode)", get_tab(node.depth())));
+        self.visit_column(node.columns());
+        self.visit_table(node.table());
+
+        match node.filter() {
+            Some(filter) => self.visit_filter(filter),
+            None => self.missing("FilterNode", node.depth() + 1),
+        }
+
+        match node.postprocessor() {
+            Some(postprocessor) => self.visit_postprocessor(postprocessor),
+            None => self.missing("PostProcessorNode", node.depth() + 1),
+        }
+    }
+
+    fn visit_column(&mut self, node: &'ast ColumnNode) {
+        self.line(format_args!(
+            "\n{}(ColumnNode)\n{}is_wildcard: {:?}\n{}column_names: {:?}",
+            get_tab(node.depth()),
+            get_tab(node.depth() + 1),
+            node.is_wildcard(),
+            get_tab(node.depth() + 1),
+            node.names(),
+        ));
+    }
+
+    fn visit_table(&mut self, node: &'ast TableNode) {
+        self.line(format_args!(
+            "\n{}(TableNode)\n{}table_name: {:?}",
+            get_tab(node.depth()),
+            get_tab(node.depth() + 1),
+            node.name(),
+        ));
+    }
+
+    fn visit_filter(&mut self, node: &'ast FilterNode) {
+        self.line(format_args!("\n{}(FilterNode)", get_tab(node.depth())));
+        walk_filter(self, node);
+    }
+
+    fn visit_condition(&mut self, node: &'ast ConditionNode) {
+        self.line(format_args!(
+            "\n{}(Condition){}",
+            get_tab(node.depth()),
+            get_tab(node.depth() + 1)
+        ));
+        walk_condition(self, node);
+    }
+
+    fn visit_operand(&mut self, node: &'ast OperandNode) {
+        let operator: &str = match node.operator() {
+            LogicalOperator::And => "AND",
+            LogicalOperator::Or => "OR",
+        };
+
+        self.line(format_args!(
+            "\n{}(Operand::{})",
+            get_tab(node.depth()),
+            operator
+        ));
+        walk_operand(self, node);
+    }
+
+    fn visit_not(&mut self, node: &'ast NotNode) {
+        self.line(format_args!("\n{}(Not)", get_tab(node.depth())));
+        walk_not(self, node);
+    }
+
+    fn visit_expression(&mut self, node: &'ast ExpressionNode) {
+        self.line(format_args!(
+            "\n{}(Expression)\n{}variable: {:?}\n{}operator: {:?}\n{}value: {:?}",
+            get_tab(node.depth()),
+            get_tab(node.depth() + 1),
+            node.column(),
+            get_tab(node.depth() + 1),
+            node.operator_token().token_type,
+            get_tab(node.depth() + 1),
+            written(node.value()),
+        ));
+    }
+
+    fn visit_list(&mut self, node: &'ast ListNode) {
+        self.line(format_args!(
+            "\n{}(List)\n{}variable: {:?}\n{}negated: {:?}\n{}values: {:?}",
+            get_tab(node.depth()),
+            get_tab(node.depth() + 1),
+            node.column(),
+            get_tab(node.depth() + 1),
+            node.negated(),
+            get_tab(node.depth() + 1),
+            node.values()
+                .into_iter()
+                .map(written)
+                .collect::<Vec<&str>>(),
+        ));
+    }
+
+    fn visit_range(&mut self, node: &'ast RangeNode) {
+        self.line(format_args!(
+            "\n{}(Range)\n{}variable: {:?}\n{}negated: {:?}\n{}low: {:?}\n{}high: {:?}",
+            get_tab(node.depth()),
+            get_tab(node.depth() + 1),
+            node.column(),
+            get_tab(node.depth() + 1),
+            node.negated(),
+            get_tab(node.depth() + 1),
+            written(node.low()),
+            get_tab(node.depth() + 1),
+            written(node.high()),
+        ));
+    }
+
+    fn visit_comparison(&mut self, node: &'ast ComparisonNode) {
+        self.line(format_args!(
+            "\n{}(Comparison)\n{}left: {:?}\n{}operator: {:?}\n{}right: {:?}",
+            get_tab(node.depth()),
+            get_tab(node.depth() + 1),
+            node.left().to_string(),
+            get_tab(node.depth() + 1),
+            node.operator_token().token_type,
+            get_tab(node.depth() + 1),
+            node.right().to_string(),
+        ));
+    }
+
+    fn visit_bool(&mut self, node: &'ast BoolNode) {
+        self.line(format_args!(
+            "\n{}(Bool::{})",
+            get_tab(node.depth()),
+            node.value()
+        ));
+    }
+
+    fn visit_postprocessor(&mut self, node: &'ast PostProcessorNode) {
+        self.line(format_args!(
+            "\n{}(PostProcessorNode)",
+            get_tab(node.depth())
+        ));
+        walk_postprocessor(self, node);
+    }
+
+    fn visit_sort(&mut self, node: &'ast SortNode) {
+        self.line(format_args!("\n{}(SortNode)", get_tab(node.depth())));
+        walk_sort(self, node);
+    }
+
+    fn visit_sort_key(&mut self, node: &'ast SortKeyNode) {
+        self.line(format_args!(
+            "\n{}(SortKeyNode)\n{}column: {:?}\n{}direction: {:?}",
+            get_tab(node.depth()),
+            get_tab(node.depth() + 1),
+            node.column(),
+            get_tab(node.depth() + 1),
+            node.direction(),
+        ));
+    }
+
+    fn visit_limit(&mut self, node: &'ast LimitNode) {
+        self.line(format_args!(
+            "\n{}(LimitNode)\n{}limit: {:?}",
+            get_tab(node.depth()),
+            get_tab(node.depth() + 1),
+            node.value(),
+        ));
+    }
+
+    fn visit_database(&mut self, node: &'ast DatabaseNode) {
+        self.line(format_args!("\n{}(DatabaseNode)", get_tab(node.depth())));
+        walk_database(self, node);
+    }
+
+    fn visit_create(&mut self, node: &'ast CreateNode) {
+        self.line(format_args!(
+            "\n{}(CreateNode)\n{}name: {}",
+            get_tab(node.depth()),
+            get_tab(node.depth() + 1),
+            node.name()
+        ));
+    }
+
+    fn visit_destroy(&mut self, node: &'ast DestroyNode) {
+        self.line(format_args!(
+            "\n{}(DestroyNode)\n{}databases: {:?}",
+            get_tab(node.depth()),
+            get_tab(node.depth() + 1),
+            node.names()
+        ));
+    }
+
+    fn visit_use(&mut self, node: &'ast UseNode) {
+        self.line(format_args!(
+            "\n{}(UseNode)\n{}name: {}",
+            get_tab(node.depth()),
+            get_tab(node.depth() + 1),
+            node.name()
+        ));
+    }
+
+    fn visit_show(&mut self, node: &'ast ShowNode) {
+        self.line(format_args!("\n{}(ShowNode)", get_tab(node.depth())));
+    }
+}
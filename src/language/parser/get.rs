@@ -1,157 +1,339 @@
 use crate::{
     language::{
+        errors::{EaqlError, ErrorContext, Warning},
         parser::{
             conditional::ConditionNode,
-            helpers::{get_tab, peek_one, validate_length},
-            postprocessor::PostProcessorNode,
+            display::TreeWriter,
+            helpers::{peek_one, span_between, valid_until_warning, validate_length},
+            postprocessor::{LimitNode, PostProcessorNode, SortNode},
         },
-        tokens::{Token, TokenType},
+        tokens::{Span, Spanned, Token, TokenType},
+        visitor::Visitor,
     },
-    utils::colors::{AnsiColor, colorize},
+    transpiler::{dialect::Dialect, sql},
 };
-use std::{fmt, usize};
+use std::fmt;
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetNode {
     _table: TableNode,
     _columns: ColumnNode,
     _filter: Option<FilterNode>,
     _postprocessor: Option<PostProcessorNode>,
 
+    _span: Span,
     _depth: u16,
 }
 
+/// The clauses of a get query that could be parsed, left as `None`
+/// where the query had an error. Produced when recovering from errors.
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PartialGetNode {
+    _columns: Option<ColumnNode>,
+    _table: Option<TableNode>,
+    _filter: Option<FilterNode>,
+    _postprocessor: Option<PostProcessorNode>,
+
+    _span: Span,
+    _depth: u16,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableNode {
     table_name: String,
 
     _literal: String,
+    _span: Span,
     _depth: u16,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ColumnNode {
     column_names: Vec<String>,
     is_wildcard: bool,
 
     _literal: String,
+    _span: Span,
     _depth: u16,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FilterNode {
     condition: ConditionNode,
 
     _literal: String,
+    _span: Span,
     _depth: u16,
 }
 
+/// Tokens starting each clause that follows the columns of a get
+/// query, in the order they appear.
+const CLAUSES: [TokenType; 4] = [
+    TokenType::From,
+    TokenType::FilterKeyword,
+    TokenType::PostProcessorEntrance,
+    TokenType::EoqToken,
+];
+
+/// Records the error and skips ahead to the start of the first of
+/// the given clauses, so parsing can carry on from there.
+fn recover(
+    tokens: &Vec<Token>,
+    idx: &mut usize,
+    errors: &mut Vec<EaqlError>,
+    err: EaqlError,
+    clauses: &[TokenType],
+) {
+    errors.push(err);
+
+    while *idx < tokens.len() && !clauses.contains(&tokens[*idx].token_type) {
+        *idx += 1;
+    }
+}
+
 impl GetNode {
-    pub fn parse(tokens: &Vec<Token>, idx: &mut usize, depth: u16) -> Result<GetNode, String> {
-        validate_length(tokens, idx, true)?;
+    pub fn parse(tokens: &Vec<Token>, idx: &mut usize, depth: u16) -> Result<GetNode, EaqlError> {
+        let mut errors: Vec<EaqlError> = vec![];
+        let partial: PartialGetNode = GetNode::parse_recovering(tokens, idx, depth, &mut errors);
+
+        match (errors.into_iter().next(), partial.complete()) {
+            (Some(err), _) => Err(err),
+            (None, Some(get)) => Ok(get),
+            (None, None) => Err(valid_until_warning(tokens, idx)),
+        }
+    }
+
+    /// Parses the node like `parse`, but rather than stopping at the
+    /// first error, records it and skips ahead to the start of the next
+    /// clause (`from`, the filter, the post-processors or the end of the
+    /// query) to carry on from there. Every clause that could be parsed
+    /// is kept in the returned node.
+    pub fn parse_recovering(
+        tokens: &Vec<Token>,
+        idx: &mut usize,
+        depth: u16,
+        errors: &mut Vec<EaqlError>,
+    ) -> PartialGetNode {
+        // We subtract 1 from this so the get keyword is included
+        let start_idx: usize = (*idx).saturating_sub(1);
+
+        let mut partial: PartialGetNode = PartialGetNode {
+            _columns: None,
+            _table: None,
+            _filter: None,
+            _postprocessor: None,
+
+            _span: Span::default(),
+            _depth: depth,
+        };
+
+        if let Err(err) = validate_length(tokens, idx, true) {
+            errors.push(err);
+            return partial;
+        }
 
-        let columns: ColumnNode = match ColumnNode::parse(tokens, idx, depth + 1) {
-            Ok(column) => column,
-            Err(err) => return Err(err),
+        match ColumnNode::parse(tokens, idx, depth + 1) {
+            Ok(columns) => partial._columns = Some(columns),
+            Err(err) => recover(tokens, idx, errors, err, &CLAUSES),
         };
 
-        let table: TableNode = match TableNode::parse(tokens, idx, depth + 1) {
-            Ok(table) => table,
-            Err(err) => return Err(err),
+        if let Err(err) = validate_length(tokens, idx, true) {
+            errors.push(err);
+            return partial;
+        }
+
+        match TableNode::parse(tokens, idx, depth + 1) {
+            Ok(table) => partial._table = Some(table),
+            Err(err) => recover(tokens, idx, errors, err, &CLAUSES[1..]),
         };
 
-        let filter: Option<FilterNode> = match FilterNode::parse(tokens, idx, depth + 1) {
-            Ok(filter) => filter,
-            Err(err) => return Err(err),
+        if let Err(err) = validate_length(tokens, idx, true) {
+            errors.push(err);
+            return partial;
+        }
+
+        match FilterNode::parse(tokens, idx, depth + 1) {
+            Ok(filter) => partial._filter = filter,
+            Err(err) => recover(tokens, idx, errors, err, &CLAUSES[2..]),
         };
 
-        let postprocessor: Option<PostProcessorNode> =
-            match PostProcessorNode::parse(tokens, idx, depth + 1) {
-                Ok(postprocessor) => postprocessor,
-                Err(msg) => return Err(msg),
-            };
+        if let Err(err) = validate_length(tokens, idx, true) {
+            errors.push(err);
+            return partial;
+        }
 
-        validate_length(tokens, idx, true)?;
+        match PostProcessorNode::parse(tokens, idx, depth + 1) {
+            Ok(postprocessor) => partial._postprocessor = postprocessor,
+            Err(err) => recover(tokens, idx, errors, err, &CLAUSES[3..]),
+        };
+
+        if let Err(err) = validate_length(tokens, idx, true) {
+            errors.push(err);
+            return partial;
+        }
 
         if tokens[*idx].token_type != TokenType::EoqToken {
-            return Err(format!(
-                "Unexpected token '{}', expected end-of-query token by this point.",
-                tokens[*idx].lexeme
-            ));
+            errors.push(EaqlError::UnexpectedToken(Box::new(ErrorContext::at(
+                format!(
+                    "Unexpected token '{}', expected end-of-query token by this point.",
+                    tokens[*idx].lexeme
+                ),
+                &tokens[*idx],
+            ).with_hint("expected a filter like `where price < 5`, a post-processor like `then limit it to 10` or `;` to end the query"))));
+        }
+
+        partial._span = span_between(tokens, start_idx, *idx);
+
+        partial
+    }
+
+    /// Outputs current AST node transpiled with color         
+    /// and it's raw query counterpart. Output are used by
+    /// the Transpiler REPL.
+    pub fn transpile_color(&self, dialect: &dyn Dialect) -> Result<(String, String), EaqlError> {
+        sql::transpile_color(dialect, |writer| writer.visit_get(self))
+    }
+
+    /// Outputs current AST node transpiled to raw SQL
+    pub fn transpile_raw(&self, dialect: &dyn Dialect) -> Result<String, EaqlError> {
+        sql::transpile_raw(dialect, |writer| writer.visit_get(self))
+    }
+
+    /// Collects warnings for this node and its subnodes.
+    pub fn warnings(&self) -> Vec<Warning> {
+        let mut warnings: Vec<Warning> = self._columns.warnings();
+
+        if let Some(postprocessor) = &self._postprocessor {
+            warnings.extend(postprocessor.warnings());
         }
 
-        Ok(GetNode {
+        warnings
+    }
+
+    /// The table data is retrieved from.
+    pub fn table(&self) -> &TableNode {
+        &self._table
+    }
+
+    /// The columns retrieved.
+    pub fn columns(&self) -> &ColumnNode {
+        &self._columns
+    }
+
+    /// The filter rows have to pass, if there is one.
+    pub fn filter(&self) -> Option<&FilterNode> {
+        self._filter.as_ref()
+    }
+
+    /// The post-processors (limit, sort) applied, if there are any.
+    pub fn postprocessor(&self) -> Option<&PostProcessorNode> {
+        self._postprocessor.as_ref()
+    }
+
+    /// The limit applied, if there is one.
+    pub fn limit(&self) -> Option<&LimitNode> {
+        self._postprocessor.as_ref()?.limit()
+    }
+
+    /// The sort applied, if there is one.
+    pub fn sort(&self) -> Option<&SortNode> {
+        self._postprocessor.as_ref()?.sort()
+    }
+
+    pub fn table_mut(&mut self) -> &mut TableNode {
+        &mut self._table
+    }
+
+    pub fn columns_mut(&mut self) -> &mut ColumnNode {
+        &mut self._columns
+    }
+
+    pub fn filter_mut(&mut self) -> Option<&mut FilterNode> {
+        self._filter.as_mut()
+    }
+
+    pub fn postprocessor_mut(&mut self) -> Option<&mut PostProcessorNode> {
+        self._postprocessor.as_mut()
+    }
+
+    pub(crate) fn depth(&self) -> u16 {
+        self._depth
+    }
+
+    /// Assembles a node from already built subnodes, used by the
+    /// query builder.
+    pub(crate) fn new(
+        columns: ColumnNode,
+        table: TableNode,
+        filter: Option<FilterNode>,
+        postprocessor: Option<PostProcessorNode>,
+        depth: u16,
+    ) -> GetNode {
+        GetNode {
             _table: table,
             _columns: columns,
             _filter: filter,
             _postprocessor: postprocessor,
 
+            _span: Span::default(),
             _depth: depth,
-        })
+        }
     }
+}
 
-    /// Outputs current AST node transpiled with color         
-    /// and it's raw query counterpart. Output are used by
-    /// the Transpiler REPL.
-    pub fn transpile_color(&self) -> (String, String) {
-        let columns: (String, String) = self._columns.transpile_color();
-        let table: (String, String) = self._table.transpile_color();
-        let filter: Option<(String, String)> = match &self._filter {
-            Some(filter) => Some(filter.transpile_color()),
-            None => None,
-        };
-        let postprocessor: Option<(String, String)> = match &self._postprocessor {
-            Some(postprocessor) => Some(postprocessor.transpile_color()),
-            None => None,
-        };
+impl PartialGetNode {
+    /// The table data is retrieved from, if it could be parsed.
+    pub fn table(&self) -> Option<&TableNode> {
+        self._table.as_ref()
+    }
 
-        (
-            [
-                Some(columns.0),
-                Some(table.0),
-                filter.as_ref().map(|f| f.0.clone()),
-                postprocessor.as_ref().map(|f| f.0.clone()),
-            ]
-            .into_iter()
-            .flatten()
-            .collect::<Vec<String>>()
-            .join(" "),
-            [
-                Some(columns.1),
-                Some(table.1),
-                filter.as_ref().map(|f| f.1.clone()),
-                postprocessor.as_ref().map(|f| f.1.clone()),
-            ]
-            .into_iter()
-            .flatten()
-            .collect::<Vec<String>>()
-            .join(" "),
-        )
+    /// The columns retrieved, if they could be parsed.
+    pub fn columns(&self) -> Option<&ColumnNode> {
+        self._columns.as_ref()
     }
 
-    /// Outputs current AST node transpiled to raw SQL
-    pub fn transpile_raw(&self) -> String {
-        let columns: String = self._columns.transpile_raw();
-        let table: String = self._table.transpile_raw();
-        let filter: Option<String> = match &self._filter {
-            Some(filter) => Some(filter.transpile_raw()),
-            None => None,
-        };
-        let postprocessor: Option<String> = match &self._postprocessor {
-            Some(postprocessor) => Some(postprocessor.transpile_raw()),
-            None => None,
-        };
+    /// The filter rows have to pass, if there is one and it could be parsed.
+    pub fn filter(&self) -> Option<&FilterNode> {
+        self._filter.as_ref()
+    }
+
+    /// The post-processors (limit, sort) applied, if there are any and
+    /// they could be parsed.
+    pub fn postprocessor(&self) -> Option<&PostProcessorNode> {
+        self._postprocessor.as_ref()
+    }
+
+    /// Turns the node into a full get node, as long as the clauses
+    /// every get query needs (the columns and table) were parsed.
+    pub fn complete(self) -> Option<GetNode> {
+        Some(GetNode {
+            _table: self._table?,
+            _columns: self._columns?,
+            _filter: self._filter,
+            _postprocessor: self._postprocessor,
+
+            _span: self._span,
+            _depth: self._depth,
+        })
+    }
+}
+
+impl From<GetNode> for PartialGetNode {
+    fn from(node: GetNode) -> PartialGetNode {
+        PartialGetNode {
+            _columns: Some(node._columns),
+            _table: Some(node._table),
+            _filter: node._filter,
+            _postprocessor: node._postprocessor,
 
-        [
-            Some(columns),
-            Some(table),
-            filter.as_ref().map(|f| f.clone()),
-            postprocessor.as_ref().map(|f| f.clone()),
-        ]
-        .into_iter()
-        .flatten()
-        .collect::<Vec<String>>()
-        .join(" ")
+            _span: node._span,
+            _depth: node._depth,
+        }
     }
 }
 
@@ -159,7 +341,7 @@ impl TableNode {
     /// Takes current node type and given the current location in the
     /// query defined by the borrowed index, makes an attempt to parse
     /// this node and associated subnodes for the Abstract Syntax Tree.
-    pub fn parse(tokens: &Vec<Token>, idx: &mut usize, depth: u16) -> Result<TableNode, String> {
+    pub fn parse(tokens: &Vec<Token>, idx: &mut usize, depth: u16) -> Result<TableNode, EaqlError> {
         let start_idx: usize = *idx;
 
         if tokens[*idx].token_type == TokenType::From
@@ -167,10 +349,22 @@ impl TableNode {
         {
             *idx += 1;
         } else {
-            return Err(format!(
-                "From-like keyword required for table selection, got \"{}\" instead",
-                tokens[*idx].lexeme
-            ));
+            // Point at the missing table name if the keyword itself is fine
+            let offending: &Token = match tokens[*idx].token_type {
+                TokenType::From => tokens.get(*idx + 1).unwrap_or(&tokens[*idx]),
+                _ => &tokens[*idx],
+            };
+
+            return Err(EaqlError::UnexpectedToken(Box::new(
+                ErrorContext::at(
+                    format!(
+                        "From-like keyword required for table selection, got \"{}\" instead",
+                        tokens[*idx].lexeme
+                    ),
+                    offending,
+                )
+                .with_hint("expected `from` followed by a table name, i.e. `from drinks`"),
+            )));
         }
 
         *idx += 1;
@@ -186,6 +380,7 @@ impl TableNode {
                     .join(" ")
             },
 
+            _span: span_between(tokens, start_idx, *idx),
             _depth: depth,
         });
     }
@@ -193,16 +388,41 @@ impl TableNode {
     /// Outputs current AST node transpiled with color         
     /// and it's raw query counterpart. Output are used by
     /// the Transpiler REPL.
-    pub fn transpile_color(&self) -> (String, String) {
-        (
-            colorize(&self._literal, AnsiColor::Blue),
-            colorize(&format!("FROM {}", self.table_name), AnsiColor::Blue),
-        )
+    pub fn transpile_color(&self, dialect: &dyn Dialect) -> Result<(String, String), EaqlError> {
+        sql::transpile_color(dialect, |writer| writer.visit_table(self))
     }
 
     /// Outputs current AST node transpiled to raw SQL
-    pub fn transpile_raw(&self) -> String {
-        format!("FROM {}", self.table_name)
+    pub fn transpile_raw(&self, dialect: &dyn Dialect) -> Result<String, EaqlError> {
+        sql::transpile_raw(dialect, |writer| writer.visit_table(self))
+    }
+
+    pub fn name(&self) -> &str {
+        &self.table_name
+    }
+
+    /// Points the query at another table, the literal the
+    /// node was parsed from is left as is.
+    pub fn set_name(&mut self, name: &str) {
+        self.table_name = name.to_string();
+    }
+
+    pub(crate) fn literal(&self) -> &str {
+        &self._literal
+    }
+
+    pub(crate) fn depth(&self) -> u16 {
+        self._depth
+    }
+
+    pub(crate) fn new(name: &str, literal: String, depth: u16) -> TableNode {
+        TableNode {
+            table_name: name.to_string(),
+
+            _literal: literal,
+            _span: Span::default(),
+            _depth: depth,
+        }
     }
 }
 
@@ -211,28 +431,31 @@ impl ColumnNode {
         tokens: &Vec<Token>,
         cols: &mut Vec<String>,
         idx: &mut usize,
-    ) -> Result<(), String> {
-        let next_token: TokenType = peek_one(tokens, &idx);
+    ) -> Result<(), EaqlError> {
+        let next_token: TokenType = peek_one(tokens, idx);
         if next_token != TokenType::And && next_token != TokenType::Comma {
             if tokens[*idx].token_type == TokenType::Identifier {
                 cols.push(tokens[*idx].literal.clone());
                 *idx += 1;
                 return Ok(());
             }
-        } else if next_token == TokenType::And || next_token == TokenType::Comma {
-            if tokens[*idx].token_type == TokenType::Identifier {
-                cols.push(tokens[*idx].literal.clone());
-                *idx += 2;
+        } else if (next_token == TokenType::And || next_token == TokenType::Comma)
+            && tokens[*idx].token_type == TokenType::Identifier
+        {
+            cols.push(tokens[*idx].literal.clone());
+            *idx += 2;
 
-                ColumnNode::recurse_build(tokens, cols, idx)?;
+            ColumnNode::recurse_build(tokens, cols, idx)?;
 
-                return Ok(());
-            }
+            return Ok(());
         }
 
-        return Err("Something went wrong parsing column names, \
+        return Err(EaqlError::UnexpectedToken(Box::new(ErrorContext::at(
+            "Something went wrong parsing column names, \
 make sure they're in a valid list notation."
-            .to_string());
+                .to_string(),
+            &tokens[*idx],
+        ).with_hint("expected column names separated by commas or `and`, i.e. `get name, size and price`"))));
     }
 
     /// Reconstructs original literal from list of tokens and
@@ -256,11 +479,18 @@ make sure they're in a valid list notation."
     /// Takes current node type and given the current location in the
     /// query defined by the borrowed index, makes an attempt to parse
     /// this node and associated subnodes for the Abstract Syntax Tree.
-    pub fn parse(tokens: &Vec<Token>, idx: &mut usize, depth: u16) -> Result<ColumnNode, String> {
+    pub fn parse(
+        tokens: &Vec<Token>,
+        idx: &mut usize,
+        depth: u16,
+    ) -> Result<ColumnNode, EaqlError> {
         // We subtract 1 from this because Get keyword has been processed already
         let start_idx: usize = *idx - 1;
 
-        if tokens[*idx].token_type == TokenType::WildcardKeyword {
+        if matches!(
+            tokens[*idx].token_type,
+            TokenType::WildcardKeyword | TokenType::Star
+        ) {
             *idx += 1;
 
             return Ok(ColumnNode {
@@ -274,6 +504,7 @@ make sure they're in a valid list notation."
                         .collect::<Vec<&str>>()
                         .join(" ")
                 },
+                _span: span_between(tokens, start_idx, *idx),
                 _depth: depth,
             });
         }
@@ -284,9 +515,10 @@ make sure they're in a valid list notation."
 
         return Ok(ColumnNode {
             is_wildcard: false,
-            column_names: column_names,
+            column_names,
 
             _literal: ColumnNode::reconstruct_literal(tokens, start_idx, *idx),
+            _span: span_between(tokens, start_idx, *idx),
             _depth: depth,
         });
     }
@@ -294,28 +526,71 @@ make sure they're in a valid list notation."
     /// Outputs current AST node transpiled with color         
     /// and it's raw query counterpart. Output are used by
     /// the Transpiler REPL.
-    pub fn transpile_color(&self) -> (String, String) {
-        let columns: String = if self.is_wildcard {
-            "*".to_string()
-        } else {
-            self.column_names.join(", ")
-        };
-
-        (
-            colorize(&self._literal, AnsiColor::Yellow),
-            colorize(&format!("SELECT {}", columns), AnsiColor::Yellow),
-        )
+    pub fn transpile_color(
+        &self,
+        dialect: &dyn Dialect,
+        top: Option<&LimitNode>,
+    ) -> Result<(String, String), EaqlError> {
+        sql::transpile_color(dialect, |writer| {
+            writer.set_top(top);
+            writer.visit_column(self)
+        })
     }
 
     /// Outputs current AST node transpiled to raw SQL
-    pub fn transpile_raw(&self) -> String {
-        let columns: String = if self.is_wildcard {
-            "*".to_string()
-        } else {
-            self.column_names.join(", ")
-        };
+    pub fn transpile_raw(
+        &self,
+        dialect: &dyn Dialect,
+        top: Option<&LimitNode>,
+    ) -> Result<String, EaqlError> {
+        sql::transpile_raw(dialect, |writer| {
+            writer.set_top(top);
+            writer.visit_column(self)
+        })
+    }
+
+    /// Collects warnings for this node and its subnodes.
+    pub fn warnings(&self) -> Vec<Warning> {
+        self.column_names
+            .iter()
+            .enumerate()
+            .filter(|(i, v)| self.column_names[..*i].contains(v))
+            .map(|(_, v)| {
+                Warning::new(
+                    format!("Column \"{}\" is requested more than once.", v),
+                    self._span,
+                )
+            })
+            .collect()
+    }
+
+    /// Names of the columns retrieved, empty for a wildcard.
+    pub fn names(&self) -> &[String] {
+        &self.column_names
+    }
+
+    /// Whether every column is retrieved (i.e. `get all`).
+    pub fn is_wildcard(&self) -> bool {
+        self.is_wildcard
+    }
+
+    pub(crate) fn literal(&self) -> &str {
+        &self._literal
+    }
+
+    pub(crate) fn depth(&self) -> u16 {
+        self._depth
+    }
+
+    pub(crate) fn new(names: Vec<String>, literal: String, depth: u16) -> ColumnNode {
+        ColumnNode {
+            is_wildcard: names.is_empty(),
+            column_names: names,
 
-        format!("SELECT {}", columns)
+            _literal: literal,
+            _span: Span::default(),
+            _depth: depth,
+        }
     }
 }
 
@@ -327,7 +602,7 @@ impl FilterNode {
         tokens: &Vec<Token>,
         idx: &mut usize,
         depth: u16,
-    ) -> Result<Option<FilterNode>, String> {
+    ) -> Result<Option<FilterNode>, EaqlError> {
         if tokens[*idx].token_type == TokenType::FilterKeyword {
             let start_idx: usize = *idx;
             *idx += 1;
@@ -343,6 +618,7 @@ impl FilterNode {
                 condition: condition_node,
 
                 _literal: tokens[start_idx].lexeme.clone(),
+                _span: span_between(tokens, start_idx, *idx),
                 _depth: depth,
             }));
         }
@@ -353,95 +629,94 @@ impl FilterNode {
     /// Outputs current AST node transpiled with color         
     /// and it's raw query counterpart. Output are used by
     /// the Transpiler REPL.
-    pub fn transpile_color(&self) -> (String, String) {
-        (
-            colorize(
-                &format!("{} {}", self._literal, self.condition.transpile_raw()),
-                AnsiColor::Cyan,
-            ),
-            colorize(
-                &format!("WHERE {}", self.condition.transpile_color()),
-                AnsiColor::Cyan,
-            ),
-        )
+    pub fn transpile_color(&self, dialect: &dyn Dialect) -> Result<(String, String), EaqlError> {
+        sql::transpile_color(dialect, |writer| writer.visit_filter(self))
     }
 
     /// Outputs current AST node transpiled to raw SQL
-    pub fn transpile_raw(&self) -> String {
-        format!("WHERE {}", self.condition.transpile_raw())
+    pub fn transpile_raw(&self, dialect: &dyn Dialect) -> Result<String, EaqlError> {
+        sql::transpile_raw(dialect, |writer| writer.visit_filter(self))
+    }
+
+    pub fn condition(&self) -> &ConditionNode {
+        &self.condition
+    }
+
+    pub fn condition_mut(&mut self) -> &mut ConditionNode {
+        &mut self.condition
+    }
+
+    pub(crate) fn literal(&self) -> &str {
+        &self._literal
+    }
+
+    pub(crate) fn depth(&self) -> u16 {
+        self._depth
+    }
+
+    pub(crate) fn new(condition: ConditionNode, literal: String, depth: u16) -> FilterNode {
+        FilterNode {
+            condition,
+
+            _literal: literal,
+            _span: Span::default(),
+            _depth: depth,
+        }
     }
 }
 
 // Display Functions
 impl fmt::Display for GetNode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "\n{}(GetNode){}{}{}{}",
-            get_tab(self._depth),
-            self._columns,
-            self._table,
-            self._filter
-                .as_ref()
-                .map(|v| v as &dyn fmt::Display)
-                .unwrap_or(&format!(
-                    "
-{}(FilterNode)
-{}N/A",
-                    get_tab(self._depth + 1),
-                    get_tab(self._depth + 2)
-                )),
-            self._postprocessor
-                .as_ref()
-                .map(|v| v as &dyn fmt::Display)
-                .unwrap_or(&format!(
-                    "
-{}(PostProcessorNode)
-{}N/A",
-                    get_tab(self._depth + 1),
-                    get_tab(self._depth + 2)
-                ))
-        )
+        TreeWriter::write(f, |writer| writer.visit_get(self))
     }
 }
 
 impl fmt::Display for FilterNode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "\n{}(FilterNode){}",
-            get_tab(self._depth),
-            self.condition
-        )
+        TreeWriter::write(f, |writer| writer.visit_filter(self))
     }
 }
 
 impl fmt::Display for TableNode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "\n{}(TableNode)
-{}table_name: {:?}",
-            get_tab(self._depth),
-            get_tab(self._depth + 1),
-            self.table_name,
-        )
+        TreeWriter::write(f, |writer| writer.visit_table(self))
     }
 }
 
 impl fmt::Display for ColumnNode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "\n{}(ColumnNode)
-{}is_wildcard: {:?}
-{}column_names: {:?}",
-            get_tab(self._depth),
-            get_tab(self._depth + 1),
-            self.is_wildcard,
-            get_tab(self._depth + 1),
-            self.column_names,
-        )
+        TreeWriter::write(f, |writer| writer.visit_column(self))
+    }
+}
+
+impl Spanned for GetNode {
+    fn span(&self) -> Span {
+        self._span
+    }
+}
+
+impl Spanned for PartialGetNode {
+    fn span(&self) -> Span {
+        self._span
+    }
+}
+
+impl Spanned for TableNode {
+    fn span(&self) -> Span {
+        self._span
+    }
+}
+
+impl Spanned for ColumnNode {
+    fn span(&self) -> Span {
+        self._span
+    }
+}
+
+impl Spanned for FilterNode {
+    fn span(&self) -> Span {
+        self._span
     }
 }
 
@@ -449,6 +724,7 @@ impl fmt::Display for ColumnNode {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::language::lexer::Lexer;
 
     #[test]
     fn unit_test_column_parsing_error() {
@@ -493,6 +769,7 @@ mod tests {
             is_wildcard: true,
 
             _literal: "get all".to_string(),
+            _span: Span::default(),
             _depth: 0,
         };
         let mut idx: usize = 1;
@@ -516,6 +793,7 @@ mod tests {
             is_wildcard: false,
 
             _literal: "get id".to_string(),
+            _span: Span::default(),
             _depth: 0,
         };
         let mut idx: usize = 1;
@@ -551,6 +829,7 @@ mod tests {
             is_wildcard: false,
 
             _literal: "get id, cost and time".to_string(),
+            _span: Span::default(),
             _depth: 0,
         };
         let mut idx: usize = 1;
@@ -598,6 +877,7 @@ mod tests {
             table_name: "table_name".to_string(),
 
             _literal: "from table_name".to_string(),
+            _span: Span::default(),
             _depth: 0,
         };
         let mut idx: usize = 0;
@@ -608,4 +888,47 @@ mod tests {
             Err(err) => assert!(false, "Output errored out -> {}", err),
         }
     }
+
+    #[test]
+    fn unit_test_node_spans() {
+        let input: String = "get name\nfrom drinks where price < 5;".to_string();
+        let tokens: Vec<Token> = Lexer::new(&input).unwrap().tokens;
+        let mut idx: usize = 1;
+
+        let node: GetNode = GetNode::parse(&tokens, &mut idx, 0).unwrap();
+
+        assert_eq!(node.span(), Span::new(0, 36));
+        assert_eq!(node._columns.span(), Span::new(0, 8));
+        assert_eq!(node._table.span(), Span::new(9, 20));
+        assert_eq!(node._filter.unwrap().span(), Span::new(21, 36));
+        assert_eq!(&input[9..20], "from drinks");
+    }
+
+    #[test]
+    fn unit_test_get_parse_recovering() {
+        let input: String =
+            "get name, from drinks where (price < 5 then sort by size and limit it to 3;"
+                .to_string();
+        let tokens: Vec<Token> = Lexer::new(&input).unwrap().tokens;
+        let mut idx: usize = 1;
+        let mut errors: Vec<EaqlError> = vec![];
+
+        let node: PartialGetNode = GetNode::parse_recovering(&tokens, &mut idx, 0, &mut errors);
+
+        // The broken clauses are left out, everything around them is kept
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(errors[0], EaqlError::UnexpectedToken(_)));
+        assert!(matches!(errors[1], EaqlError::UnbalancedParen(_)));
+        assert!(node.columns().is_none());
+        assert!(node.filter().is_none());
+        assert_eq!(node.table().map(|v| v.name()), Some("drinks"));
+        assert_eq!(
+            node.postprocessor()
+                .and_then(|v| v.limit())
+                .map(|v| v.value()),
+            Some(3)
+        );
+        assert_eq!(tokens[idx].token_type, TokenType::EoqToken);
+        assert!(node.complete().is_none());
+    }
 }
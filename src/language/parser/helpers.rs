@@ -1,18 +1,25 @@
-use crate::language::tokens::{Token, TokenType};
+use crate::language::{
+    errors::{EaqlError, ErrorContext},
+    tokens::{Span, Token, TokenType},
+};
 
 const TAB_SIZE: u16 = 2;
 
-pub fn validate_length(tokens: &Vec<Token>, idx: &usize, required: bool) -> Result<(), String> {
+pub fn validate_length(tokens: &Vec<Token>, idx: &usize, required: bool) -> Result<(), EaqlError> {
     if *idx >= tokens.len() && required {
-        return Err(format!(
+        let message: String = format!(
             "Query valid until after \"{}\"! Possible unfinished query?",
-            tokens[..*idx]
-                .into_iter()
+            tokens[..(*idx).min(tokens.len())]
+                .iter()
                 .map(|x| x.lexeme.as_str())
                 .collect::<Vec<&str>>()
                 .join(" ")
-        )
-        .to_string());
+        );
+
+        return Err(EaqlError::UnexpectedEnd(Box::new(match tokens.last() {
+            Some(token) => ErrorContext::after(message, token),
+            None => ErrorContext::new(message),
+        })));
     }
 
     Ok(())
@@ -22,20 +29,23 @@ pub fn get_tab(depth: u16) -> String {
     " ".repeat(TAB_SIZE as usize * depth as usize)
 }
 
-pub fn valid_until_warning(tokens: &Vec<Token>, idx: &usize) -> String {
+pub fn valid_until_warning(tokens: &Vec<Token>, idx: &usize) -> EaqlError {
     if *idx >= tokens.len() {
-        return "N/A".to_string();
+        return validate_length(tokens, idx, true)
+            .expect_err("Index is past the end of the tokens");
     }
 
-    format!(
-        "Query valid until after \"{}\"!",
-        tokens[..*idx]
-            .into_iter()
-            .map(|x| x.lexeme.as_str())
-            .collect::<Vec<&str>>()
-            .join(" ")
-    )
-    .to_string()
+    EaqlError::UnexpectedToken(Box::new(ErrorContext::at(
+        format!(
+            "Query valid until after \"{}\"!",
+            tokens[..*idx]
+                .iter()
+                .map(|x| x.lexeme.as_str())
+                .collect::<Vec<&str>>()
+                .join(" ")
+        ),
+        &tokens[*idx],
+    )))
 }
 
 // Look a token ahead
@@ -43,6 +53,15 @@ pub fn peek_one(tokens: &Vec<Token>, idx: &usize) -> TokenType {
     if *idx + 1 >= tokens.len() {
         TokenType::NullToken
     } else {
-        tokens[*idx + 1].token_type.clone()
+        tokens[*idx + 1].token_type
+    }
+}
+
+/// Span covering the tokens between the given bounds, `end_idx` is exclusive.
+pub fn span_between(tokens: &Vec<Token>, start_idx: usize, end_idx: usize) -> Span {
+    match (tokens.get(start_idx), tokens.get(end_idx.max(1) - 1)) {
+        (Some(first), Some(last)) if start_idx < end_idx => first.span.to(last.span),
+        (Some(first), _) => Span::new(first.span.start, first.span.start),
+        _ => Span::default(),
     }
 }
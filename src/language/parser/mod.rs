@@ -1,5 +1,7 @@
+pub mod arithmetic;
 pub mod conditional;
 pub mod database;
+pub(crate) mod display;
 pub mod get;
 pub mod helpers;
 pub mod parser;
@@ -1,13 +1,17 @@
 use crate::{
+    formatter::{Style, eaql},
     language::{
+        errors::{EaqlError, ErrorContext, Warning},
         parser::database::DatabaseNode,
         parser::{
-            get::GetNode,
-            helpers::{get_tab, validate_length},
+            display::TreeWriter,
+            get::{GetNode, PartialGetNode},
+            helpers::validate_length,
         },
-        tokens::{Token, TokenType},
+        tokens::{Span, Spanned, Token, TokenType},
+        visitor::Visitor,
     },
-    utils::logger,
+    transpiler::{dialect::Dialect, sql},
 };
 use std::fmt;
 
@@ -20,24 +24,51 @@ pub enum ImpliedAction {
     _Rename,
 }
 
-#[derive(Debug)]
+/// What a query asks for.
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Statement {
+    /// Retrieving data from a table (`get ... from ...`).
+    Get(GetNode),
+    /// Creating, destroying, using or showing databases.
+    Database(DatabaseNode),
+}
+
+/// Whatever could be parsed of a query, kept when recovering from
+/// errors so tools can still make use of the valid parts.
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PartialQuery {
+    /// A get query, with each of its clauses that could be parsed.
+    Get(PartialGetNode),
+    /// A database operation, these are either parsed in full or not at all.
+    Database(DatabaseNode),
+    /// Nothing could be made of the query (i.e. an unknown action).
+    Empty,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Query {
-    _get: Option<GetNode>,
-    _database: Option<DatabaseNode>,
+    statement: Statement,
+
     _depth: u16,
 }
 
 impl ImpliedAction {
-    pub fn try_from(value: TokenType) -> Result<ImpliedAction, String> {
-        match value {
+    pub fn try_from(token: &Token) -> Result<ImpliedAction, EaqlError> {
+        match token.token_type {
             TokenType::CreateKeyword => Ok(ImpliedAction::Create),
             TokenType::DeleteKeyword => Ok(ImpliedAction::Delete),
             TokenType::ShowKeyword => Ok(ImpliedAction::Show),
             TokenType::UseKeyword => Ok(ImpliedAction::Use),
-            _ => Err(format!(
-                "Invalid action token type encountered -> got {:?}",
-                value
-            )),
+            _ => Err(EaqlError::UnknownAction(Box::new(ErrorContext::at(
+                format!(
+                    "Invalid action token type encountered -> got {:?}",
+                    token.token_type
+                ),
+                token,
+            )))),
         }
     }
 }
@@ -46,19 +77,18 @@ impl Query {
     /// Takes current node type and given the current location in the
     /// query defined by the borrowed index, makes an attempt to parse
     /// this node and associated subnodes for the Abstract Syntax Tree.
-    pub fn parse(tokens: &Vec<Token>, idx: &mut usize, depth: u16) -> Result<Query, String> {
-        validate_length(&tokens, &idx, true)?;
+    pub fn parse(tokens: &Vec<Token>, idx: &mut usize, depth: u16) -> Result<Query, EaqlError> {
+        validate_length(tokens, idx, true)?;
 
         if tokens[*idx].token_type == TokenType::Get {
             *idx += 1;
 
-            let get_node: GetNode = GetNode::parse(&tokens, idx, depth + 1)?;
+            let get_node: GetNode = GetNode::parse(tokens, idx, depth + 1)?;
             return Ok(Query {
-                _get: Some(get_node),
-                _database: None,
+                statement: Statement::Get(get_node),
                 _depth: depth,
             });
-        } else if vec![
+        } else if [
             TokenType::CreateKeyword,
             TokenType::DeleteKeyword,
             TokenType::UseKeyword,
@@ -74,88 +104,159 @@ impl Query {
                 *idx += 1;
 
                 let database_node: DatabaseNode = DatabaseNode::parse(
-                    &tokens,
+                    tokens,
                     idx,
                     depth + 1,
-                    ImpliedAction::try_from(tokens[*idx - 2].token_type)?,
+                    ImpliedAction::try_from(&tokens[*idx - 2])?,
                 )?;
 
                 return Ok(Query {
-                    _get: None,
-                    _database: Some(database_node),
+                    statement: Statement::Database(database_node),
                     _depth: depth,
                 });
             } else {
-                return Err(format!(
-                    "Query recieved an action keyword, but received an invalid target keyword `{:?}`. Valid targets are: `Database`",
-                    tokens[*idx].token_type
-                ));
+                return Err(EaqlError::UnexpectedToken(Box::new(ErrorContext::at(
+                    format!(
+                        "Query recieved an action keyword, but received an invalid target keyword `{:?}`. Valid targets are: `Database`",
+                        tokens[*idx].token_type
+                    ),
+                    &tokens[*idx],
+                ).with_hint("expected `database` after the action, i.e. `create database shop;`"))));
             }
         }
 
-        return Err("Couldn't determine requested action. Please review documentation for valid query actions.".to_string());
+        return Err(EaqlError::UnknownAction(Box::new(ErrorContext::at(
+            "Couldn't determine requested action. Please review documentation for valid query actions.".to_string(),
+            &tokens[*idx],
+        ).with_hint("queries start with an action like `get`, `create`, `delete`, `use` or `show`"))));
     }
 
     /// Outputs current AST node transpiled with color         
     /// and it's raw query counterpart. Output are used by
     /// the Transpiler REPL.
-    pub fn transpile_color(&self) -> (String, String) {
-        if let Some(get) = &self._get {
-            return get.transpile_color();
-        } else if let Some(database) = &self._database {
-            return database.transpile_color();
-        } else {
-            logger::error("A fatal error occurred while transpiling your query!");
-        };
+    pub fn transpile_color(&self, dialect: &dyn Dialect) -> Result<(String, String), EaqlError> {
+        sql::transpile_color(dialect, |writer| writer.visit_query(self))
     }
 
     /// Ouputs current AST node tranpile to raw SQL.
-    pub fn transpile_raw(&self) -> String {
-        if let Some(get) = &self._get {
-            return get.transpile_raw();
-        } else if let Some(database) = &self._database {
-            return database.transpile_raw();
-        } else {
-            logger::error("A fatal error occurred while transpiling your query!");
-        };
+    pub fn transpile_raw(&self, dialect: &dyn Dialect) -> Result<String, EaqlError> {
+        sql::transpile_raw(dialect, |writer| writer.visit_query(self))
+    }
+
+    /// Outputs the query rewritten as canonical EAQL, parsing
+    /// the output gives back the same query.
+    pub fn to_eaql(&self, style: &Style) -> Result<String, EaqlError> {
+        eaql::write(style, |writer| writer.visit_query(self))
+    }
+
+    /// Collects warnings for this node and its subnodes.
+    pub fn warnings(&self) -> Vec<Warning> {
+        match &self.statement {
+            Statement::Get(get) => get.warnings(),
+            Statement::Database(_) => vec![],
+        }
+    }
+
+    /// What the query asks for.
+    pub fn statement(&self) -> &Statement {
+        &self.statement
+    }
+
+    pub fn statement_mut(&mut self) -> &mut Statement {
+        &mut self.statement
+    }
+
+    /// Consumes the query, returning what it asks for.
+    pub fn into_statement(self) -> Statement {
+        self.statement
+    }
+
+    pub(crate) fn depth(&self) -> u16 {
+        self._depth
+    }
+
+    pub(crate) fn new(statement: Statement, depth: u16) -> Query {
+        Query {
+            statement,
+            _depth: depth,
+        }
     }
 }
 
 impl fmt::Display for Query {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}(Query){}{}",
-            get_tab(self._depth),
-            self._get
-                .as_ref()
-                .map(|v| v as &dyn fmt::Display)
-                .unwrap_or(&""),
-            self._database
-                .as_ref()
-                .map(|v| v as &dyn fmt::Display)
-                .unwrap_or(&"")
-        )
+        TreeWriter::write(f, |writer| writer.visit_query(self))
+    }
+}
+
+impl fmt::Display for Statement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        TreeWriter::write(f, |writer| writer.visit_statement(self))
+    }
+}
+
+impl Spanned for Query {
+    fn span(&self) -> Span {
+        self.statement.span()
+    }
+}
+
+impl Spanned for Statement {
+    fn span(&self) -> Span {
+        match self {
+            Statement::Get(node) => node.span(),
+            Statement::Database(node) => node.span(),
+        }
     }
 }
 
 /// Main parsing interface. Takes a vector of tokens
 /// produced by the Lexer class, and attempts to parse
 /// it into an Abstract Syntax Tree.
-pub fn parse(tokens: &Vec<Token>) -> Result<Query, String> {
+pub fn parse(tokens: &Vec<Token>) -> Result<Query, EaqlError> {
     let mut idx: usize = 0;
 
     Query::parse(tokens, &mut idx, 0)
 }
 
+/// Parsing interface reporting every error in the query rather than
+/// just the first. Parsing picks back up at the next clause after each
+/// error, and whatever could be parsed is returned alongside the errors.
+pub fn parse_recovering(tokens: &Vec<Token>) -> (PartialQuery, Vec<EaqlError>) {
+    let mut idx: usize = 0;
+    let mut errors: Vec<EaqlError> = vec![];
+
+    // Database queries are a single clause, so only get queries recover
+    if tokens.first().map(|v| v.token_type) == Some(TokenType::Get) {
+        idx += 1;
+
+        let get_node: PartialGetNode = GetNode::parse_recovering(tokens, &mut idx, 1, &mut errors);
+        return (PartialQuery::Get(get_node), errors);
+    }
+
+    match Query::parse(tokens, &mut idx, 0) {
+        Ok(query) => match query.into_statement() {
+            Statement::Get(get) => (PartialQuery::Get(get.into()), errors),
+            Statement::Database(database) => (PartialQuery::Database(database), errors),
+        },
+        Err(err) => {
+            errors.push(err);
+            (PartialQuery::Empty, errors)
+        }
+    }
+}
+
 /* Template for Nodes
 impl TemplateNode {
     pub fn parse(
         tokens: &Vec<Token>,
         idx: &mut usize,
-        depth: u16) -> Result<TemplateNode, String> {
+        depth: u16) -> Result<TemplateNode, EaqlError> {
 
-        return Err("ERROR PLACEHOLDER".to_string());
+        return Err(EaqlError::UnexpectedToken(Box::new(
+            ErrorContext::at("ERROR PLACEHOLDER".to_string(), &tokens[*idx])
+                .with_hint("HINT PLACEHOLDER"),
+        )));
     }
 }
 */
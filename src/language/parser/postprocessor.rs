@@ -1,24 +1,61 @@
 use crate::{
     language::{
-        parser::helpers::{get_tab, peek_one, validate_length},
-        tokens::{Token, TokenType},
+        errors::{EaqlError, ErrorContext, Warning},
+        parser::{
+            display::TreeWriter,
+            helpers::{peek_one, span_between, validate_length},
+        },
+        tokens::{Span, Spanned, Token, TokenType},
+        visitor::Visitor,
     },
-    utils::colors::{AnsiColor, colorize},
+    transpiler::{dialect::Dialect, sql},
 };
-use std::{fmt, usize};
+use std::fmt;
 
 #[derive(PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LimitNode {
     limit: i32,
 
     _literal: String,
+    _span: Span,
+    _depth: u16,
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+#[derive(PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SortKeyNode {
+    column: String,
+    direction: SortDirection,
+
+    _span: Span,
+    _depth: u16,
+}
+
+#[derive(PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SortNode {
+    keys: Vec<SortKeyNode>,
+
+    _literal: String,
+    _span: Span,
     _depth: u16,
 }
 
 #[derive(PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PostProcessorNode {
-    pub limit: Option<LimitNode>,
+    limit: Option<LimitNode>,
+    sort: Option<SortNode>,
 
+    _span: Span,
     _depth: u16,
 }
 
@@ -26,36 +63,53 @@ impl LimitNode {
     /// Takes current node type and given the current location in the
     /// query defined by the borrowed index, makes an attempt to parse
     /// this node and associated subnodes for the Abstract Syntax Tree.
-    pub fn parse(tokens: &Vec<Token>, idx: &mut usize, depth: u16) -> Result<LimitNode, String> {
+    pub fn parse(tokens: &Vec<Token>, idx: &mut usize, depth: u16) -> Result<LimitNode, EaqlError> {
         validate_length(tokens, idx, true)?;
 
         let start_idx: usize = *idx - 1;
 
         if tokens[*idx].token_type != TokenType::NumberLiteral {
-            return Err(format!(
-                "Limit post-processor expects a number literal, got -> {:?}",
-                tokens[*idx].token_type
-            ));
+            return Err(EaqlError::InvalidLimit(Box::new(
+                ErrorContext::at(
+                    format!(
+                        "Limit post-processor expects a number literal, got -> {:?}",
+                        tokens[*idx].token_type
+                    ),
+                    &tokens[*idx],
+                )
+                .with_hint("limits must be a whole number, i.e. `then limit it to 10`"),
+            )));
         }
 
+        let limit: i32 = match tokens[*idx].literal.parse::<i32>() {
+            Ok(state) => state,
+            Err(_) => {
+                return Err(EaqlError::InvalidLimit(Box::new(
+                    ErrorContext::at(
+                        format!(
+                            "Limit post-processor expects 32-bit integer, got -> {}",
+                            tokens[*idx].lexeme
+                        ),
+                        &tokens[*idx],
+                    )
+                    .with_hint("limits must be a whole number, i.e. `then limit it to 10`"),
+                )));
+            }
+        };
+
+        *idx += 1;
+
         return Ok(LimitNode {
-            limit: match tokens[*idx].literal.parse::<i32>() {
-                Ok(state) => state,
-                Err(_) => {
-                    return Err(format!(
-                        "Limit post-processor expects 32-bit integer, got -> {}",
-                        tokens[*idx].lexeme
-                    ));
-                }
-            },
+            limit,
 
             _literal: {
-                tokens[start_idx..*idx + 1]
+                tokens[start_idx..*idx]
                     .iter()
                     .map(|v| v.lexeme.as_str())
                     .collect::<Vec<&str>>()
                     .join(" ")
             },
+            _span: span_between(tokens, start_idx, *idx),
             _depth: depth,
         });
     }
@@ -63,16 +117,259 @@ impl LimitNode {
     /// Outputs current AST node transpiled with color         
     /// and it's raw query counterpart. Output are used by
     /// the Transpiler REPL.
-    pub fn transpile_color(&self) -> (String, String) {
-        (
-            colorize(&self._literal, AnsiColor::Magenta),
-            colorize(&format!("LIMIT {}", self.limit), AnsiColor::Magenta),
-        )
+    pub fn transpile_color(&self, dialect: &dyn Dialect) -> (String, String) {
+        // Limits never fail to transpile
+        sql::transpile_color(dialect, |writer| writer.visit_limit(self)).unwrap_or_default()
     }
 
-    /// Outputs current AST node transpiled to raw SQL.
+    /// Outputs current AST node transpiled to raw SQL. Dialects using
+    /// `TOP` handle the limit when selecting columns instead.
+    pub fn transpile_raw(&self, dialect: &dyn Dialect) -> String {
+        sql::transpile_raw(dialect, |writer| writer.visit_limit(self)).unwrap_or_default()
+    }
+
+    /// Collects warnings for this node and its subnodes.
+    pub fn warnings(&self) -> Vec<Warning> {
+        if self.limit > 0 {
+            return vec![];
+        }
+
+        vec![
+            Warning::new(
+                format!(
+                    "Limiting to {} rows will always return nothing.",
+                    self.limit
+                ),
+                self._span,
+            )
+            .with_hint("remove the limit, or limit to at least 1 row"),
+        ]
+    }
+
+    /// Maximum number of rows returned.
+    pub fn value(&self) -> i32 {
+        self.limit
+    }
+
+    pub fn set_value(&mut self, limit: i32) {
+        self.limit = limit;
+    }
+
+    pub(crate) fn literal(&self) -> &str {
+        &self._literal
+    }
+
+    pub(crate) fn depth(&self) -> u16 {
+        self._depth
+    }
+
+    pub(crate) fn new(limit: i32, literal: String, depth: u16) -> LimitNode {
+        LimitNode {
+            limit,
+
+            _literal: literal,
+            _span: Span::default(),
+            _depth: depth,
+        }
+    }
+}
+
+impl SortDirection {
+    fn try_from(token: &Token) -> Result<SortDirection, EaqlError> {
+        match token.lexeme.to_lowercase().as_str() {
+            "ascending" => Ok(SortDirection::Ascending),
+            "descending" => Ok(SortDirection::Descending),
+            _ => Err(EaqlError::InvalidSort(Box::new(
+                ErrorContext::at(
+                    format!(
+                        "Sort post-processor expects \"ascending\" or \"descending\", got -> {}",
+                        token.lexeme
+                    ),
+                    token,
+                )
+                .with_hint("expected `ascending` or `descending`"),
+            ))),
+        }
+    }
+
+    /// Outputs the SQL keyword for this direction.
     pub fn transpile_raw(&self) -> String {
-        format!("LIMIT {}", self.limit)
+        match self {
+            SortDirection::Ascending => "ASC".to_string(),
+            SortDirection::Descending => "DESC".to_string(),
+        }
+    }
+}
+
+impl SortKeyNode {
+    /// Takes current node type and given the current location in the
+    /// query defined by the borrowed index, makes an attempt to parse
+    /// this node and associated subnodes for the Abstract Syntax Tree.
+    pub fn parse(
+        tokens: &Vec<Token>,
+        idx: &mut usize,
+        depth: u16,
+    ) -> Result<SortKeyNode, EaqlError> {
+        validate_length(tokens, idx, true)?;
+
+        let start_idx: usize = *idx;
+
+        if tokens[*idx].token_type != TokenType::Identifier {
+            return Err(EaqlError::InvalidSort(Box::new(
+                ErrorContext::at(
+                    format!(
+                        "Sort post-processor expects a column name to sort by, got -> {}",
+                        tokens[*idx].lexeme
+                    ),
+                    &tokens[*idx],
+                )
+                .with_hint("expected a column name to sort by, i.e. `sort by price`"),
+            )));
+        }
+
+        let column: String = tokens[*idx].literal.clone();
+        let mut direction: SortDirection = SortDirection::Ascending;
+
+        *idx += 1;
+        validate_length(tokens, idx, true)?;
+
+        // Direction is optional and defaults to ascending, it may
+        // also be followed by an "order" (i.e. "in descending order")
+        if tokens[*idx].token_type == TokenType::SortType {
+            direction = SortDirection::try_from(&tokens[*idx])?;
+            *idx += 1;
+            validate_length(tokens, idx, true)?;
+
+            if tokens[*idx].token_type == TokenType::Order {
+                *idx += 1;
+            }
+        }
+
+        Ok(SortKeyNode {
+            column,
+            direction,
+
+            _span: span_between(tokens, start_idx, *idx),
+            _depth: depth,
+        })
+    }
+
+    /// Outputs current AST node transpiled to raw SQL.
+    pub fn transpile_raw(&self, dialect: &dyn Dialect) -> Result<String, EaqlError> {
+        sql::transpile_raw(dialect, |writer| writer.visit_sort_key(self))
+    }
+
+    pub fn column(&self) -> &str {
+        &self.column
+    }
+
+    pub fn direction(&self) -> SortDirection {
+        self.direction
+    }
+
+    pub(crate) fn depth(&self) -> u16 {
+        self._depth
+    }
+
+    pub(crate) fn new(column: &str, direction: SortDirection, depth: u16) -> SortKeyNode {
+        SortKeyNode {
+            column: column.to_string(),
+            direction,
+
+            _span: Span::default(),
+            _depth: depth,
+        }
+    }
+}
+
+impl SortNode {
+    /// Takes current node type and given the current location in the
+    /// query defined by the borrowed index, makes an attempt to parse
+    /// this node and associated subnodes for the Abstract Syntax Tree.
+    pub fn parse(tokens: &Vec<Token>, idx: &mut usize, depth: u16) -> Result<SortNode, EaqlError> {
+        validate_length(tokens, idx, true)?;
+
+        // We subtract 1 from this because the sort keyword has been processed already
+        let start_idx: usize = *idx - 1;
+
+        if tokens[*idx].token_type != TokenType::SortHelper {
+            return Err(EaqlError::InvalidSort(Box::new(
+                ErrorContext::at(
+                    format!(
+                        "Sort post-processor expects \"by\" before the sort columns, got -> {}",
+                        tokens[*idx].lexeme
+                    ),
+                    &tokens[*idx],
+                )
+                .with_hint("expected `by`, i.e. `sort by price`"),
+            )));
+        }
+
+        *idx += 1;
+
+        let mut keys: Vec<SortKeyNode> = vec![SortKeyNode::parse(tokens, idx, depth + 1)?];
+
+        // Additional keys are listed the same way as columns, an "and"
+        // that isn't followed by a column belongs to the post-processor list
+        while *idx < tokens.len()
+            && (tokens[*idx].token_type == TokenType::Comma
+                || (tokens[*idx].token_type == TokenType::And
+                    && peek_one(tokens, idx) == TokenType::Identifier))
+        {
+            *idx += 1;
+            keys.push(SortKeyNode::parse(tokens, idx, depth + 1)?);
+        }
+
+        Ok(SortNode {
+            keys,
+
+            _literal: tokens[start_idx..*idx]
+                .iter()
+                .map(|v| v.lexeme.as_str())
+                .collect::<Vec<&str>>()
+                .join(" "),
+            _span: span_between(tokens, start_idx, *idx),
+            _depth: depth,
+        })
+    }
+
+    /// Outputs current AST node transpiled with color
+    /// and it's raw query counterpart. Output are used by
+    /// the Transpiler REPL.
+    pub fn transpile_color(&self, dialect: &dyn Dialect) -> Result<(String, String), EaqlError> {
+        sql::transpile_color(dialect, |writer| writer.visit_sort(self))
+    }
+
+    /// Outputs current AST node transpiled to raw SQL.
+    pub fn transpile_raw(&self, dialect: &dyn Dialect) -> Result<String, EaqlError> {
+        sql::transpile_raw(dialect, |writer| writer.visit_sort(self))
+    }
+
+    /// Columns sorted by, in order of priority.
+    pub fn keys(&self) -> &[SortKeyNode] {
+        &self.keys
+    }
+
+    pub fn keys_mut(&mut self) -> &mut [SortKeyNode] {
+        &mut self.keys
+    }
+
+    pub(crate) fn literal(&self) -> &str {
+        &self._literal
+    }
+
+    pub(crate) fn depth(&self) -> u16 {
+        self._depth
+    }
+
+    pub(crate) fn new(keys: Vec<SortKeyNode>, literal: String, depth: u16) -> SortNode {
+        SortNode {
+            keys,
+
+            _literal: literal,
+            _span: Span::default(),
+            _depth: depth,
+        }
     }
 }
 
@@ -82,14 +379,51 @@ impl PostProcessorNode {
         final_node: &mut PostProcessorNode,
         depth: u16,
         idx: &mut usize,
-    ) -> Result<(), String> {
+    ) -> Result<(), EaqlError> {
         validate_length(tokens, idx, true)?;
 
         match tokens[*idx].token_type {
             TokenType::And => {
+                if final_node.limit.is_none() && final_node.sort.is_none() {
+                    return Err(EaqlError::UnexpectedToken(Box::new(ErrorContext::at(
+                        "Post-processor list can't start with \"and\", expected a post-processor."
+                            .to_string(),
+                        &tokens[*idx],
+                    ))));
+                }
+
                 *idx += 1;
+                validate_length(tokens, idx, true)?;
+
+                if !matches!(
+                    tokens[*idx].token_type,
+                    TokenType::LimitKeyword | TokenType::Sort | TokenType::Order
+                ) {
+                    return Err(EaqlError::UnexpectedToken(Box::new(
+                        ErrorContext::at(
+                            format!(
+                                "Expected a post-processor after \"and\", got -> \"{}\"",
+                                tokens[*idx].lexeme
+                            ),
+                            &tokens[*idx],
+                        )
+                        .with_hint(
+                            "expected a post-processor like `limit it to 10` or `sort by price`",
+                        ),
+                    )));
+                }
             }
             TokenType::LimitKeyword => {
+                if final_node.limit.is_some() {
+                    return Err(EaqlError::InvalidLimit(Box::new(
+                        ErrorContext::at(
+                            "Limit post-processor was provided more than once.".to_string(),
+                            &tokens[*idx],
+                        )
+                        .with_hint("keep a single limit, i.e. `then limit it to 10`"),
+                    )));
+                }
+
                 *idx += 1;
 
                 final_node.limit = match LimitNode::parse(tokens, idx, depth + 1) {
@@ -97,11 +431,27 @@ impl PostProcessorNode {
                     Err(msg) => return Err(msg),
                 };
             }
+            TokenType::Sort | TokenType::Order => {
+                if final_node.sort.is_some() {
+                    return Err(EaqlError::InvalidSort(Box::new(ErrorContext::at(
+                        "Sort post-processor was provided more than once, list every sort column in a single sort instead."
+                            .to_string(),
+                        &tokens[*idx],
+                    ))));
+                }
+
+                *idx += 1;
+
+                final_node.sort = Some(SortNode::parse(tokens, idx, depth + 1)?);
+            }
             _ => {
-                return Err(format!(
-                    "Unexpected token, expected post-processor entrance keyword or list continuation, got -> \"{}\"",
-                    tokens[*idx].lexeme
-                ));
+                return Err(EaqlError::UnexpectedToken(Box::new(ErrorContext::at(
+                    format!(
+                        "Unexpected token, expected post-processor entrance keyword or list continuation, got -> \"{}\"",
+                        tokens[*idx].lexeme
+                    ),
+                    &tokens[*idx],
+                ).with_hint("expected a post-processor like `limit it to 10` or `sort by price`"))));
             }
         };
 
@@ -113,12 +463,24 @@ impl PostProcessorNode {
         final_node: &mut PostProcessorNode,
         depth: u16,
         idx: &mut usize,
-    ) -> Result<(), String> {
-        let next_token: TokenType = peek_one(tokens, &idx);
+    ) -> Result<(), EaqlError> {
+        validate_length(tokens, idx, true)?;
 
         // End of query, required after postprocessor nodes
-        if next_token == TokenType::EoqToken {
-            *idx += 1;
+        if tokens[*idx].token_type == TokenType::EoqToken {
+            if final_node.limit.is_none() && final_node.sort.is_none() {
+                return Err(EaqlError::UnexpectedEnd(Box::new(
+                    ErrorContext::at(
+                        "Post-processor entrance found, but no post-processor was provided!"
+                            .to_string(),
+                        &tokens[*idx],
+                    )
+                    .with_hint(
+                        "expected a post-processor like `limit it to 10` or `sort by price`",
+                    ),
+                )));
+            }
+
             return Ok(());
         } else {
             PostProcessorNode::handle_postprocessor(tokens, final_node, depth, idx)?;
@@ -136,21 +498,25 @@ impl PostProcessorNode {
         tokens: &Vec<Token>,
         idx: &mut usize,
         depth: u16,
-    ) -> Result<Option<PostProcessorNode>, String> {
+    ) -> Result<Option<PostProcessorNode>, EaqlError> {
         validate_length(tokens, idx, true)?;
 
         if tokens[*idx].token_type != TokenType::PostProcessorEntrance {
             return Ok(None);
         }
 
+        let start_idx: usize = *idx;
         *idx += 1;
 
         let mut final_node: PostProcessorNode = PostProcessorNode {
             limit: None,
+            sort: None,
+            _span: Span::default(),
             _depth: depth,
         };
 
         PostProcessorNode::recurse_build(tokens, &mut final_node, depth, idx)?;
+        final_node._span = span_between(tokens, start_idx, *idx);
 
         return Ok(Some(final_node));
     }
@@ -158,58 +524,100 @@ impl PostProcessorNode {
     /// Outputs current AST node transpiled with color         
     /// and it's raw query counterpart. Output are used by
     /// the Transpiler REPL.
-    pub fn transpile_color(&self) -> (String, String) {
-        let mut final_lexeme: Vec<String> = vec![];
-        let mut final_transpiled: Vec<String> = vec![];
+    pub fn transpile_color(&self, dialect: &dyn Dialect) -> Result<(String, String), EaqlError> {
+        sql::transpile_color(dialect, |writer| writer.visit_postprocessor(self))
+    }
 
-        if self.limit.is_some() {
-            let limit_transpiled: (String, String) = self.limit.as_ref().unwrap().transpile_color();
+    /// outputs current ast node transpiled to raw sql.
+    pub fn transpile_raw(&self, dialect: &dyn Dialect) -> Result<String, EaqlError> {
+        sql::transpile_raw(dialect, |writer| writer.visit_postprocessor(self))
+    }
 
-            final_lexeme.push(limit_transpiled.0);
-            final_transpiled.push(limit_transpiled.1);
-        }
+    /// Collects warnings for this node and its subnodes.
+    pub fn warnings(&self) -> Vec<Warning> {
+        self.limit.iter().flat_map(|v| v.warnings()).collect()
+    }
 
-        (final_lexeme.join(" "), final_transpiled.join(" "))
+    pub fn limit(&self) -> Option<&LimitNode> {
+        self.limit.as_ref()
     }
 
-    /// outputs current ast node transpiled to raw sql.
-    pub fn transpile_raw(&self) -> String {
-        let mut final_transpiled: Vec<String> = vec![];
+    pub fn sort(&self) -> Option<&SortNode> {
+        self.sort.as_ref()
+    }
 
-        if self.limit.is_some() {
-            let limit_transpiled: String = self.limit.as_ref().unwrap().transpile_raw();
+    pub fn limit_mut(&mut self) -> Option<&mut LimitNode> {
+        self.limit.as_mut()
+    }
 
-            final_transpiled.push(limit_transpiled);
-        }
+    pub fn sort_mut(&mut self) -> Option<&mut SortNode> {
+        self.sort.as_mut()
+    }
+
+    pub(crate) fn depth(&self) -> u16 {
+        self._depth
+    }
+
+    pub(crate) fn new(
+        limit: Option<LimitNode>,
+        sort: Option<SortNode>,
+        depth: u16,
+    ) -> PostProcessorNode {
+        PostProcessorNode {
+            limit,
+            sort,
 
-        final_transpiled.join(" ")
+            _span: Span::default(),
+            _depth: depth,
+        }
     }
 }
 
 impl fmt::Display for PostProcessorNode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "\n{}(PostProcessorNode){}",
-            get_tab(self._depth),
-            self.limit
-                .as_ref()
-                .map(|v| v as &dyn fmt::Display)
-                .unwrap_or(&"")
-        )
+        TreeWriter::write(f, |writer| writer.visit_postprocessor(self))
+    }
+}
+
+impl fmt::Display for SortNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        TreeWriter::write(f, |writer| writer.visit_sort(self))
+    }
+}
+
+impl fmt::Display for SortKeyNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        TreeWriter::write(f, |writer| writer.visit_sort_key(self))
     }
 }
 
 impl fmt::Display for LimitNode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "\n{}(LimitNode)
-{}limit: {:?}",
-            get_tab(self._depth),
-            get_tab(self._depth + 1),
-            self.limit,
-        )
+        TreeWriter::write(f, |writer| writer.visit_limit(self))
+    }
+}
+
+impl Spanned for LimitNode {
+    fn span(&self) -> Span {
+        self._span
+    }
+}
+
+impl Spanned for SortKeyNode {
+    fn span(&self) -> Span {
+        self._span
+    }
+}
+
+impl Spanned for SortNode {
+    fn span(&self) -> Span {
+        self._span
+    }
+}
+
+impl Spanned for PostProcessorNode {
+    fn span(&self) -> Span {
+        self._span
     }
 }
 
@@ -217,6 +625,7 @@ impl fmt::Display for LimitNode {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::transpiler::dialect::Generic;
 
     #[test]
     fn unit_test_postprocessor_error() {
@@ -286,9 +695,12 @@ mod tests {
             limit: Some(LimitNode {
                 limit: 5,
 
+                _span: Span::default(),
                 _depth: 1,
                 _literal: "limit 5".to_string(),
             }),
+            sort: None,
+            _span: Span::default(),
             _depth: 0,
         };
         let mut idx: usize = 0;
@@ -302,4 +714,116 @@ mod tests {
             Err(err) => assert!(false, "Output errored out -> {}", err),
         }
     }
+
+    #[test]
+    fn unit_test_postprocessor_sort_normal() {
+        let input: Vec<Token> = vec![
+            Token::new(
+                TokenType::PostProcessorEntrance,
+                &"".to_string(),
+                &"then".to_string(),
+            ),
+            Token::new(TokenType::Sort, &"".to_string(), &"sort".to_string()),
+            Token::new(TokenType::SortHelper, &"".to_string(), &"by".to_string()),
+            Token::new(
+                TokenType::Identifier,
+                &"price".to_string(),
+                &"price".to_string(),
+            ),
+            Token::new(
+                TokenType::SortType,
+                &"".to_string(),
+                &"descending".to_string(),
+            ),
+            Token::new(TokenType::Order, &"".to_string(), &"order".to_string()),
+            Token::new(TokenType::Comma, &"".to_string(), &",".to_string()),
+            Token::new(
+                TokenType::Identifier,
+                &"name".to_string(),
+                &"name".to_string(),
+            ),
+            Token::new(TokenType::And, &"".to_string(), &"and".to_string()),
+            Token::new(
+                TokenType::LimitKeyword,
+                &"".to_string(),
+                &"limit".to_string(),
+            ),
+            Token::new(TokenType::NumberLiteral, &"5".to_string(), &"5".to_string()),
+            Token::new(TokenType::EoqToken, &"".to_string(), &".".to_string()),
+        ];
+
+        let expected: PostProcessorNode = PostProcessorNode {
+            limit: Some(LimitNode {
+                limit: 5,
+
+                _span: Span::default(),
+                _depth: 1,
+                _literal: "limit 5".to_string(),
+            }),
+            sort: Some(SortNode {
+                keys: vec![
+                    SortKeyNode {
+                        column: "price".to_string(),
+                        direction: SortDirection::Descending,
+                        _span: Span::default(),
+                        _depth: 2,
+                    },
+                    SortKeyNode {
+                        column: "name".to_string(),
+                        direction: SortDirection::Ascending,
+                        _span: Span::default(),
+                        _depth: 2,
+                    },
+                ],
+
+                _span: Span::default(),
+                _depth: 1,
+                _literal: "sort by price descending order , name".to_string(),
+            }),
+            _span: Span::default(),
+            _depth: 0,
+        };
+        let mut idx: usize = 0;
+        let depth: u16 = 0;
+
+        match PostProcessorNode::parse(&input, &mut idx, depth) {
+            Ok(val) => match val {
+                Some(node) => {
+                    assert_eq!(
+                        node.transpile_raw(&Generic),
+                        Ok("ORDER BY price DESC, name ASC LIMIT 5".to_string())
+                    );
+                    assert_eq!(node, expected);
+                }
+                None => assert!(false, "Output returned nothing but something was expected!"),
+            },
+            Err(err) => assert!(false, "Output errored out -> {}", err),
+        }
+    }
+
+    #[test]
+    fn unit_test_postprocessor_sort_error_direction() {
+        let input: Vec<Token> = vec![
+            Token::new(
+                TokenType::PostProcessorEntrance,
+                &"".to_string(),
+                &"then".to_string(),
+            ),
+            Token::new(TokenType::Sort, &"".to_string(), &"sort".to_string()),
+            Token::new(
+                TokenType::SortType,
+                &"".to_string(),
+                &"ascending".to_string(),
+            ),
+            Token::new(TokenType::EoqToken, &"".to_string(), &".".to_string()),
+        ];
+
+        let mut idx: usize = 0;
+        let depth: u16 = 0;
+
+        match PostProcessorNode::parse(&input, &mut idx, depth) {
+            Ok(_) => assert!(false, "Output was expected to error!"),
+            Err(err) => assert!(true, "Output errored out -> {}", err),
+        }
+    }
 }
@@ -2,6 +2,7 @@ use lazy_static::lazy_static;
 use std::{collections::HashMap, fmt};
 
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TokenType {
     // Single Char Tokens
     OpenParen,
@@ -13,13 +14,39 @@ pub enum TokenType {
     Gte,
     Lte,
     Equal,
+    NotEqual,
     Lt,
     Gt,
 
+    // Pattern Matching
+    Contains,
+    StartsWith,
+    EndsWith,
+    Like,
+    IgnoreCase,
+
+    // Lists and Ranges
+    OneOf,
+    NotOneOf,
+    Between,
+    NotBetween,
+
+    // Empty Values
+    IsEmpty,
+    IsNotEmpty,
+
+    // Arithmetic
+    Plus,
+    Minus,
+    Star,
+    Slash,
+
     // Literals
     Identifier,
     StringLiteral,
     NumberLiteral,
+    BooleanLiteral,
+    NullLiteral,
 
     // Keywords
     DeleteKeyword,
@@ -47,23 +74,101 @@ pub enum TokenType {
     NullToken,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+/// Byte offsets of a token within the original query, `end` is exclusive.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Span {
+        Span { start, end }
+    }
+
+    /// Smallest span covering both spans.
+    pub fn to(&self, other: Span) -> Span {
+        Span::new(self.start.min(other.start), self.end.max(other.end))
+    }
+}
+
+/// Anything that can be traced back to a stretch of the original query,
+/// used by diagnostics, editors and source maps.
+pub trait Spanned {
+    fn span(&self) -> Span;
+}
+
+/// Line and column of a character in the query, both counted from 1.
+/// Columns count characters rather than bytes.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Default for Position {
+    fn default() -> Position {
+        Position { line: 1, column: 1 }
+    }
+}
+
+impl Position {
+    /// Position of the character after the given text, if the text
+    /// started at this position.
+    pub fn advance(&self, text: &str) -> Position {
+        text.chars().fold(*self, |position, c| match c {
+            '\n' => Position {
+                line: position.line + 1,
+                column: 1,
+            },
+            _ => Position {
+                column: position.column + 1,
+                ..position
+            },
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Token {
     pub token_type: TokenType,
     pub literal: String,
     pub lexeme: String,
+    pub span: Span,
+    pub position: Position,
 }
 
 impl Token {
     pub fn new(token_type: TokenType, literal: &String, lexeme: &String) -> Token {
         Token {
-            token_type: token_type,
+            token_type,
             literal: literal.to_owned(),
             lexeme: lexeme.to_owned(),
+            span: Span::default(),
+            position: Position::default(),
         }
     }
 }
 
+// Tokens are compared by content alone, where they were found in
+// the query doesn't change what they mean
+impl PartialEq for Token {
+    fn eq(&self, other: &Token) -> bool {
+        self.token_type == other.token_type
+            && self.literal == other.literal
+            && self.lexeme == other.lexeme
+    }
+}
+
+impl Spanned for Token {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
 impl fmt::Display for Token {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -75,17 +180,81 @@ impl fmt::Display for Token {
 }
 
 // These are for finding unique cases of tokens
-pub const SINGLE_START_TOKENS: &[char] = &['(', ')', '!', '.', ';', ','];
+pub const SINGLE_START_TOKENS: &[char] = &['(', ')', '!', '.', ';', ',', '+', '*', '/'];
 
 pub const SINGLE_DOUBLE_START_TOKENS: &[char] = &['<', '>', '='];
 
+/// Comparisons written out in English, the lexer joins the words of
+/// each into a single token of the comparison it means. Where one
+/// phrase starts another (i.e. `is greater than or equal to`) the
+/// longest one wins.
+pub const COMPARISON_PHRASES: &[(&str, TokenType)] = &[
+    ("is greater than or equal to", TokenType::Gte),
+    ("greater than or equal to", TokenType::Gte),
+    ("is at least", TokenType::Gte),
+    ("is no less than", TokenType::Gte),
+    ("is no fewer than", TokenType::Gte),
+    ("is not less than", TokenType::Gte),
+    ("is not under", TokenType::Gte),
+    ("is not below", TokenType::Gte),
+    ("is less than or equal to", TokenType::Lte),
+    ("less than or equal to", TokenType::Lte),
+    ("is at most", TokenType::Lte),
+    ("is no more than", TokenType::Lte),
+    ("is not more than", TokenType::Lte),
+    ("is not greater than", TokenType::Lte),
+    ("is not over", TokenType::Lte),
+    ("is not above", TokenType::Lte),
+    ("is greater than", TokenType::Gt),
+    ("greater than", TokenType::Gt),
+    ("is more than", TokenType::Gt),
+    ("more than", TokenType::Gt),
+    ("is over", TokenType::Gt),
+    ("is above", TokenType::Gt),
+    ("is less than", TokenType::Lt),
+    ("less than", TokenType::Lt),
+    ("is fewer than", TokenType::Lt),
+    ("fewer than", TokenType::Lt),
+    ("is under", TokenType::Lt),
+    ("is below", TokenType::Lt),
+    ("is equal to", TokenType::Equal),
+    ("equal to", TokenType::Equal),
+    ("is not equal to", TokenType::NotEqual),
+    ("does not equal", TokenType::NotEqual),
+    ("starts with", TokenType::StartsWith),
+    ("begins with", TokenType::StartsWith),
+    ("ends with", TokenType::EndsWith),
+    ("is like", TokenType::Like),
+    ("is one of", TokenType::OneOf),
+    ("is any of", TokenType::OneOf),
+    ("is not one of", TokenType::NotOneOf),
+    ("is none of", TokenType::NotOneOf),
+    ("is between", TokenType::Between),
+    ("is not between", TokenType::NotBetween),
+    ("not between", TokenType::NotBetween),
+    ("is empty", TokenType::IsEmpty),
+    ("is missing", TokenType::IsEmpty),
+    ("is not empty", TokenType::IsNotEmpty),
+    ("is not missing", TokenType::IsNotEmpty),
+];
+
+/// Other phrases joined into a single token the same way as
+/// comparisons, these change how the comparison before them is made.
+pub const MODIFIER_PHRASES: &[(&str, TokenType)] = &[("ignoring case", TokenType::IgnoreCase)];
+
+/// Arithmetic written out in English, joined the same way as comparisons.
+pub const ARITHMETIC_PHRASES: &[(&str, TokenType)] = &[
+    ("multiplied by", TokenType::Star),
+    ("divided by", TokenType::Slash),
+];
+
 lazy_static! {
     pub static ref IDENTIFER_STOPS: Vec<char> = {
         let mut rv: Vec<char> = vec![];
 
         rv.extend_from_slice(SINGLE_DOUBLE_START_TOKENS);
         rv.extend_from_slice(SINGLE_START_TOKENS);
-        rv.extend_from_slice(&['\"', ' ']);
+        rv.extend_from_slice(&['\"', ' ', '\n', '\t', '\r', '\x0c']);
 
         return rv;
     };
@@ -144,6 +313,21 @@ lazy_static! {
             ("not", TokenType::Not),
 
             ("is", TokenType::Equal),
+            ("equals", TokenType::Equal),
+            ("exceeds", TokenType::Gt),
+
+            ("contains", TokenType::Contains),
+            ("like", TokenType::Like),
+            ("between", TokenType::Between),
+
+            ("plus", TokenType::Plus),
+            ("minus", TokenType::Minus),
+            ("times", TokenType::Star),
+
+            ("true", TokenType::BooleanLiteral),
+            ("false", TokenType::BooleanLiteral),
+            ("null", TokenType::NullLiteral),
+            ("nothing", TokenType::NullLiteral),
 
             ("or", TokenType::Or),
 
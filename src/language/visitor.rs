@@ -0,0 +1,556 @@
+/*
+Visiting the Abstract Syntax Tree
+
+A visitor gets called back for every node of a parsed query, so a new
+backend or lint only has to say what happens at the nodes it cares about
+rather than adding another method to every node. Each `visit_*` method
+defaults to walking into the node's children through the matching
+`walk_*` function, overriding a method and calling the walk function
+from it keeps the visitor descending past that node.
+*/
+
+use crate::language::parser::{
+    arithmetic::{ArithmeticChild, ArithmeticNode, NegateNode},
+    conditional::{
+        BoolNode, ComparisonNode, ConditionChild, ConditionNode, ExpressionNode, ListNode, NotNode,
+        OperandNode, RangeNode,
+    },
+    database::{CreateNode, DatabaseNode, DatabaseOperation, DestroyNode, ShowNode, UseNode},
+    get::{ColumnNode, FilterNode, GetNode, TableNode},
+    parser::{Query, Statement},
+    postprocessor::{LimitNode, PostProcessorNode, SortKeyNode, SortNode},
+};
+
+/// Walks a borrowed tree, `'ast` being how long the tree is borrowed
+/// for so visitors can hold on to the nodes they collect.
+///
+/// # Example
+/// ```
+/// use eaql::language::ast::{parse, ExpressionNode};
+/// use eaql::language::visitor::Visitor;
+///
+/// struct Columns<'ast>(Vec<&'ast str>);
+///
+/// impl<'ast> Visitor<'ast> for Columns<'ast> {
+///     fn visit_expression(&mut self, node: &'ast ExpressionNode) {
+///         self.0.push(node.column());
+///     }
+/// }
+///
+/// let query = parse("get all from drinks where price < 5 or size is \"large\";").unwrap();
+/// let mut columns = Columns(vec![]);
+///
+/// columns.visit_query(&query);
+/// assert_eq!(columns.0, ["price", "size"]);
+/// ```
+///
+pub trait Visitor<'ast> {
+    fn visit_query(&mut self, node: &'ast Query) {
+        walk_query(self, node);
+    }
+
+    fn visit_statement(&mut self, node: &'ast Statement) {
+        walk_statement(self, node);
+    }
+
+    fn visit_get(&mut self, node: &'ast GetNode) {
+        walk_get(self, node);
+    }
+
+    fn visit_column(&mut self, _node: &'ast ColumnNode) {}
+
+    fn visit_table(&mut self, _node: &'ast TableNode) {}
+
+    fn visit_filter(&mut self, node: &'ast FilterNode) {
+        walk_filter(self, node);
+    }
+
+    fn visit_condition(&mut self, node: &'ast ConditionNode) {
+        walk_condition(self, node);
+    }
+
+    fn visit_condition_child(&mut self, node: &'ast ConditionChild) {
+        walk_condition_child(self, node);
+    }
+
+    fn visit_operand(&mut self, node: &'ast OperandNode) {
+        walk_operand(self, node);
+    }
+
+    fn visit_not(&mut self, node: &'ast NotNode) {
+        walk_not(self, node);
+    }
+
+    fn visit_expression(&mut self, _node: &'ast ExpressionNode) {}
+
+    fn visit_list(&mut self, _node: &'ast ListNode) {}
+
+    fn visit_range(&mut self, _node: &'ast RangeNode) {}
+
+    fn visit_comparison(&mut self, node: &'ast ComparisonNode) {
+        walk_comparison(self, node);
+    }
+
+    fn visit_arithmetic(&mut self, node: &'ast ArithmeticChild) {
+        walk_arithmetic(self, node);
+    }
+
+    fn visit_arithmetic_op(&mut self, node: &'ast ArithmeticNode) {
+        walk_arithmetic_op(self, node);
+    }
+
+    fn visit_negate(&mut self, node: &'ast NegateNode) {
+        walk_negate(self, node);
+    }
+
+    fn visit_bool(&mut self, _node: &'ast BoolNode) {}
+
+    fn visit_postprocessor(&mut self, node: &'ast PostProcessorNode) {
+        walk_postprocessor(self, node);
+    }
+
+    fn visit_sort(&mut self, node: &'ast SortNode) {
+        walk_sort(self, node);
+    }
+
+    fn visit_sort_key(&mut self, _node: &'ast SortKeyNode) {}
+
+    fn visit_limit(&mut self, _node: &'ast LimitNode) {}
+
+    fn visit_database(&mut self, node: &'ast DatabaseNode) {
+        walk_database(self, node);
+    }
+
+    fn visit_create(&mut self, _node: &'ast CreateNode) {}
+
+    fn visit_destroy(&mut self, _node: &'ast DestroyNode) {}
+
+    fn visit_use(&mut self, _node: &'ast UseNode) {}
+
+    fn visit_show(&mut self, _node: &'ast ShowNode) {}
+}
+
+pub fn walk_query<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Query) {
+    visitor.visit_statement(node.statement());
+}
+
+pub fn walk_statement<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Statement) {
+    match node {
+        Statement::Get(get) => visitor.visit_get(get),
+        Statement::Database(database) => visitor.visit_database(database),
+    }
+}
+
+/// Visits the clauses of a get in the order SQL lists them.
+pub fn walk_get<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast GetNode) {
+    visitor.visit_column(node.columns());
+    visitor.visit_table(node.table());
+
+    if let Some(filter) = node.filter() {
+        visitor.visit_filter(filter);
+    }
+
+    if let Some(postprocessor) = node.postprocessor() {
+        visitor.visit_postprocessor(postprocessor);
+    }
+}
+
+pub fn walk_filter<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast FilterNode) {
+    visitor.visit_condition(node.condition());
+}
+
+pub fn walk_condition<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast ConditionNode) {
+    visitor.visit_condition_child(node.condition());
+}
+
+pub fn walk_condition_child<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ConditionChild,
+) {
+    match node {
+        ConditionChild::Op(op) => visitor.visit_operand(op),
+        ConditionChild::Expr(expr) => visitor.visit_expression(expr),
+        ConditionChild::Bool(b) => visitor.visit_bool(b),
+        ConditionChild::Not(not) => visitor.visit_not(not),
+        ConditionChild::List(list) => visitor.visit_list(list),
+        ConditionChild::Range(range) => visitor.visit_range(range),
+        ConditionChild::Compare(compare) => visitor.visit_comparison(compare),
+    }
+}
+
+pub fn walk_operand<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast OperandNode) {
+    visitor.visit_condition_child(node.left());
+    visitor.visit_condition_child(node.right());
+}
+
+pub fn walk_not<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast NotNode) {
+    visitor.visit_condition_child(node.child());
+}
+
+pub fn walk_comparison<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ComparisonNode,
+) {
+    visitor.visit_arithmetic(node.left());
+    visitor.visit_arithmetic(node.right());
+}
+
+/// Columns and values are where arithmetic bottoms out, so only
+/// operators and negations have anything further to visit.
+pub fn walk_arithmetic<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ArithmeticChild,
+) {
+    match node {
+        ArithmeticChild::Op(op) => visitor.visit_arithmetic_op(op),
+        ArithmeticChild::Negate(negate) => visitor.visit_negate(negate),
+        ArithmeticChild::Column(_) | ArithmeticChild::Value(_) => {}
+    }
+}
+
+pub fn walk_arithmetic_op<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ArithmeticNode,
+) {
+    visitor.visit_arithmetic(node.left());
+    visitor.visit_arithmetic(node.right());
+}
+
+pub fn walk_negate<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast NegateNode) {
+    visitor.visit_arithmetic(node.child());
+}
+
+/// Visits sorting before limiting, the order SQL applies them in
+/// regardless of the order they were listed in.
+pub fn walk_postprocessor<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast PostProcessorNode,
+) {
+    if let Some(sort) = node.sort() {
+        visitor.visit_sort(sort);
+    }
+
+    if let Some(limit) = node.limit() {
+        visitor.visit_limit(limit);
+    }
+}
+
+pub fn walk_sort<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast SortNode) {
+    for key in node.keys() {
+        visitor.visit_sort_key(key);
+    }
+}
+
+pub fn walk_database<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast DatabaseNode) {
+    match node.operation() {
+        Some(DatabaseOperation::Create(create)) => visitor.visit_create(create),
+        Some(DatabaseOperation::Destroy(destroy)) => visitor.visit_destroy(destroy),
+        Some(DatabaseOperation::Use(node)) => visitor.visit_use(node),
+        Some(DatabaseOperation::Show(show)) => visitor.visit_show(show),
+        None => {}
+    }
+}
+
+/// Walks a mutably borrowed tree so visitors can rewrite it in place.
+///
+/// # Example
+/// ```
+/// use eaql::language::ast::{parse, TableNode};
+/// use eaql::language::visitor::VisitorMut;
+/// use eaql::transpiler::dialect::Generic;
+///
+/// struct Rename;
+///
+/// impl VisitorMut for Rename {
+///     fn visit_table_mut(&mut self, node: &mut TableNode) {
+///         node.set_name("beverages");
+///     }
+/// }
+///
+/// let mut query = parse("get name from drinks;").unwrap();
+///
+/// Rename.visit_query_mut(&mut query);
+/// assert_eq!(query.transpile_raw(&Generic).unwrap(), "SELECT name FROM beverages");
+/// ```
+///
+pub trait VisitorMut {
+    fn visit_query_mut(&mut self, node: &mut Query) {
+        walk_query_mut(self, node);
+    }
+
+    fn visit_statement_mut(&mut self, node: &mut Statement) {
+        walk_statement_mut(self, node);
+    }
+
+    fn visit_get_mut(&mut self, node: &mut GetNode) {
+        walk_get_mut(self, node);
+    }
+
+    fn visit_column_mut(&mut self, _node: &mut ColumnNode) {}
+
+    fn visit_table_mut(&mut self, _node: &mut TableNode) {}
+
+    fn visit_filter_mut(&mut self, node: &mut FilterNode) {
+        walk_filter_mut(self, node);
+    }
+
+    fn visit_condition_mut(&mut self, node: &mut ConditionNode) {
+        walk_condition_mut(self, node);
+    }
+
+    fn visit_condition_child_mut(&mut self, node: &mut ConditionChild) {
+        walk_condition_child_mut(self, node);
+    }
+
+    fn visit_operand_mut(&mut self, node: &mut OperandNode) {
+        walk_operand_mut(self, node);
+    }
+
+    fn visit_not_mut(&mut self, node: &mut NotNode) {
+        walk_not_mut(self, node);
+    }
+
+    fn visit_expression_mut(&mut self, _node: &mut ExpressionNode) {}
+
+    fn visit_list_mut(&mut self, _node: &mut ListNode) {}
+
+    fn visit_range_mut(&mut self, _node: &mut RangeNode) {}
+
+    fn visit_comparison_mut(&mut self, node: &mut ComparisonNode) {
+        walk_comparison_mut(self, node);
+    }
+
+    fn visit_arithmetic_mut(&mut self, node: &mut ArithmeticChild) {
+        walk_arithmetic_mut(self, node);
+    }
+
+    fn visit_arithmetic_op_mut(&mut self, node: &mut ArithmeticNode) {
+        walk_arithmetic_op_mut(self, node);
+    }
+
+    fn visit_negate_mut(&mut self, node: &mut NegateNode) {
+        walk_negate_mut(self, node);
+    }
+
+    fn visit_bool_mut(&mut self, _node: &mut BoolNode) {}
+
+    fn visit_postprocessor_mut(&mut self, node: &mut PostProcessorNode) {
+        walk_postprocessor_mut(self, node);
+    }
+
+    fn visit_sort_mut(&mut self, node: &mut SortNode) {
+        walk_sort_mut(self, node);
+    }
+
+    fn visit_sort_key_mut(&mut self, _node: &mut SortKeyNode) {}
+
+    fn visit_limit_mut(&mut self, _node: &mut LimitNode) {}
+
+    fn visit_database_mut(&mut self, node: &mut DatabaseNode) {
+        walk_database_mut(self, node);
+    }
+
+    fn visit_create_mut(&mut self, _node: &mut CreateNode) {}
+
+    fn visit_destroy_mut(&mut self, _node: &mut DestroyNode) {}
+
+    fn visit_use_mut(&mut self, _node: &mut UseNode) {}
+
+    fn visit_show_mut(&mut self, _node: &mut ShowNode) {}
+}
+
+pub fn walk_query_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Query) {
+    visitor.visit_statement_mut(node.statement_mut());
+}
+
+pub fn walk_statement_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Statement) {
+    match node {
+        Statement::Get(get) => visitor.visit_get_mut(get),
+        Statement::Database(database) => visitor.visit_database_mut(database),
+    }
+}
+
+pub fn walk_get_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut GetNode) {
+    visitor.visit_column_mut(node.columns_mut());
+    visitor.visit_table_mut(node.table_mut());
+
+    if let Some(filter) = node.filter_mut() {
+        visitor.visit_filter_mut(filter);
+    }
+
+    if let Some(postprocessor) = node.postprocessor_mut() {
+        visitor.visit_postprocessor_mut(postprocessor);
+    }
+}
+
+pub fn walk_filter_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut FilterNode) {
+    visitor.visit_condition_mut(node.condition_mut());
+}
+
+pub fn walk_condition_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut ConditionNode) {
+    visitor.visit_condition_child_mut(node.condition_mut());
+}
+
+pub fn walk_condition_child_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ConditionChild,
+) {
+    match node {
+        ConditionChild::Op(op) => visitor.visit_operand_mut(op),
+        ConditionChild::Expr(expr) => visitor.visit_expression_mut(expr),
+        ConditionChild::Bool(b) => visitor.visit_bool_mut(b),
+        ConditionChild::Not(not) => visitor.visit_not_mut(not),
+        ConditionChild::List(list) => visitor.visit_list_mut(list),
+        ConditionChild::Range(range) => visitor.visit_range_mut(range),
+        ConditionChild::Compare(compare) => visitor.visit_comparison_mut(compare),
+    }
+}
+
+pub fn walk_operand_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut OperandNode) {
+    visitor.visit_condition_child_mut(node.left_mut());
+    visitor.visit_condition_child_mut(node.right_mut());
+}
+
+pub fn walk_not_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut NotNode) {
+    visitor.visit_condition_child_mut(node.child_mut());
+}
+
+pub fn walk_comparison_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut ComparisonNode) {
+    visitor.visit_arithmetic_mut(node.left_mut());
+    visitor.visit_arithmetic_mut(node.right_mut());
+}
+
+pub fn walk_arithmetic_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut ArithmeticChild) {
+    match node {
+        ArithmeticChild::Op(op) => visitor.visit_arithmetic_op_mut(op),
+        ArithmeticChild::Negate(negate) => visitor.visit_negate_mut(negate),
+        ArithmeticChild::Column(_) | ArithmeticChild::Value(_) => {}
+    }
+}
+
+pub fn walk_arithmetic_op_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut ArithmeticNode) {
+    visitor.visit_arithmetic_mut(node.left_mut());
+    visitor.visit_arithmetic_mut(node.right_mut());
+}
+
+pub fn walk_negate_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut NegateNode) {
+    visitor.visit_arithmetic_mut(node.child_mut());
+}
+
+pub fn walk_postprocessor_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut PostProcessorNode,
+) {
+    if let Some(sort) = node.sort_mut() {
+        visitor.visit_sort_mut(sort);
+    }
+
+    if let Some(limit) = node.limit_mut() {
+        visitor.visit_limit_mut(limit);
+    }
+}
+
+pub fn walk_sort_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut SortNode) {
+    for key in node.keys_mut() {
+        visitor.visit_sort_key_mut(key);
+    }
+}
+
+pub fn walk_database_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut DatabaseNode) {
+    if let Some(create) = &mut node._create {
+        visitor.visit_create_mut(create);
+    } else if let Some(destroy) = &mut node._destroy {
+        visitor.visit_destroy_mut(destroy);
+    } else if let Some(show) = &mut node._show {
+        visitor.visit_show_mut(show);
+    } else if let Some(node) = &mut node._use {
+        visitor.visit_use_mut(node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::ast::parse;
+
+    #[derive(Default)]
+    struct Counter {
+        expressions: usize,
+        arithmetic_columns: usize,
+        sort_keys: usize,
+        limits: usize,
+    }
+
+    impl Visitor<'_> for Counter {
+        fn visit_expression(&mut self, _node: &ExpressionNode) {
+            self.expressions += 1;
+        }
+
+        fn visit_arithmetic(&mut self, node: &ArithmeticChild) {
+            if let ArithmeticChild::Column(_) = node {
+                self.arithmetic_columns += 1;
+            }
+
+            walk_arithmetic(self, node);
+        }
+
+        fn visit_sort_key(&mut self, _node: &SortKeyNode) {
+            self.sort_keys += 1;
+        }
+
+        fn visit_limit(&mut self, _node: &LimitNode) {
+            self.limits += 1;
+        }
+    }
+
+    struct Limiter;
+
+    impl VisitorMut for Limiter {
+        fn visit_limit_mut(&mut self, node: &mut LimitNode) {
+            node.set_value(node.value().max(1));
+        }
+    }
+
+    #[test]
+    fn unit_test_visitor_walks_every_node() {
+        let query: Query = parse(
+            "get all from drinks where not (price < 5 or size is \"large\") and name = \"tea\" \
+then sort by name, price descending and limit it to 3;",
+        )
+        .unwrap();
+        let mut counter: Counter = Counter::default();
+
+        counter.visit_query(&query);
+
+        assert_eq!(counter.expressions, 3);
+        assert_eq!(counter.sort_keys, 2);
+        assert_eq!(counter.limits, 1);
+    }
+
+    #[test]
+    fn unit_test_visitor_walks_arithmetic() {
+        let query: Query =
+            parse("get all from drinks where price * (quantity - 1) > -discount and size = 2;")
+                .unwrap();
+        let mut counter: Counter = Counter::default();
+
+        counter.visit_query(&query);
+
+        assert_eq!(counter.expressions, 1);
+        assert_eq!(counter.arithmetic_columns, 3);
+    }
+
+    #[test]
+    fn unit_test_visitor_mut_rewrites() {
+        let mut query: Query = parse("get name from drinks then limit it to 0;").unwrap();
+
+        Limiter.visit_query_mut(&mut query);
+
+        let Statement::Get(get) = query.statement() else {
+            panic!("Expected a get statement");
+        };
+
+        assert_eq!(get.limit().map(|v| v.value()), Some(1));
+        assert!(query.warnings().is_empty());
+    }
+}
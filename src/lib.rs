@@ -1,4 +1,5 @@
 // This is the main interface to the interworkings of the EAQL language
+pub mod formatter;
 pub mod language;
 pub mod transpiler;
 pub mod utils;
@@ -0,0 +1,533 @@
+/*
+SQL dialects the transpiler can target
+
+Every database speaks a slightly different flavour of SQL, so anything
+that differs between them is routed through the Dialect trait rather
+than being hard-coded into the AST nodes.
+*/
+
+use crate::language::errors::{EaqlError, ErrorContext};
+
+/// How a dialect limits the number of rows returned.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum LimitStyle {
+    /// `SELECT ... LIMIT n`
+    Limit,
+    /// `SELECT TOP n ...`
+    Top,
+    /// `SELECT ... FETCH FIRST n ROWS ONLY`
+    FetchFirst,
+}
+
+/// Words reserved by at least one of the supported dialects, identifiers
+/// matching any of them are always quoted.
+pub const RESERVED_WORDS: &[&str] = &[
+    "add",
+    "all",
+    "alter",
+    "and",
+    "any",
+    "as",
+    "asc",
+    "between",
+    "by",
+    "case",
+    "check",
+    "column",
+    "constraint",
+    "create",
+    "cross",
+    "current",
+    "database",
+    "default",
+    "delete",
+    "desc",
+    "distinct",
+    "drop",
+    "else",
+    "end",
+    "except",
+    "exists",
+    "false",
+    "fetch",
+    "for",
+    "foreign",
+    "from",
+    "full",
+    "grant",
+    "group",
+    "having",
+    "in",
+    "index",
+    "inner",
+    "insert",
+    "intersect",
+    "into",
+    "is",
+    "join",
+    "key",
+    "left",
+    "like",
+    "limit",
+    "natural",
+    "not",
+    "null",
+    "offset",
+    "on",
+    "or",
+    "order",
+    "outer",
+    "primary",
+    "references",
+    "right",
+    "rows",
+    "schema",
+    "select",
+    "set",
+    "table",
+    "then",
+    "to",
+    "top",
+    "true",
+    "union",
+    "unique",
+    "update",
+    "use",
+    "user",
+    "using",
+    "values",
+    "when",
+    "where",
+    "with",
+];
+
+/// Errors on characters that can't be represented safely in a query
+/// (i.e. newlines or null bytes smuggled into a value).
+fn reject_control_characters(kind: &str, value: &str) -> Result<(), EaqlError> {
+    match value.chars().find(|c| c.is_control()) {
+        Some(c) => Err(EaqlError::InvalidValue(Box::new(ErrorContext::new(
+            format!(
+                "{} {:?} contains the control character {:?}, which can't be safely transpiled.",
+                kind, value, c
+            ),
+        )))),
+        None => Ok(()),
+    }
+}
+
+/// Whether the text is a number as the lexer reads them, digits with
+/// an optional minus in front and decimals after a `.`.
+pub fn is_number(value: &str) -> bool {
+    let unsigned: &str = value.strip_prefix('-').unwrap_or(value);
+
+    unsigned
+        .splitn(2, '.')
+        .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Whether an identifier has to be quoted to be read back as a
+/// single identifier, rather than a keyword or several tokens.
+pub fn needs_quoting(identifier: &str) -> bool {
+    let mut chars = identifier.chars();
+    let plain: bool = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    !plain || RESERVED_WORDS.contains(&identifier.to_lowercase().as_str())
+}
+
+/// How a dialect writes the placeholders of bound parameters.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PlaceholderStyle {
+    /// `price = ?`
+    QuestionMark,
+    /// `price = $1`
+    Numbered,
+    /// `price = :p1`
+    Named,
+}
+
+pub trait Dialect {
+    /// Name used to select the dialect (i.e. from the REPL).
+    fn name(&self) -> &'static str;
+
+    /// Opening and closing characters used to quote identifiers.
+    fn identifier_quotes(&self) -> (char, char) {
+        ('"', '"')
+    }
+
+    /// Quotes a table, column or database name if it needs it, any
+    /// closing quote inside the name is escaped by doubling it.
+    fn quote_identifier(&self, identifier: &str) -> Result<String, EaqlError> {
+        reject_control_characters("Identifier", identifier)?;
+
+        if !needs_quoting(identifier) {
+            return Ok(identifier.to_string());
+        }
+
+        let (open, close) = self.identifier_quotes();
+
+        Ok(format!(
+            "{}{}{}",
+            open,
+            identifier.replace(close, &format!("{}{}", close, close)),
+            close
+        ))
+    }
+
+    /// Wraps a string value in single quotes, doubling any single
+    /// quotes inside it.
+    fn quote_string(&self, value: &str) -> Result<String, EaqlError> {
+        reject_control_characters("String literal", value)?;
+
+        Ok(format!("'{}'", value.replace('\'', "''")))
+    }
+
+    /// Escapes the `%` and `_` wildcards of a `LIKE` pattern (and the
+    /// escape character itself) so they're matched as written.
+    fn escape_pattern(&self, text: &str) -> String {
+        text.chars().fold(String::new(), |mut escaped, c| {
+            if matches!(c, '%' | '_' | '\\') {
+                escaped.push('\\');
+            }
+
+            escaped.push(c);
+            escaped
+        })
+    }
+
+    /// Whether `LIKE` needs to be told `\` escapes wildcards with an
+    /// `ESCAPE` clause, only added to patterns with escapes in them.
+    fn needs_escape_clause(&self) -> bool {
+        true
+    }
+
+    /// Whether the dialect has `ILIKE`, otherwise matching ignoring
+    /// case lowercases both sides of `LIKE`.
+    fn supports_ilike(&self) -> bool {
+        false
+    }
+
+    /// Outputs a number literal, checking it's written the way the lexer
+    /// reads numbers since a tree can be built without the lexer.
+    fn format_number(&self, value: &str) -> Result<String, EaqlError> {
+        if !is_number(value) {
+            return Err(EaqlError::InvalidValue(Box::new(ErrorContext::new(
+                format!("\"{}\" isn't a valid number.", value),
+            ))));
+        }
+
+        Ok(value.to_string())
+    }
+
+    /// Outputs `true` or `false` as the dialect writes them.
+    fn format_bool(&self, value: bool) -> String {
+        match value {
+            true => "TRUE".to_string(),
+            false => "FALSE".to_string(),
+        }
+    }
+
+    /// Outputs the number of rows a query is limited to.
+    fn format_limit(&self, limit: i32) -> String {
+        limit.to_string()
+    }
+
+    fn limit_style(&self) -> LimitStyle {
+        LimitStyle::Limit
+    }
+
+    fn placeholder_style(&self) -> PlaceholderStyle {
+        PlaceholderStyle::QuestionMark
+    }
+
+    fn create_database(&self, name: &str) -> Result<String, EaqlError> {
+        Ok(format!("CREATE DATABASE {}", self.quote_identifier(name)?))
+    }
+
+    fn drop_databases(&self, names: &[String]) -> Result<String, EaqlError> {
+        // Most dialects can only drop a single database per statement
+        Ok(names
+            .iter()
+            .map(|name| Ok(format!("DROP DATABASE {}", self.quote_identifier(name)?)))
+            .collect::<Result<Vec<String>, EaqlError>>()?
+            .join("; "))
+    }
+
+    fn use_database(&self, name: &str) -> Result<String, EaqlError> {
+        Ok(format!("USE {}", self.quote_identifier(name)?))
+    }
+
+    fn show_databases(&self) -> Result<String, EaqlError> {
+        Ok("SHOW DATABASES".to_string())
+    }
+}
+
+/// The dialect-agnostic flavour of SQL the transpiler has always
+/// emitted, this is what `transpiler::engine` uses.
+pub struct Generic;
+
+/// Standard SQL, where databases are represented as schemas.
+pub struct Ansi;
+
+pub struct Postgres;
+
+pub struct MySql;
+
+pub struct Sqlite;
+
+pub struct SqlServer;
+
+impl Dialect for Generic {
+    fn name(&self) -> &'static str {
+        "generic"
+    }
+
+    fn drop_databases(&self, names: &[String]) -> Result<String, EaqlError> {
+        Ok(format!(
+            "DROP DATABASE {}",
+            names
+                .iter()
+                .map(|name| self.quote_identifier(name))
+                .collect::<Result<Vec<String>, EaqlError>>()?
+                .join(", ")
+        ))
+    }
+
+    fn use_database(&self, name: &str) -> Result<String, EaqlError> {
+        Ok(format!("USE DATABASE {}", self.quote_identifier(name)?))
+    }
+}
+
+impl Dialect for Ansi {
+    fn name(&self) -> &'static str {
+        "ansi"
+    }
+
+    fn limit_style(&self) -> LimitStyle {
+        LimitStyle::FetchFirst
+    }
+
+    fn create_database(&self, name: &str) -> Result<String, EaqlError> {
+        Ok(format!("CREATE SCHEMA {}", self.quote_identifier(name)?))
+    }
+
+    fn drop_databases(&self, names: &[String]) -> Result<String, EaqlError> {
+        Ok(names
+            .iter()
+            .map(|name| Ok(format!("DROP SCHEMA {}", self.quote_identifier(name)?)))
+            .collect::<Result<Vec<String>, EaqlError>>()?
+            .join("; "))
+    }
+
+    fn use_database(&self, name: &str) -> Result<String, EaqlError> {
+        Ok(format!("SET SCHEMA {}", self.quote_string(name)?))
+    }
+
+    fn show_databases(&self) -> Result<String, EaqlError> {
+        Ok("SELECT schema_name FROM information_schema.schemata".to_string())
+    }
+}
+
+impl Dialect for Postgres {
+    fn name(&self) -> &'static str {
+        "postgres"
+    }
+
+    fn placeholder_style(&self) -> PlaceholderStyle {
+        PlaceholderStyle::Numbered
+    }
+
+    // Backslashes escape wildcards in `LIKE` by default
+    fn needs_escape_clause(&self) -> bool {
+        false
+    }
+
+    fn supports_ilike(&self) -> bool {
+        true
+    }
+
+    fn use_database(&self, name: &str) -> Result<String, EaqlError> {
+        Err(EaqlError::Unsupported(Box::new(ErrorContext::new(
+            format!(
+                "PostgreSQL can't switch databases from a query, reconnect to \"{}\" instead.",
+                name
+            ),
+        ))))
+    }
+
+    fn show_databases(&self) -> Result<String, EaqlError> {
+        Ok("SELECT datname FROM pg_database WHERE NOT datistemplate".to_string())
+    }
+}
+
+impl Dialect for MySql {
+    fn name(&self) -> &'static str {
+        "mysql"
+    }
+
+    fn identifier_quotes(&self) -> (char, char) {
+        ('`', '`')
+    }
+
+    // Backslashes are escape characters in MySQL strings by default
+    fn quote_string(&self, value: &str) -> Result<String, EaqlError> {
+        reject_control_characters("String literal", value)?;
+
+        Ok(format!(
+            "'{}'",
+            value.replace('\\', "\\\\").replace('\'', "''")
+        ))
+    }
+
+    fn needs_escape_clause(&self) -> bool {
+        false
+    }
+}
+
+impl Dialect for Sqlite {
+    fn name(&self) -> &'static str {
+        "sqlite"
+    }
+
+    fn placeholder_style(&self) -> PlaceholderStyle {
+        PlaceholderStyle::Named
+    }
+
+    // SQLite databases are files, attaching one creates it if needed
+    fn create_database(&self, name: &str) -> Result<String, EaqlError> {
+        Ok(format!(
+            "ATTACH DATABASE {} AS {}",
+            self.quote_string(&format!("{}.db", name))?,
+            self.quote_identifier(name)?
+        ))
+    }
+
+    fn drop_databases(&self, names: &[String]) -> Result<String, EaqlError> {
+        Ok(names
+            .iter()
+            .map(|name| Ok(format!("DETACH DATABASE {}", self.quote_identifier(name)?)))
+            .collect::<Result<Vec<String>, EaqlError>>()?
+            .join("; "))
+    }
+
+    fn use_database(&self, name: &str) -> Result<String, EaqlError> {
+        Err(EaqlError::Unsupported(Box::new(ErrorContext::new(
+            format!(
+                "SQLite can't switch databases, attach \"{}\" and prefix tables with its name instead.",
+                name
+            ),
+        ))))
+    }
+
+    fn show_databases(&self) -> Result<String, EaqlError> {
+        Ok("PRAGMA database_list".to_string())
+    }
+}
+
+impl Dialect for SqlServer {
+    fn name(&self) -> &'static str {
+        "sqlserver"
+    }
+
+    fn identifier_quotes(&self) -> (char, char) {
+        ('[', ']')
+    }
+
+    // Wildcards are matched as written when wrapped in brackets, which
+    // needs no `ESCAPE` clause
+    fn escape_pattern(&self, text: &str) -> String {
+        text.chars().fold(String::new(), |mut escaped, c| {
+            match c {
+                '%' | '_' | '[' => escaped.push_str(&format!("[{}]", c)),
+                c => escaped.push(c),
+            }
+
+            escaped
+        })
+    }
+
+    fn needs_escape_clause(&self) -> bool {
+        false
+    }
+
+    // There are no boolean literals, bit columns hold 1 or 0 instead
+    fn format_bool(&self, value: bool) -> String {
+        match value {
+            true => "1".to_string(),
+            false => "0".to_string(),
+        }
+    }
+
+    fn limit_style(&self) -> LimitStyle {
+        LimitStyle::Top
+    }
+
+    fn drop_databases(&self, names: &[String]) -> Result<String, EaqlError> {
+        Ok(format!(
+            "DROP DATABASE {}",
+            names
+                .iter()
+                .map(|name| self.quote_identifier(name))
+                .collect::<Result<Vec<String>, EaqlError>>()?
+                .join(", ")
+        ))
+    }
+
+    fn show_databases(&self) -> Result<String, EaqlError> {
+        Ok("SELECT name FROM sys.databases".to_string())
+    }
+}
+
+/// Every dialect the transpiler ships with.
+pub const DIALECTS: &[&dyn Dialect] = &[&Generic, &Ansi, &Postgres, &MySql, &Sqlite, &SqlServer];
+
+/// Looks up a dialect by its name, ignoring case.
+pub fn from_name(name: &str) -> Option<&'static dyn Dialect> {
+    DIALECTS
+        .iter()
+        .find(|dialect| dialect.name().eq_ignore_ascii_case(name))
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_test_dialect_from_name() {
+        assert_eq!(from_name("postgres").map(|v| v.name()), Some("postgres"));
+        assert_eq!(from_name("MySQL").map(|v| v.name()), Some("mysql"));
+        assert!(from_name("oracle").is_none());
+    }
+
+    #[test]
+    fn unit_test_dialect_escape_pattern() {
+        assert_eq!(Generic.escape_pattern("50%_off\\"), "50\\%\\_off\\\\");
+        assert_eq!(SqlServer.escape_pattern("50%_[off]"), "50[%][_][[]off]");
+        assert_eq!(Postgres.escape_pattern("latte"), "latte");
+    }
+
+    #[test]
+    fn unit_test_dialect_format_number() {
+        assert_eq!(Generic.format_number("-12.5"), Ok("-12.5".to_string()));
+        assert!(matches!(
+            Generic.format_number("5; DROP TABLE drinks; --"),
+            Err(EaqlError::InvalidValue(_))
+        ));
+        assert!(Postgres.format_number("1.").is_err());
+        assert!(Postgres.format_number("inf").is_err());
+    }
+
+    #[test]
+    fn unit_test_dialect_names_unique() {
+        for (i, dialect) in DIALECTS.iter().enumerate() {
+            assert!(
+                DIALECTS[i + 1..].iter().all(|v| v.name() != dialect.name()),
+                "Dialect name \"{}\" is used more than once",
+                dialect.name()
+            );
+        }
+    }
+}
@@ -1,2 +1,6 @@
+pub mod dialect;
+pub mod parameters;
+pub mod reverse;
+pub(crate) mod sql;
 pub mod transpiler;
 pub use transpiler::*;
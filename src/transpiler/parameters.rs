@@ -0,0 +1,176 @@
+/*
+Parameterized SQL output
+
+Rather than inlining values into the generated SQL, the Parameterized
+dialect wraps the dialect being targeted and swaps every value for a
+placeholder while recording the value itself, so the SQL can be handed
+to a database driver as a prepared statement.
+*/
+
+use crate::{
+    language::errors::{EaqlError, ErrorContext},
+    transpiler::dialect::{Dialect, LimitStyle, PlaceholderStyle},
+};
+use std::cell::RefCell;
+
+/// A value bound to one of the placeholders of a parameterized query.
+#[derive(Debug, PartialEq, Clone)]
+pub enum BoundValue {
+    Text(String),
+    Integer(i64),
+    Float(f64),
+}
+
+/// Wraps a dialect, replacing string literals, number literals and
+/// limits with placeholders. Values are recorded in the order they
+/// appear in the SQL, which is also the order placeholders are numbered.
+pub struct Parameterized<'a> {
+    dialect: &'a dyn Dialect,
+    values: RefCell<Vec<BoundValue>>,
+}
+
+impl<'a> Parameterized<'a> {
+    pub fn new(dialect: &'a dyn Dialect) -> Parameterized<'a> {
+        Parameterized {
+            dialect,
+            values: RefCell::new(vec![]),
+        }
+    }
+
+    /// Consumes the wrapper, returning the values bound so far.
+    pub fn into_values(self) -> Vec<BoundValue> {
+        self.values.into_inner()
+    }
+
+    /// Records a value and outputs the placeholder standing in for it.
+    fn bind(&self, value: BoundValue) -> String {
+        let mut values = self.values.borrow_mut();
+        values.push(value);
+
+        match self.dialect.placeholder_style() {
+            PlaceholderStyle::QuestionMark => "?".to_string(),
+            PlaceholderStyle::Numbered => format!("${}", values.len()),
+            PlaceholderStyle::Named => format!(":p{}", values.len()),
+        }
+    }
+}
+
+impl Dialect for Parameterized<'_> {
+    fn name(&self) -> &'static str {
+        self.dialect.name()
+    }
+
+    fn identifier_quotes(&self) -> (char, char) {
+        self.dialect.identifier_quotes()
+    }
+
+    fn quote_identifier(&self, identifier: &str) -> Result<String, EaqlError> {
+        self.dialect.quote_identifier(identifier)
+    }
+
+    fn quote_string(&self, value: &str) -> Result<String, EaqlError> {
+        Ok(self.bind(BoundValue::Text(value.to_string())))
+    }
+
+    fn escape_pattern(&self, text: &str) -> String {
+        self.dialect.escape_pattern(text)
+    }
+
+    fn needs_escape_clause(&self) -> bool {
+        self.dialect.needs_escape_clause()
+    }
+
+    fn supports_ilike(&self) -> bool {
+        self.dialect.supports_ilike()
+    }
+
+    fn format_bool(&self, value: bool) -> String {
+        self.dialect.format_bool(value)
+    }
+
+    fn format_number(&self, value: &str) -> Result<String, EaqlError> {
+        let bound: BoundValue = if let Ok(integer) = value.parse::<i64>() {
+            BoundValue::Integer(integer)
+        } else if let Ok(float) = value.parse::<f64>() {
+            BoundValue::Float(float)
+        } else {
+            return Err(EaqlError::InvalidValue(Box::new(ErrorContext::new(
+                format!("\"{}\" isn't a valid number.", value),
+            ))));
+        };
+
+        Ok(self.bind(bound))
+    }
+
+    fn format_limit(&self, limit: i32) -> String {
+        let placeholder: String = self.bind(BoundValue::Integer(limit as i64));
+
+        // `TOP` only accepts an expression when it's parenthesized
+        if self.limit_style() == LimitStyle::Top {
+            format!("({})", placeholder)
+        } else {
+            placeholder
+        }
+    }
+
+    fn limit_style(&self) -> LimitStyle {
+        self.dialect.limit_style()
+    }
+
+    fn placeholder_style(&self) -> PlaceholderStyle {
+        self.dialect.placeholder_style()
+    }
+
+    fn create_database(&self, name: &str) -> Result<String, EaqlError> {
+        self.dialect.create_database(name)
+    }
+
+    fn drop_databases(&self, names: &[String]) -> Result<String, EaqlError> {
+        self.dialect.drop_databases(names)
+    }
+
+    fn use_database(&self, name: &str) -> Result<String, EaqlError> {
+        self.dialect.use_database(name)
+    }
+
+    fn show_databases(&self) -> Result<String, EaqlError> {
+        self.dialect.show_databases()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transpiler::dialect::{Generic, Postgres, Sqlite};
+
+    #[test]
+    fn unit_test_parameterized_placeholders() {
+        let generic = Parameterized::new(&Generic);
+        let postgres = Parameterized::new(&Postgres);
+        let sqlite = Parameterized::new(&Sqlite);
+
+        for dialect in [&generic, &postgres, &sqlite] {
+            assert!(dialect.quote_string("latte").is_ok());
+            assert!(dialect.format_number("-2.5").is_ok());
+            dialect.format_limit(5);
+        }
+
+        assert_eq!(generic.quote_string("x"), Ok("?".to_string()));
+        assert_eq!(postgres.quote_string("x"), Ok("$4".to_string()));
+        assert_eq!(sqlite.quote_string("x"), Ok(":p4".to_string()));
+        assert_eq!(
+            generic.into_values(),
+            vec![
+                BoundValue::Text("latte".to_string()),
+                BoundValue::Float(-2.5),
+                BoundValue::Integer(5),
+                BoundValue::Text("x".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn unit_test_parameterized_number_error() {
+        assert!(Parameterized::new(&Generic).format_number("2.5.1").is_err());
+    }
+}
@@ -0,0 +1,997 @@
+/*
+Reverse Transpiling
+
+Reads back the SQL the transpiler emits and turns it into the EAQL query
+it could have come from, so learners can see the EAQL for SQL they
+already know. Only that subset is understood:
+
+SELECT [TOP n] columns FROM table [WHERE ...] [ORDER BY ...] [LIMIT n]
+CREATE DATABASE name, DROP DATABASE names, USE name, SHOW DATABASES
+
+along with the forms each dialect uses in their place. Queries are put
+together with the query builder, so anything EAQL can't express (i.e.
+a column named `where`) is an error rather than a broken query.
+*/
+
+use crate::{
+    formatter::Style,
+    language::{
+        builder::{Condition, Expr, GetBuilder, Literal},
+        diagnostics,
+        errors::{EaqlError, ErrorContext},
+        parser::{
+            conditional::{Case, ComparisonOperator},
+            parser::Query,
+            postprocessor::SortDirection,
+        },
+    },
+    transpiler::dialect::{self, DIALECTS, Dialect, Generic},
+    utils::{
+        colors::{AnsiColor, colorize},
+        io::{self, Format},
+        logger,
+    },
+};
+use std::{iter::Peekable, str::CharIndices};
+
+#[derive(Debug, PartialEq, Clone)]
+enum SqlToken {
+    /// Keywords and bare identifiers.
+    Word(String),
+    /// Identifiers wrapped in the dialect's quotes.
+    Quoted(String),
+    Text(String),
+    Number(String),
+    Symbol(&'static str),
+}
+
+impl SqlToken {
+    /// Whether the token is the given keyword, ignoring case.
+    fn is(&self, keyword: &str) -> bool {
+        matches!(self, SqlToken::Word(word) if word.eq_ignore_ascii_case(keyword))
+    }
+
+    /// Same token, ignoring the case of keywords.
+    fn same(&self, other: &SqlToken) -> bool {
+        match (self, other) {
+            (SqlToken::Word(word), other) => other.is(word),
+            _ => self == other,
+        }
+    }
+}
+
+fn unexpected(message: String, hint: &str) -> EaqlError {
+    EaqlError::UnexpectedToken(Box::new(ErrorContext::new(message))).with_hint(hint)
+}
+
+/// Reads up to the closing character, where a doubled closing
+/// character (or one after a backslash, if allowed) stands for itself.
+fn read_quoted(
+    chars: &mut Peekable<CharIndices>,
+    close: char,
+    backslash: bool,
+) -> Result<String, EaqlError> {
+    let mut value: String = String::new();
+
+    while let Some((_, c)) = chars.next() {
+        match c {
+            '\\' if backslash => match chars.next() {
+                Some((_, escaped)) => value.push(escaped),
+                None => break,
+            },
+            c if c == close => match chars.peek() {
+                Some((_, next)) if *next == close => {
+                    chars.next();
+                    value.push(close);
+                }
+                _ => return Ok(value),
+            },
+            c => value.push(c),
+        }
+    }
+
+    Err(EaqlError::UnknownToken(Box::new(ErrorContext::new(
+        format!(
+            "SQL has an unterminated quote, expected a closing `{}`",
+            close
+        ),
+    ))))
+}
+
+fn tokenize(sql: &str, dialect: &dyn Dialect) -> Result<Vec<SqlToken>, EaqlError> {
+    let mut tokens: Vec<SqlToken> = vec![];
+    let mut chars: Peekable<CharIndices> = sql.char_indices().peekable();
+    let (open, close): (char, char) = dialect.identifier_quotes();
+
+    // Dialects escaping backslashes in strings read them back the same way
+    let backslash: bool = dialect.quote_string("\\").is_ok_and(|v| v == "'\\\\'");
+
+    while let Some((start, c)) = chars.next() {
+        let token: SqlToken = match c {
+            c if c.is_whitespace() => continue,
+            c if c.is_alphabetic() || c == '_' => {
+                let mut end: usize = start + c.len_utf8();
+
+                while let Some((i, c)) = chars.next_if(|(_, c)| c.is_alphanumeric() || *c == '_') {
+                    end = i + c.len_utf8();
+                }
+
+                SqlToken::Word(sql[start..end].to_string())
+            }
+            c if c.is_ascii_digit()
+                || (c == '-' && chars.peek().is_some_and(|(_, c)| c.is_ascii_digit())) =>
+            {
+                let mut end: usize = start + 1;
+
+                while let Some((i, _)) = chars.next_if(|(_, c)| c.is_ascii_digit() || *c == '.') {
+                    end = i + 1;
+                }
+
+                SqlToken::Number(sql[start..end].to_string())
+            }
+            '\'' => SqlToken::Text(read_quoted(&mut chars, '\'', backslash)?),
+            c if c == open => SqlToken::Quoted(read_quoted(&mut chars, close, false)?),
+            '<' if chars.next_if(|(_, c)| *c == '=').is_some() => SqlToken::Symbol("<="),
+            '<' if chars.next_if(|(_, c)| *c == '>').is_some() => SqlToken::Symbol("<>"),
+            '>' if chars.next_if(|(_, c)| *c == '=').is_some() => SqlToken::Symbol(">="),
+            '!' if chars.next_if(|(_, c)| *c == '=').is_some() => SqlToken::Symbol("<>"),
+            '<' => SqlToken::Symbol("<"),
+            '>' => SqlToken::Symbol(">"),
+            '=' => SqlToken::Symbol("="),
+            '*' => SqlToken::Symbol("*"),
+            '+' => SqlToken::Symbol("+"),
+            // Two in a row would start a comment, which is never output
+            '-' if chars.peek().is_none_or(|(_, c)| *c != '-') => SqlToken::Symbol("-"),
+            '/' if chars.peek().is_none_or(|(_, c)| *c != '*') => SqlToken::Symbol("/"),
+            ',' => SqlToken::Symbol(","),
+            '(' => SqlToken::Symbol("("),
+            ')' => SqlToken::Symbol(")"),
+            '.' => SqlToken::Symbol("."),
+            ';' => SqlToken::Symbol(";"),
+            c => {
+                return Err(EaqlError::UnknownToken(Box::new(ErrorContext::new(
+                    format!("Couldn't make sense of \"{}\" in the SQL!", c),
+                ))));
+            }
+        };
+
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+/// A character of a `LIKE` pattern, either a wildcard or one that's
+/// matched as written.
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum PatternPart {
+    Wildcard(char),
+    Char(char),
+}
+
+/// Splits a `LIKE` pattern into its parts, undoing the escapes the
+/// dialect wrote wildcards with.
+fn pattern_parts(pattern: &str, escaped: bool, dialect: &dyn Dialect) -> Vec<PatternPart> {
+    let bracketed: bool = dialect.escape_pattern("%") == "[%]";
+    let backslash: bool = escaped || (!dialect.needs_escape_clause() && !bracketed);
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut parts: Vec<PatternPart> = vec![];
+    let mut idx: usize = 0;
+
+    while idx < chars.len() {
+        let part: PatternPart = match chars[idx..] {
+            ['\\', c, ..] if backslash => {
+                idx += 1;
+                PatternPart::Char(c)
+            }
+            ['[', c @ ('%' | '_' | '['), ']', ..] if bracketed => {
+                idx += 2;
+                PatternPart::Char(c)
+            }
+            [c @ ('%' | '_'), ..] => PatternPart::Wildcard(c),
+            [c, ..] => PatternPart::Char(c),
+            [] => break,
+        };
+
+        parts.push(part);
+        idx += 1;
+    }
+
+    parts
+}
+
+/// The EAQL comparison a `LIKE` pattern came from, a value wrapped in
+/// `%` is `contains` and so on, anything else is a `like` pattern.
+fn pattern_comparison(
+    pattern: &str,
+    escaped: bool,
+    case: Case,
+    dialect: &dyn Dialect,
+) -> (ComparisonOperator, String) {
+    let parts: Vec<PatternPart> = pattern_parts(pattern, escaped, dialect);
+    let text = |parts: &[PatternPart]| -> Option<String> {
+        parts
+            .iter()
+            .map(|v| match v {
+                PatternPart::Char(c) => Some(*c),
+                PatternPart::Wildcard(_) => None,
+            })
+            .collect()
+    };
+    let any: PatternPart = PatternPart::Wildcard('%');
+
+    if let [first, middle @ .., last] = parts.as_slice()
+        && *first == any
+        && *last == any
+        && let Some(text) = text(middle)
+    {
+        return (ComparisonOperator::Contains(case), text);
+    }
+
+    if let [rest @ .., last] = parts.as_slice()
+        && *last == any
+        && let Some(text) = text(rest)
+    {
+        return (ComparisonOperator::StartsWith(case), text);
+    }
+
+    if let [first, rest @ ..] = parts.as_slice()
+        && *first == any
+        && let Some(text) = text(rest)
+    {
+        return (ComparisonOperator::EndsWith(case), text);
+    }
+
+    // EAQL patterns escape wildcards meant as written with `\`
+    let pattern: String = parts
+        .iter()
+        .map(|v| match v {
+            PatternPart::Char(c @ ('%' | '_' | '\\')) => format!("\\{}", c),
+            PatternPart::Char(c) | PatternPart::Wildcard(c) => c.to_string(),
+        })
+        .collect();
+
+    (ComparisonOperator::Like(case), pattern)
+}
+
+struct SqlParser<'d> {
+    tokens: Vec<SqlToken>,
+    idx: usize,
+    dialect: &'d dyn Dialect,
+}
+
+impl SqlParser<'_> {
+    fn peek(&self) -> Option<&SqlToken> {
+        self.tokens.get(self.idx)
+    }
+
+    fn next(&mut self, expected: &str) -> Result<SqlToken, EaqlError> {
+        let token: Option<SqlToken> = self.tokens.get(self.idx).cloned();
+        self.idx += 1;
+
+        token.ok_or_else(|| {
+            EaqlError::UnexpectedEnd(Box::new(ErrorContext::new(format!(
+                "SQL ended early, expected {}",
+                expected
+            ))))
+        })
+    }
+
+    /// Consumes the keyword if it's next.
+    fn accept(&mut self, keyword: &str) -> bool {
+        let found: bool = self.peek().is_some_and(|v| v.is(keyword));
+
+        if found {
+            self.idx += 1;
+        }
+
+        found
+    }
+
+    fn expect(&mut self, keyword: &str) -> Result<(), EaqlError> {
+        match self.next(&format!("`{}`", keyword))? {
+            token if token.is(keyword) => Ok(()),
+            token => Err(unexpected(
+                format!("Expected `{}` in the SQL, got {:?}", keyword, token),
+                "only the SQL the transpiler outputs can be read back",
+            )),
+        }
+    }
+
+    fn expect_symbol(&mut self, symbol: &'static str) -> Result<(), EaqlError> {
+        match self.next(&format!("`{}`", symbol))? {
+            SqlToken::Symbol(found) if found == symbol => Ok(()),
+            token => Err(unexpected(
+                format!("Expected `{}` in the SQL, got {:?}", symbol, token),
+                "only the SQL the transpiler outputs can be read back",
+            )),
+        }
+    }
+
+    fn accept_symbol(&mut self, symbol: &str) -> bool {
+        let found: bool = matches!(self.peek(), Some(SqlToken::Symbol(v)) if *v == symbol);
+
+        if found {
+            self.idx += 1;
+        }
+
+        found
+    }
+
+    fn identifier(&mut self) -> Result<String, EaqlError> {
+        match self.next("a name")? {
+            SqlToken::Word(name) | SqlToken::Quoted(name) => Ok(name),
+            token => Err(unexpected(
+                format!("Expected a name in the SQL, got {:?}", token),
+                "tables, columns and databases are named with a single word",
+            )),
+        }
+    }
+
+    fn identifiers(&mut self) -> Result<Vec<String>, EaqlError> {
+        let mut names: Vec<String> = vec![self.identifier()?];
+
+        while self.accept_symbol(",") {
+            names.push(self.identifier()?);
+        }
+
+        Ok(names)
+    }
+
+    fn number(&mut self) -> Result<i32, EaqlError> {
+        match self.next("a number")? {
+            SqlToken::Number(number) => number.parse::<i32>().map_err(|_| {
+                EaqlError::InvalidLimit(Box::new(ErrorContext::new(format!(
+                    "Limits must be a 32-bit integer, got -> {}",
+                    number
+                ))))
+            }),
+            token => Err(unexpected(
+                format!("Expected a number in the SQL, got {:?}", token),
+                "limits must be a whole number, i.e. `LIMIT 10`",
+            )),
+        }
+    }
+
+    /// Whether the `(` next groups arithmetic rather than conditions,
+    /// told apart by what comes after its matching `)`.
+    fn arithmetic_group(&self) -> bool {
+        if !matches!(self.peek(), Some(SqlToken::Symbol("("))) {
+            return false;
+        }
+
+        let mut depth: usize = 0;
+
+        for (i, token) in self.tokens.iter().enumerate().skip(self.idx) {
+            match token {
+                SqlToken::Symbol("(") => depth += 1,
+                SqlToken::Symbol(")") if depth == 1 => {
+                    return matches!(
+                        self.tokens.get(i + 1),
+                        Some(SqlToken::Symbol(
+                            "+" | "-" | "*" | "/" | "=" | "<>" | "<" | "<=" | ">" | ">="
+                        ))
+                    );
+                }
+                SqlToken::Symbol(")") => depth -= 1,
+                _ => (),
+            }
+        }
+
+        false
+    }
+
+    fn finish(&self) -> Result<(), EaqlError> {
+        match self.peek() {
+            Some(token) => Err(unexpected(
+                format!("Unexpected {:?} at the end of the SQL", token),
+                "only the SQL the transpiler outputs can be read back",
+            )),
+            None => Ok(()),
+        }
+    }
+
+    fn statement(&mut self) -> Result<Query, EaqlError> {
+        let query: Query = match self.next("a statement")? {
+            token if token.is("select") => self.select()?,
+            token if token.is("create") => {
+                if !self.accept("schema") {
+                    self.expect("database")?;
+                }
+
+                Query::create_database(&self.identifier()?)?
+            }
+            token if token.is("attach") => {
+                // SQLite attaches a `<name>.db` file under the database's name
+                self.expect("database")?;
+                self.next("a file name")?;
+                self.expect("as")?;
+
+                Query::create_database(&self.identifier()?)?
+            }
+            token if token.is("drop") || token.is("detach") => {
+                if !self.accept("schema") {
+                    self.expect("database")?;
+                }
+
+                Query::delete_databases(self.identifiers()?)?
+            }
+            token if token.is("use") => {
+                self.accept("database");
+
+                Query::use_database(&self.identifier()?)?
+            }
+            token if token.is("set") => {
+                self.expect("schema")?;
+
+                match self.next("a schema name")? {
+                    SqlToken::Text(name) => Query::use_database(&name)?,
+                    token => {
+                        return Err(unexpected(
+                            format!("Expected a quoted schema name, got {:?}", token),
+                            "i.e. `SET SCHEMA 'shop'`",
+                        ));
+                    }
+                }
+            }
+            token if token.is("show") => {
+                self.expect("databases")?;
+
+                Query::show_databases()
+            }
+            token => {
+                return Err(EaqlError::UnknownAction(Box::new(
+                    ErrorContext::new(format!(
+                        "Couldn't determine what the SQL does, got {:?}",
+                        token
+                    ))
+                    .with_hint("statements start with `SELECT`, `CREATE`, `DROP`, `USE` or `SHOW`"),
+                )));
+            }
+        };
+
+        self.finish()?;
+
+        Ok(query)
+    }
+
+    fn select(&mut self) -> Result<Query, EaqlError> {
+        let top: Option<i32> = match self.accept("top") {
+            true => Some(self.number()?),
+            false => None,
+        };
+
+        let builder: GetBuilder = match self.accept_symbol("*") {
+            true => Query::get_all(),
+            false => Query::get(self.identifiers()?),
+        };
+
+        self.expect("from")?;
+
+        let mut builder = builder.from(&self.identifier()?);
+
+        if self.accept("where") {
+            builder = builder.filter(self.or()?);
+        }
+
+        if self.accept("order") {
+            self.expect("by")?;
+
+            loop {
+                let column: String = self.identifier()?;
+                let direction: SortDirection = match self.accept("desc") {
+                    true => SortDirection::Descending,
+                    false => {
+                        self.accept("asc");
+                        SortDirection::Ascending
+                    }
+                };
+
+                builder = builder.sort_by(&column, direction);
+
+                if !self.accept_symbol(",") {
+                    break;
+                }
+            }
+        }
+
+        let limit: Option<i32> = if self.accept("limit") {
+            Some(self.number()?)
+        } else if self.accept("fetch") {
+            self.expect("first")?;
+            let limit: i32 = self.number()?;
+            self.expect("rows")?;
+            self.expect("only")?;
+
+            Some(limit)
+        } else {
+            top
+        };
+
+        if let Some(limit) = limit {
+            builder = builder.limit(limit);
+        }
+
+        builder.build()
+    }
+
+    // Conditions are read with the usual SQL precedence, `OR` binding
+    // loosest and `NOT` tightest
+    fn or(&mut self) -> Result<Condition, EaqlError> {
+        let mut condition: Condition = self.and()?;
+
+        while self.accept("or") {
+            condition = condition.or(self.and()?);
+        }
+
+        Ok(condition)
+    }
+
+    fn and(&mut self) -> Result<Condition, EaqlError> {
+        let mut condition: Condition = self.not()?;
+
+        while self.accept("and") {
+            condition = condition.and(self.not()?);
+        }
+
+        Ok(condition)
+    }
+
+    fn not(&mut self) -> Result<Condition, EaqlError> {
+        if self.accept("not") {
+            return Ok(!self.not()?);
+        }
+
+        if !self.arithmetic_group() && self.accept_symbol("(") {
+            let condition: Condition = self.or()?;
+
+            if !self.accept_symbol(")") {
+                return Err(EaqlError::UnbalancedParen(Box::new(
+                    ErrorContext::new("SQL condition had unclosed parentheses".to_string())
+                        .with_hint("every `(` needs a matching `)`"),
+                )));
+            }
+
+            return Ok(condition);
+        }
+
+        self.comparison()
+    }
+
+    /// Reads a text value, wrapped in `LOWER(...)` when `lowered`.
+    fn text(&mut self, lowered: bool) -> Result<String, EaqlError> {
+        if lowered {
+            self.expect("lower")?;
+            self.expect_symbol("(")?;
+        }
+
+        let text: String = match self.next("a pattern")? {
+            SqlToken::Text(text) => text,
+            token => {
+                return Err(unexpected(
+                    format!("Expected a quoted pattern in the SQL, got {:?}", token),
+                    "`LIKE` is followed by a pattern, i.e. `'%latte%'`",
+                ));
+            }
+        };
+
+        if lowered {
+            self.expect_symbol(")")?;
+        }
+
+        Ok(text)
+    }
+
+    /// Reads the rest of a `LIKE` comparison on the column, up to and
+    /// including the `ESCAPE` clause if it has one.
+    fn pattern(&mut self, column: String, case: Case) -> Result<Condition, EaqlError> {
+        let lowered: bool = self.peek().is_some_and(|v| v.is("lower"));
+        let pattern: String = self.text(lowered)?;
+        let escaped: bool = self.accept("escape");
+
+        if escaped && self.next("an escape character")? != SqlToken::Text("\\".to_string()) {
+            return Err(unexpected(
+                "Only `\\` can be read back as the escape character of a pattern".to_string(),
+                "i.e. `LIKE '50\\%' ESCAPE '\\'`",
+            ));
+        }
+
+        let (operator, value): (ComparisonOperator, String) =
+            pattern_comparison(&pattern, escaped, case, self.dialect);
+
+        Ok(Condition::Compare(column, operator, Literal::Text(value)))
+    }
+
+    fn comparison(&mut self) -> Result<Condition, EaqlError> {
+        // Dialects without `ILIKE` ignore case by lowercasing both sides
+        if self.accept("lower") {
+            self.expect_symbol("(")?;
+            let column: String = self.identifier()?;
+            self.expect_symbol(")")?;
+            self.expect("like")?;
+
+            return self.pattern(column, Case::Insensitive);
+        }
+
+        let left: Expr = self.arithmetic()?;
+
+        // Everything but the plain comparisons only ever has a column on the left
+        let Expr::Column(column) = left else {
+            let operator: ComparisonOperator = self.comparison_operator()?;
+
+            return Ok(left.compare(operator, self.arithmetic()?));
+        };
+
+        if self.accept("like") {
+            return self.pattern(column, Case::Sensitive);
+        } else if self.accept("ilike") {
+            return self.pattern(column, Case::Insensitive);
+        }
+
+        if self.accept("is") {
+            let operator: ComparisonOperator = match self.accept("not") {
+                true => ComparisonOperator::NotEqual,
+                false => ComparisonOperator::Equal,
+            };
+            self.expect("null")?;
+
+            return Ok(Condition::Compare(column, operator, Literal::Null));
+        }
+
+        let negated: bool = self.accept("not");
+
+        if self.accept("in") {
+            let mut values: Vec<Literal> = vec![];
+
+            self.expect_symbol("(")?;
+
+            loop {
+                values.push(self.value()?);
+
+                if !self.accept_symbol(",") {
+                    break;
+                }
+            }
+
+            self.expect_symbol(")")?;
+
+            return Ok(Condition::OneOf(column, values, negated));
+        } else if self.accept("between") {
+            let low: Literal = self.value()?;
+            self.expect("and")?;
+            let high: Literal = self.value()?;
+
+            return Ok(Condition::Between(column, low, high, negated));
+        } else if negated {
+            return Err(unexpected(
+                "Expected `IN` or `BETWEEN` after `NOT` in the SQL".to_string(),
+                "only the SQL the transpiler outputs can be read back",
+            ));
+        }
+
+        let operator: ComparisonOperator = self.comparison_operator()?;
+
+        Ok(Expr::Column(column).compare(operator, self.arithmetic()?))
+    }
+
+    fn comparison_operator(&mut self) -> Result<ComparisonOperator, EaqlError> {
+        match self.next("a comparison")? {
+            SqlToken::Symbol("=") => Ok(ComparisonOperator::Equal),
+            SqlToken::Symbol("<>") => Ok(ComparisonOperator::NotEqual),
+            SqlToken::Symbol("<") => Ok(ComparisonOperator::LessThan),
+            SqlToken::Symbol("<=") => Ok(ComparisonOperator::LessThanOrEqual),
+            SqlToken::Symbol(">") => Ok(ComparisonOperator::GreaterThan),
+            SqlToken::Symbol(">=") => Ok(ComparisonOperator::GreaterThanOrEqual),
+            token => Err(unexpected(
+                format!("Expected a comparison in the SQL, got {:?}", token),
+                "expected a comparison like `=`, `<>`, `<=`",
+            )),
+        }
+    }
+
+    // Arithmetic is read with the usual precedence as well, multiplying
+    // and dividing before adding and subtracting
+    fn arithmetic(&mut self) -> Result<Expr, EaqlError> {
+        let mut expr: Expr = self.term()?;
+
+        loop {
+            if self.accept_symbol("+") {
+                expr = expr + self.term()?;
+            } else if self.accept_symbol("-") {
+                expr = expr - self.term()?;
+            } else {
+                return Ok(expr);
+            }
+        }
+    }
+
+    fn term(&mut self) -> Result<Expr, EaqlError> {
+        let mut expr: Expr = self.factor()?;
+
+        loop {
+            if self.accept_symbol("*") {
+                expr = expr * self.factor()?;
+            } else if self.accept_symbol("/") {
+                expr = expr / self.factor()?;
+            } else {
+                return Ok(expr);
+            }
+        }
+    }
+
+    fn factor(&mut self) -> Result<Expr, EaqlError> {
+        if self.accept_symbol("-") {
+            return Ok(-self.factor()?);
+        }
+
+        if self.accept_symbol("(") {
+            let expr: Expr = self.arithmetic()?;
+            self.expect_symbol(")")?;
+
+            return Ok(expr);
+        }
+
+        match self.next("a column or value")? {
+            token if token.is("true") => Ok(Expr::Value(Literal::Bool(true))),
+            token if token.is("false") => Ok(Expr::Value(Literal::Bool(false))),
+            SqlToken::Word(name) | SqlToken::Quoted(name) => Ok(Expr::Column(name)),
+            SqlToken::Text(text) => Ok(Expr::Value(Literal::Text(text))),
+            SqlToken::Number(number) => Ok(Expr::Value(Literal::Number(number))),
+            token => Err(unexpected(
+                format!("Expected a column or value in the SQL, got {:?}", token),
+                "columns are compared against a value, another column or arithmetic",
+            )),
+        }
+    }
+
+    fn value(&mut self) -> Result<Literal, EaqlError> {
+        match self.next("a value")? {
+            SqlToken::Text(text) => Ok(Literal::Text(text)),
+            SqlToken::Number(number) => Ok(Literal::Number(number)),
+            token if token.is("true") => Ok(Literal::Bool(true)),
+            token if token.is("false") => Ok(Literal::Bool(false)),
+            token => Err(unexpected(
+                format!("Expected a value in the SQL, got {:?}", token),
+                "columns are compared against a value, i.e. `5` or `'large'`",
+            )),
+        }
+    }
+}
+
+/// Whether the statement is how any dialect lists its databases,
+/// those are catalog queries rather than a `SELECT` EAQL could express.
+fn lists_databases(statement: &[SqlToken], dialect: &dyn Dialect) -> bool {
+    DIALECTS.iter().any(|target| {
+        target
+            .show_databases()
+            .and_then(|sql| tokenize(&sql, dialect))
+            .is_ok_and(|tokens| {
+                tokens.len() == statement.len()
+                    && tokens.iter().zip(statement).all(|(a, b)| a.same(b))
+            })
+    })
+}
+
+/// Parse SQL (String) in a dialect into the EAQL query it could
+/// have been transpiled from
+pub fn parse_sql(sql: &str, dialect: &dyn Dialect) -> Result<Query, EaqlError> {
+    let tokens: Vec<SqlToken> = tokenize(sql, dialect)?;
+    let statements: Vec<&[SqlToken]> = tokens
+        .split(|v| *v == SqlToken::Symbol(";"))
+        .filter(|v| !v.is_empty())
+        .collect();
+
+    match statements.as_slice() {
+        [] => Err(EaqlError::UnexpectedEnd(Box::new(ErrorContext::new(
+            "No SQL was provided".to_string(),
+        )))),
+        [statement] if lists_databases(statement, dialect) => Ok(Query::show_databases()),
+        [statement] => SqlParser {
+            tokens: statement.to_vec(),
+            idx: 0,
+            dialect,
+        }
+        .statement(),
+        // Most dialects drop each database in a statement of its own
+        _ if statements
+            .iter()
+            .all(|v| v.first().is_some_and(|v| v.is("drop") || v.is("detach"))) =>
+        {
+            let mut names: Vec<String> = vec![];
+
+            for statement in statements {
+                let mut parser: SqlParser = SqlParser {
+                    tokens: statement.to_vec(),
+                    idx: 1,
+                    dialect,
+                };
+
+                if !parser.accept("schema") {
+                    parser.expect("database")?;
+                }
+
+                names.extend(parser.identifiers()?);
+                parser.finish()?;
+            }
+
+            Query::delete_databases(names)
+        }
+        _ => Err(EaqlError::Unsupported(Box::new(
+            ErrorContext::new("Only one SQL statement can be read at a time".to_string())
+                .with_hint("EAQL queries are a single statement, try them one by one"),
+        ))),
+    }
+}
+
+/// Starts a Reverse Transpiling loop that accepts SQL from STDIN
+/// and outputs the EAQL query it could have come from. The
+/// dialect the SQL is in can be switched with `.dialect <name>`.
+pub fn repl_loop(format: Format) {
+    let mut dialect: &dyn Dialect = &Generic;
+
+    loop {
+        let Some(sql) = io::query_stdin(&format!("reverse:{}", dialect.name())) else {
+            println!();
+            return;
+        };
+
+        if let Some(name) = sql.strip_prefix(".dialect") {
+            match dialect::from_name(name.trim()) {
+                Some(state) => dialect = state,
+                None => logger::warning(&format!("Unknown dialect \"{}\"", name.trim())),
+            };
+
+            continue;
+        }
+
+        let parsed: Query = match parse_sql(&sql, dialect) {
+            Ok(state) => state,
+            Err(err) => {
+                eprintln!("{}", diagnostics::render_color(&err, &sql));
+                continue;
+            }
+        };
+
+        if io::print_ast(format, &parsed) {
+            continue;
+        }
+
+        match parsed.to_eaql(&Style::default()) {
+            Ok(eaql) => println!(
+                "‣ {} {}",
+                colorize("EAQL Query:", AnsiColor::BrightBlack),
+                eaql
+            ),
+            Err(err) => eprintln!("{}", diagnostics::render_color(&err, &sql)),
+        };
+    }
+}
+
+/// Reverse Transpile SQL (String) in a dialect to EAQL
+///
+/// # Example
+/// ```
+/// use eaql::transpiler::{dialect::Generic, reverse::engine};
+/// assert_eq!(
+///     engine("SELECT name FROM drinks WHERE price < 5 ORDER BY name DESC LIMIT 10;", &Generic),
+///     Ok("Get name from drinks where price is less than 5 then sort by name descending and limit it to 10."
+///         .to_string())
+/// );
+/// ```
+///
+pub fn engine(sql: &str, dialect: &dyn Dialect) -> Result<String, EaqlError> {
+    parse_sql(sql, dialect)?.to_eaql(&Style::default())
+}
+
+// Begin Reverse Transpiling Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transpiler::dialect::{MySql, SqlServer};
+
+    #[test]
+    fn unit_test_tokenize() {
+        assert_eq!(
+            tokenize(
+                "SELECT [a]]b] FROM t WHERE x <> 'it''s' AND y >= -1.5;",
+                &SqlServer
+            ),
+            Ok(vec![
+                SqlToken::Word("SELECT".to_string()),
+                SqlToken::Quoted("a]b".to_string()),
+                SqlToken::Word("FROM".to_string()),
+                SqlToken::Word("t".to_string()),
+                SqlToken::Word("WHERE".to_string()),
+                SqlToken::Word("x".to_string()),
+                SqlToken::Symbol("<>"),
+                SqlToken::Text("it's".to_string()),
+                SqlToken::Word("AND".to_string()),
+                SqlToken::Word("y".to_string()),
+                SqlToken::Symbol(">="),
+                SqlToken::Number("-1.5".to_string()),
+                SqlToken::Symbol(";"),
+            ])
+        );
+    }
+
+    #[test]
+    fn unit_test_tokenize_backslash() {
+        assert_eq!(
+            tokenize("'a\\\\b'", &MySql),
+            Ok(vec![SqlToken::Text("a\\b".to_string())])
+        );
+        assert_eq!(
+            tokenize("'a\\\\b'", &Generic),
+            Ok(vec![SqlToken::Text("a\\\\b".to_string())])
+        );
+    }
+
+    #[test]
+    fn unit_test_tokenize_error() {
+        assert!(tokenize("SELECT 'a FROM t", &Generic).is_err());
+        assert!(tokenize("SELECT a FROM t WHERE a ~ 1", &Generic).is_err());
+        assert!(tokenize("SELECT a FROM t WHERE a = 1 -- 2", &Generic).is_err());
+        assert!(tokenize("SELECT a FROM t /* b */", &Generic).is_err());
+    }
+
+    #[test]
+    fn unit_test_pattern_comparison() {
+        assert_eq!(
+            pattern_comparison("%50\\%%", true, Case::Sensitive, &Generic),
+            (
+                ComparisonOperator::Contains(Case::Sensitive),
+                "50%".to_string()
+            )
+        );
+        assert_eq!(
+            pattern_comparison("a[_]%", false, Case::Insensitive, &SqlServer),
+            (
+                ComparisonOperator::StartsWith(Case::Insensitive),
+                "a_".to_string()
+            )
+        );
+        assert_eq!(
+            pattern_comparison("%a_\\%", false, Case::Sensitive, &MySql),
+            (
+                ComparisonOperator::Like(Case::Sensitive),
+                "%a_\\%".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn unit_test_condition_precedence() {
+        let mut parser: SqlParser = SqlParser {
+            tokens: tokenize("NOT a = 1 OR b = 2 AND (c = 3 OR d = 4)", &Generic).unwrap(),
+            idx: 0,
+            dialect: &Generic,
+        };
+
+        assert_eq!(
+            parser.or(),
+            Ok((!Condition::Compare(
+                "a".to_string(),
+                ComparisonOperator::Equal,
+                Literal::Number("1".to_string())
+            ))
+            .or(Condition::Compare(
+                "b".to_string(),
+                ComparisonOperator::Equal,
+                Literal::Number("2".to_string())
+            )
+            .and(
+                Condition::Compare(
+                    "c".to_string(),
+                    ComparisonOperator::Equal,
+                    Literal::Number("3".to_string())
+                )
+                .or(Condition::Compare(
+                    "d".to_string(),
+                    ComparisonOperator::Equal,
+                    Literal::Number("4".to_string())
+                ))
+            )))
+        );
+    }
+}
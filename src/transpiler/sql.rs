@@ -0,0 +1,562 @@
+/*
+SQL Generation
+
+Turns a parsed query into SQL for a dialect by visiting its tree. Every
+clause is output as the SQL text along with the reduced query it came
+from, colored when the output is meant for the Transpiler REPL.
+*/
+
+use crate::{
+    language::{
+        errors::{EaqlError, ErrorContext},
+        parser::{
+            arithmetic::{ArithmeticChild, ArithmeticNode, NegateNode},
+            conditional::{
+                BoolNode, Case, ComparisonNode, ComparisonOperator, ConditionChild, ConditionNode,
+                ExpressionNode, ListNode, LogicalOperator, NotNode, OperandNode, RangeNode, Value,
+                value,
+            },
+            database::{CreateNode, DatabaseNode, DestroyNode, ShowNode, UseNode},
+            get::{ColumnNode, FilterNode, GetNode, TableNode},
+            postprocessor::{LimitNode, SortKeyNode, SortNode},
+        },
+        visitor::{
+            Visitor, walk_arithmetic, walk_condition, walk_condition_child, walk_database,
+            walk_filter, walk_get, walk_sort,
+        },
+    },
+    transpiler::dialect::{Dialect, LimitStyle},
+    utils::colors::{AnsiColor, colorize},
+};
+
+/// How tightly a conditional node binds in SQL (and EAQL), lowest first.
+/// A child only needs parentheses when it binds looser than its parent.
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub(crate) enum Precedence {
+    Or,
+    And,
+    Not,
+    Expression,
+}
+
+pub(crate) struct SqlWriter<'d> {
+    dialect: &'d dyn Dialect,
+    colored: bool,
+
+    eaql: Vec<String>,
+    sql: String,
+
+    top: Option<i32>,
+    precedence: Precedence,
+    error: Option<EaqlError>,
+}
+
+/// Outputs whatever `visit` visits transpiled with color and
+/// it's raw query counterpart. Output are used by the Transpiler REPL.
+pub(crate) fn transpile_color(
+    dialect: &dyn Dialect,
+    visit: impl FnOnce(&mut SqlWriter),
+) -> Result<(String, String), EaqlError> {
+    let mut writer: SqlWriter = SqlWriter::new(dialect, true);
+
+    visit(&mut writer);
+    writer.finish()
+}
+
+/// Outputs whatever `visit` visits transpiled to raw SQL.
+pub(crate) fn transpile_raw(
+    dialect: &dyn Dialect,
+    visit: impl FnOnce(&mut SqlWriter),
+) -> Result<String, EaqlError> {
+    let mut writer: SqlWriter = SqlWriter::new(dialect, false);
+
+    visit(&mut writer);
+    writer.finish().map(|(_, sql)| sql)
+}
+
+pub(crate) fn precedence(node: &ConditionChild) -> Precedence {
+    match node {
+        ConditionChild::Op(op) if op.operator() == LogicalOperator::And => Precedence::And,
+        ConditionChild::Op(_) => Precedence::Or,
+        ConditionChild::Not(_) => Precedence::Not,
+        ConditionChild::Expr(_)
+        | ConditionChild::Bool(_)
+        | ConditionChild::List(_)
+        | ConditionChild::Range(_)
+        | ConditionChild::Compare(_) => Precedence::Expression,
+    }
+}
+
+impl<'d> SqlWriter<'d> {
+    fn new(dialect: &'d dyn Dialect, colored: bool) -> SqlWriter<'d> {
+        SqlWriter {
+            dialect,
+            colored,
+            eaql: vec![],
+            sql: String::new(),
+            top: None,
+            precedence: Precedence::Or,
+            error: None,
+        }
+    }
+
+    /// Limit of the get being transpiled, used by dialects that
+    /// limit with `TOP` while selecting columns.
+    pub(crate) fn set_top(&mut self, top: Option<&LimitNode>) {
+        self.top = top.map(|v| v.value());
+    }
+
+    fn finish(self) -> Result<(String, String), EaqlError> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok((self.eaql.join(" "), self.sql)),
+        }
+    }
+
+    fn paint(&self, text: &str, color: AnsiColor) -> String {
+        if self.colored {
+            colorize(text, color)
+        } else {
+            text.to_string()
+        }
+    }
+
+    /// Keeps the first error hit, later output is thrown away anyway.
+    fn check(&mut self, result: Result<String, EaqlError>) -> String {
+        result.unwrap_or_else(|err| {
+            self.error.get_or_insert(err);
+            String::new()
+        })
+    }
+
+    /// Appends a clause and the part of the query it came from,
+    /// either may be empty when it has nothing to output.
+    fn clause(&mut self, eaql: String, sql: String) {
+        if !eaql.is_empty() {
+            self.eaql.push(eaql);
+        }
+
+        if !sql.is_empty() {
+            if !self.sql.is_empty() {
+                self.sql.push(' ');
+            }
+
+            self.sql.push_str(&sql);
+        }
+    }
+
+    /// Runs `visit`, taking the SQL it output rather than appending it.
+    fn capture(&mut self, visit: impl FnOnce(&mut Self)) -> String {
+        let outer: String = std::mem::take(&mut self.sql);
+
+        visit(self);
+        std::mem::replace(&mut self.sql, outer)
+    }
+}
+
+/// The SQL operator for a plain comparison, patterns have none.
+fn comparison_operator(operator: ComparisonOperator) -> Option<&'static str> {
+    match operator {
+        ComparisonOperator::Equal => Some("="),
+        ComparisonOperator::NotEqual => Some("<>"),
+        ComparisonOperator::LessThan => Some("<"),
+        ComparisonOperator::LessThanOrEqual => Some("<="),
+        ComparisonOperator::GreaterThan => Some(">"),
+        ComparisonOperator::GreaterThanOrEqual => Some(">="),
+        _ => None,
+    }
+}
+
+/// Turns a `like` pattern written in EAQL, where `\` escapes the
+/// character after it, into the same pattern for the dialect.
+fn like_pattern(dialect: &dyn Dialect, pattern: &str) -> String {
+    let mut converted: String = String::new();
+    let mut chars = pattern.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '%' | '_' => converted.push(c),
+            '\\' => {
+                let escaped: char = chars.next().unwrap_or('\\');
+                converted.push_str(&dialect.escape_pattern(&escaped.to_string()));
+            }
+            c => converted.push_str(&dialect.escape_pattern(&c.to_string())),
+        }
+    }
+
+    converted
+}
+
+impl SqlWriter<'_> {
+    fn value(&mut self, value: Value) -> String {
+        match value {
+            Value::Text(text) => self.check(self.dialect.quote_string(text)),
+            Value::Number(number) => self.check(self.dialect.format_number(number)),
+            Value::Bool(value) => self.dialect.format_bool(value),
+            Value::Null => "NULL".to_string(),
+        }
+    }
+
+    /// Outputs one side of some arithmetic, wrapped in parentheses
+    /// where SQL would otherwise do it in another order.
+    fn arithmetic_side(&mut self, node: &ArithmeticChild, grouped: bool) {
+        if grouped {
+            self.sql.push('(');
+        }
+
+        self.visit_arithmetic(node);
+
+        if grouped {
+            self.sql.push(')');
+        }
+    }
+
+    /// Outputs an expression matching text against a pattern as `LIKE`,
+    /// escaping the wildcards of values that are meant to be matched
+    /// as written.
+    fn visit_pattern(&mut self, node: &ExpressionNode) {
+        let text: &str = match node.value() {
+            Value::Text(text) | Value::Number(text) => text,
+            _ => unreachable!("Only text is parsed as a pattern"),
+        };
+        let pattern: String = match node.operator() {
+            ComparisonOperator::Contains(_) => {
+                format!("%{}%", self.dialect.escape_pattern(text))
+            }
+            ComparisonOperator::StartsWith(_) => format!("{}%", self.dialect.escape_pattern(text)),
+            ComparisonOperator::EndsWith(_) => format!("%{}", self.dialect.escape_pattern(text)),
+            _ => like_pattern(self.dialect, text),
+        };
+        let escape: &str = match self.dialect.needs_escape_clause() && pattern.contains('\\') {
+            true => " ESCAPE '\\'",
+            false => "",
+        };
+
+        let column: String = self.check(self.dialect.quote_identifier(node.column()));
+        let value: String = self.check(self.dialect.quote_string(&pattern));
+        let (column, operator, value): (String, &str, String) = match node.operator().pattern_case()
+        {
+            Some(Case::Insensitive) if self.dialect.supports_ilike() => {
+                (column, "ILIKE", value + escape)
+            }
+            Some(Case::Insensitive) => (
+                format!("LOWER({})", column),
+                "LIKE",
+                format!("LOWER({}){}", value, escape),
+            ),
+            _ => (column, "LIKE", value + escape),
+        };
+        let expression: String = format!(
+            "{} {} {}",
+            self.paint(&column, AnsiColor::Yellow),
+            self.paint(operator, AnsiColor::Cyan),
+            self.paint(&value, AnsiColor::Green)
+        );
+
+        self.sql.push_str(&expression);
+    }
+}
+
+impl<'ast> Visitor<'ast> for SqlWriter<'_> {
+    fn visit_get(&mut self, node: &'ast GetNode) {
+        if self.dialect.limit_style() == LimitStyle::Top {
+            self.top = node.limit().map(|v| v.value());
+        }
+
+        walk_get(self, node);
+    }
+
+    fn visit_column(&mut self, node: &'ast ColumnNode) {
+        let columns: String = if node.is_wildcard() {
+            "*".to_string()
+        } else {
+            let quoted: Result<Vec<String>, EaqlError> = node
+                .names()
+                .iter()
+                .map(|column| self.dialect.quote_identifier(column))
+                .collect();
+
+            self.check(quoted.map(|v| v.join(", ")))
+        };
+
+        let sql: String = match self.top {
+            Some(limit) => format!(
+                "SELECT TOP {} {}",
+                self.dialect.format_limit(limit),
+                columns
+            ),
+            None => format!("SELECT {}", columns),
+        };
+
+        self.clause(
+            self.paint(node.literal(), AnsiColor::Yellow),
+            self.paint(&sql, AnsiColor::Yellow),
+        );
+    }
+
+    fn visit_table(&mut self, node: &'ast TableNode) {
+        let table: String = self.check(self.dialect.quote_identifier(node.name()));
+
+        self.clause(
+            self.paint(node.literal(), AnsiColor::Blue),
+            self.paint(&format!("FROM {}", table), AnsiColor::Blue),
+        );
+    }
+
+    fn visit_filter(&mut self, node: &'ast FilterNode) {
+        let condition: String = self.capture(|writer| walk_filter(writer, node));
+
+        self.clause(
+            self.paint(
+                &format!("{} {}", node.literal(), node.condition().literal()),
+                AnsiColor::Cyan,
+            ),
+            format!("{} {}", self.paint("WHERE", AnsiColor::Cyan), condition),
+        );
+    }
+
+    fn visit_condition(&mut self, node: &'ast ConditionNode) {
+        self.precedence = Precedence::Or;
+        walk_condition(self, node);
+    }
+
+    /// Only adds parentheses where the SQL precedence wouldn't match the tree.
+    fn visit_condition_child(&mut self, node: &'ast ConditionChild) {
+        let node: &ConditionChild = node.simplify();
+        let parent: Precedence = std::mem::replace(&mut self.precedence, precedence(node));
+        let grouped: bool = self.precedence < parent;
+
+        if grouped {
+            self.sql.push_str(&self.paint("(", AnsiColor::Cyan));
+        }
+
+        walk_condition_child(self, node);
+
+        if grouped {
+            self.sql.push_str(&self.paint(")", AnsiColor::Cyan));
+        }
+
+        self.precedence = parent;
+    }
+
+    fn visit_operand(&mut self, node: &'ast OperandNode) {
+        let operator: &str = match node.operator() {
+            LogicalOperator::And => "AND",
+            LogicalOperator::Or => "OR",
+        };
+
+        self.visit_condition_child(node.left());
+        self.sql
+            .push_str(&format!(" {} ", self.paint(operator, AnsiColor::Cyan)));
+        self.visit_condition_child(node.right());
+    }
+
+    fn visit_not(&mut self, node: &'ast NotNode) {
+        self.sql
+            .push_str(&format!("{} ", self.paint("NOT", AnsiColor::Cyan)));
+        self.precedence = Precedence::Expression;
+        self.visit_condition_child(node.child());
+    }
+
+    fn visit_expression(&mut self, node: &'ast ExpressionNode) {
+        let Some(operator) = comparison_operator(node.operator()) else {
+            return self.visit_pattern(node);
+        };
+        let column: String = self.check(self.dialect.quote_identifier(node.column()));
+        // Nothing is ever equal to null in SQL, it's checked with IS instead
+        let (operator, value): (&str, String) = match (node.operator(), node.value()) {
+            (ComparisonOperator::Equal, Value::Null) => ("IS", "NULL".to_string()),
+            (_, Value::Null) => ("IS NOT", "NULL".to_string()),
+            (_, value) => (operator, self.value(value)),
+        };
+        let expression: String = format!(
+            "{} {} {}",
+            self.paint(&column, AnsiColor::Yellow),
+            self.paint(operator, AnsiColor::Cyan),
+            self.paint(&value, AnsiColor::Green)
+        );
+
+        self.sql.push_str(&expression);
+    }
+
+    fn visit_comparison(&mut self, node: &'ast ComparisonNode) {
+        let left: String = self.capture(|writer| writer.visit_arithmetic(node.left()));
+        let right: String = self.capture(|writer| writer.visit_arithmetic(node.right()));
+        let expression: String = format!(
+            "{} {} {}",
+            self.paint(&left, AnsiColor::Yellow),
+            self.paint(
+                comparison_operator(node.operator()).unwrap_or_default(),
+                AnsiColor::Cyan
+            ),
+            self.paint(&right, AnsiColor::Green)
+        );
+
+        self.sql.push_str(&expression);
+    }
+
+    fn visit_arithmetic(&mut self, node: &'ast ArithmeticChild) {
+        let written: String = match node {
+            ArithmeticChild::Column(token) => {
+                self.check(self.dialect.quote_identifier(&token.lexeme))
+            }
+            ArithmeticChild::Value(token) => self.value(value(token)),
+            ArithmeticChild::Negate(_) | ArithmeticChild::Op(_) => {
+                return walk_arithmetic(self, node);
+            }
+        };
+
+        self.sql.push_str(&written);
+    }
+
+    fn visit_arithmetic_op(&mut self, node: &'ast ArithmeticNode) {
+        self.arithmetic_side(node.left(), node.grouped(false));
+        self.sql
+            .push_str(&format!(" {} ", node.operator().symbol()));
+        self.arithmetic_side(node.right(), node.grouped(true));
+    }
+
+    fn visit_negate(&mut self, node: &'ast NegateNode) {
+        self.sql.push('-');
+        self.arithmetic_side(node.child(), node.grouped());
+    }
+
+    fn visit_list(&mut self, node: &'ast ListNode) {
+        let column: String = self.check(self.dialect.quote_identifier(node.column()));
+        let values: Vec<String> = node
+            .values()
+            .into_iter()
+            .map(|value| self.value(value))
+            .collect();
+        let expression: String = format!(
+            "{} {} {}",
+            self.paint(&column, AnsiColor::Yellow),
+            self.paint(
+                if node.negated() { "NOT IN" } else { "IN" },
+                AnsiColor::Cyan
+            ),
+            self.paint(&format!("({})", values.join(", ")), AnsiColor::Green)
+        );
+
+        self.sql.push_str(&expression);
+    }
+
+    fn visit_range(&mut self, node: &'ast RangeNode) {
+        let column: String = self.check(self.dialect.quote_identifier(node.column()));
+        let low: String = self.value(node.low());
+        let high: String = self.value(node.high());
+        let expression: String = format!(
+            "{} {} {} {} {}",
+            self.paint(&column, AnsiColor::Yellow),
+            self.paint(
+                if node.negated() {
+                    "NOT BETWEEN"
+                } else {
+                    "BETWEEN"
+                },
+                AnsiColor::Cyan
+            ),
+            self.paint(&low, AnsiColor::Green),
+            self.paint("AND", AnsiColor::Cyan),
+            self.paint(&high, AnsiColor::Green)
+        );
+
+        self.sql.push_str(&expression);
+    }
+
+    fn visit_bool(&mut self, node: &'ast BoolNode) {
+        let value: String = self.paint(&self.dialect.format_bool(node.value()), AnsiColor::Green);
+
+        self.sql.push_str(&value);
+    }
+
+    fn visit_sort(&mut self, node: &'ast SortNode) {
+        let keys: String = self.capture(|writer| walk_sort(writer, node));
+
+        self.clause(
+            self.paint(node.literal(), AnsiColor::Magenta),
+            self.paint(&format!("ORDER BY {}", keys), AnsiColor::Magenta),
+        );
+    }
+
+    fn visit_sort_key(&mut self, node: &'ast SortKeyNode) {
+        let column: String = self.check(self.dialect.quote_identifier(node.column()));
+
+        if !self.sql.is_empty() {
+            self.sql.push_str(", ");
+        }
+
+        self.sql
+            .push_str(&format!("{} {}", column, node.direction().transpile_raw()));
+    }
+
+    /// Dialects using `TOP` handle the limit when selecting columns instead.
+    fn visit_limit(&mut self, node: &'ast LimitNode) {
+        let sql: String = match self.dialect.limit_style() {
+            LimitStyle::Limit => format!("LIMIT {}", self.dialect.format_limit(node.value())),
+            LimitStyle::FetchFirst => format!(
+                "FETCH FIRST {} ROWS ONLY",
+                self.dialect.format_limit(node.value())
+            ),
+            LimitStyle::Top => "".to_string(),
+        };
+
+        self.clause(
+            self.paint(node.literal(), AnsiColor::Magenta),
+            if sql.is_empty() {
+                sql
+            } else {
+                self.paint(&sql, AnsiColor::Magenta)
+            },
+        );
+    }
+
+    fn visit_database(&mut self, node: &'ast DatabaseNode) {
+        if node.operation().is_none() {
+            self.error
+                .get_or_insert(EaqlError::UnknownAction(Box::new(ErrorContext::new(
+                    "No database operation provided".to_string(),
+                ))));
+            return;
+        }
+
+        self.clause(
+            self.paint(node.literal(), AnsiColor::Yellow),
+            "".to_string(),
+        );
+        walk_database(self, node);
+    }
+
+    fn visit_create(&mut self, node: &'ast CreateNode) {
+        let sql: String = self.check(self.dialect.create_database(node.name()));
+
+        self.clause(
+            self.paint(node.literal(), AnsiColor::Blue),
+            self.paint(&sql, AnsiColor::Yellow),
+        );
+    }
+
+    fn visit_destroy(&mut self, node: &'ast DestroyNode) {
+        let sql: String = self.check(self.dialect.drop_databases(node.names()));
+
+        self.clause(
+            self.paint(node.literal(), AnsiColor::Blue),
+            self.paint(&sql, AnsiColor::Yellow),
+        );
+    }
+
+    fn visit_use(&mut self, node: &'ast UseNode) {
+        let sql: String = self.check(self.dialect.use_database(node.name()));
+
+        self.clause(
+            self.paint(node.literal(), AnsiColor::Blue),
+            self.paint(&sql, AnsiColor::Yellow),
+        );
+    }
+
+    fn visit_show(&mut self, _node: &'ast ShowNode) {
+        let sql: String = self.check(self.dialect.show_databases());
+
+        self.clause("".to_string(), self.paint(&sql, AnsiColor::Yellow));
+    }
+}
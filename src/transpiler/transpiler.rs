@@ -1,39 +1,96 @@
 use crate::{
-    language::parser::parser::Query,
+    language::{
+        diagnostics,
+        errors::{EaqlError, Warning},
+        parser::parser::Query,
+    },
+    transpiler::{
+        dialect::{self, Dialect, Generic},
+        parameters::{BoundValue, Parameterized},
+    },
     utils::{
         colors::{AnsiColor, colorize},
-        io, logger,
-        query::process_query,
+        io::{self, Format},
+        logger,
+        query::{
+            process_query, process_query_with_warnings, process_script,
+            process_script_with_warnings,
+        },
     },
 };
 
 /// Starts a Transpiling loop that accepts queries from STDIN
 /// and outputs color coded SQL matching cooresponding parts
-/// of the input queries.
-pub fn repl_loop() {
+/// of the input queries. The target dialect can be switched
+/// with `.dialect <name>`.
+pub fn repl_loop(format: Format) {
+    let mut dialect: &dyn Dialect = &Generic;
+
     loop {
-        let query: String = io::query_stdin("transpiler");
+        let Some(query) = io::query_stdin(&format!("transpiler:{}", dialect.name())) else {
+            println!();
+            return;
+        };
+
+        if let Some(name) = query.strip_prefix(".dialect") {
+            match dialect::from_name(name.trim()) {
+                Some(state) => dialect = state,
+                None => logger::warning(&format!(
+                    "Unknown dialect \"{}\", expected one of: {}",
+                    name.trim(),
+                    dialect::DIALECTS
+                        .iter()
+                        .map(|v| v.name())
+                        .collect::<Vec<&str>>()
+                        .join(", ")
+                )),
+            };
+
+            continue;
+        }
+
+        // Every query on the line is transpiled, one after another
+        let parsed: Vec<Query> = match process_script_with_warnings(&query) {
+            Ok((state, warnings)) => {
+                for warning in warnings {
+                    eprintln!("{}", diagnostics::render_warning_color(&warning, &query));
+                }
+
+                state
+            }
+            Err(errors) => {
+                for err in errors {
+                    eprintln!("{}", diagnostics::render_color(&err, &query));
+                }
 
-        let parsed: Query = match process_query(&query) {
-            Some(state) => state,
-            None => {
-                logger::warning("Invalid query, see above warnings for issues!");
                 continue;
             }
         };
 
-        let transpiled: (String, String) = parsed.transpile_color();
-
-        println!(
-            "‣ {} {};",
-            colorize("Reduced Query:", AnsiColor::BrightBlack),
-            transpiled.0
-        );
-        println!(
-            "‣ {} {};",
-            colorize("SQL Query:", AnsiColor::BrightBlack),
-            transpiled.1
-        );
+        for parsed in parsed {
+            if io::print_ast(format, &parsed) {
+                continue;
+            }
+
+            let transpiled: (String, String) = match parsed.transpile_color(dialect) {
+                Ok(state) => state,
+                Err(err) => {
+                    eprintln!("{}", diagnostics::render_color(&err, &query));
+                    continue;
+                }
+            };
+
+            println!(
+                "‣ {} {};",
+                colorize("Reduced Query:", AnsiColor::BrightBlack),
+                transpiled.0
+            );
+            println!(
+                "‣ {} {};",
+                colorize("SQL Query:", AnsiColor::BrightBlack),
+                transpiled.1
+            );
+        }
     }
 }
 
@@ -45,13 +102,106 @@ pub fn repl_loop() {
 /// assert_eq!(engine(&"Get everything from db_1!"), Ok("SELECT * FROM db_1;".to_string()));
 /// ```
 ///
-pub fn engine(query: &str) -> Result<String, String> {
-    let parsed: Query = match process_query(&query.to_string()) {
-        Some(state) => state,
-        None => {
-            return Err("Invalid query, see above logged warnings for issues!".to_string());
+pub fn engine(query: &str) -> Result<String, EaqlError> {
+    engine_with_dialect(query, &Generic)
+}
+
+/// Transpile Input Query (String) to a specific dialect of SQL
+///
+/// # Example
+/// ```
+/// use eaql::transpiler::{dialect::Postgres, engine_with_dialect};
+/// assert_eq!(
+///     engine_with_dialect(&"Get name from user then limit it to 5!", &Postgres),
+///     Ok("SELECT name FROM \"user\" LIMIT 5;".to_string())
+/// );
+/// ```
+///
+pub fn engine_with_dialect(query: &str, dialect: &dyn Dialect) -> Result<String, EaqlError> {
+    let parsed: Query = process_query(&query.to_string())?;
+
+    return Ok(format!("{};", parsed.transpile_raw(dialect)?));
+}
+
+/// Transpile Input Query (String) to a specific dialect of SQL,
+/// returned alongside any warnings about the query
+///
+/// # Example
+/// ```
+/// use eaql::transpiler::{dialect::Generic, engine_with_warnings};
+/// let (sql, warnings) =
+///     engine_with_warnings(&"Get name from drinks then limit it to 0!", &Generic).unwrap();
+/// assert_eq!(sql, "SELECT name FROM drinks LIMIT 0;");
+/// assert_eq!(warnings[0].message(), "Limiting to 0 rows will always return nothing.");
+/// ```
+///
+pub fn engine_with_warnings(
+    query: &str,
+    dialect: &dyn Dialect,
+) -> Result<(String, Vec<Warning>), EaqlError> {
+    let (parsed, warnings): (Query, Vec<Warning>) =
+        process_query_with_warnings(&query.to_string())?;
+
+    return Ok((format!("{};", parsed.transpile_raw(dialect)?), warnings));
+}
+
+/// Transpile Input Script (String) of one or more queries to a SQL
+/// script of a specific dialect, one statement per line. Errors in any
+/// of the queries are all returned rather than just the first.
+///
+/// # Example
+/// ```
+/// use eaql::transpiler::{dialect::Generic, engine_script};
+/// assert_eq!(
+///     engine_script(&"Use database shop. Get everything from drinks!", &Generic),
+///     Ok("USE DATABASE shop;\nSELECT * FROM drinks;".to_string())
+/// );
+/// assert_eq!(engine_script(&"Get from drinks. Get all drinks!", &Generic).unwrap_err().len(), 2);
+/// ```
+///
+pub fn engine_script(script: &str, dialect: &dyn Dialect) -> Result<String, Vec<EaqlError>> {
+    let parsed: Vec<Query> = process_script(&script.to_string())?;
+
+    let mut statements: Vec<String> = vec![];
+    let mut errors: Vec<EaqlError> = vec![];
+
+    for query in parsed {
+        match query.transpile_raw(dialect) {
+            Ok(statement) => statements.push(format!("{};", statement)),
+            Err(err) => errors.push(err),
         }
-    };
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    Ok(statements.join("\n"))
+}
+
+/// Transpile Input Query (String) to SQL with placeholders in place of
+/// values, returned alongside the values to bind in placeholder order
+///
+/// # Example
+/// ```
+/// use eaql::transpiler::{dialect::Postgres, engine_parameterized, parameters::BoundValue};
+/// assert_eq!(
+///     engine_parameterized(&"Get name from drinks where price < 5 then limit it to 10!", &Postgres),
+///     Ok((
+///         "SELECT name FROM drinks WHERE price < $1 LIMIT $2;".to_string(),
+///         vec![BoundValue::Integer(5), BoundValue::Integer(10)]
+///     ))
+/// );
+/// ```
+///
+pub fn engine_parameterized(
+    query: &str,
+    dialect: &dyn Dialect,
+) -> Result<(String, Vec<BoundValue>), EaqlError> {
+    let parsed: Query = process_query(&query.to_string())?;
+
+    let parameterized: Parameterized = Parameterized::new(dialect);
+    let transpiled: String = parsed.transpile_raw(&parameterized)?;
 
-    return Ok(format!("{};", parsed.transpile_raw()));
+    return Ok((format!("{};", transpiled), parameterized.into_values()));
 }
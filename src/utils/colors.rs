@@ -1,7 +1,8 @@
+#[derive(Clone, Copy)]
 pub enum AnsiColor {
     _Black,
     _Red,
-    _Green,
+    Green,
     Yellow,
     Blue,
     Magenta,
@@ -18,11 +19,11 @@ pub enum AnsiColor {
 }
 
 impl AnsiColor {
-    fn to_code(&self) -> &str {
+    fn to_code(self) -> &'static str {
         match self {
             AnsiColor::_Black => "\x1b[30m",
             AnsiColor::_Red => "\x1b[31m",
-            AnsiColor::_Green => "\x1b[32m",
+            AnsiColor::Green => "\x1b[32m",
             AnsiColor::Yellow => "\x1b[33m",
             AnsiColor::Blue => "\x1b[34m",
             AnsiColor::Magenta => "\x1b[35m",
@@ -13,11 +13,20 @@ const HELP_MENU: &str = r#"
        EAQL - Test Environment Help Menu
 
 Usage:
-    cargo run <module>
+    cargo run <module> [--format <text|json>]
 
 Modules:
     transpile: EAQL -> SQL Language Transpiler
+        (switch SQL dialects with ".dialect <name>")
+    reverse: SQL -> EAQL Reverse Transpiler
+        (switch SQL dialects with ".dialect <name>")
     validate: Query Validitor
+    format: EAQL -> Canonical EAQL Formatter
+        (prefer keywords with ".prefer <keyword>")
+
+Options:
+    --format: Output queries as color coded "text" (default)
+        or their AST as "json" (needs the `serde` feature)
     
 ═══════════════════════════════════════════════════
 "#;
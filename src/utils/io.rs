@@ -1,13 +1,69 @@
-use crate::utils::logger;
+#[cfg(feature = "serde")]
+use crate::language::ast;
+use crate::{language::parser::parser::Query, utils::logger};
 use std::io::{self, Write};
 
-pub fn query_stdin(tag: &str) -> String {
+/// How the REPLs output parsed queries.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Format {
+    /// Color coded output meant to be read in a terminal.
+    Text,
+    /// The AST as JSON, needs the `serde` feature.
+    #[cfg(feature = "serde")]
+    Json,
+}
+
+impl Format {
+    /// Gets the format matching a name, case insensitive (i.e. "json").
+    pub fn from_name(name: &str) -> Option<Format> {
+        match name.to_ascii_lowercase().as_str() {
+            "text" => Some(Format::Text),
+            #[cfg(feature = "serde")]
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Prompts for and reads a line from STDIN, returns `None` once
+/// STDIN is closed or can't be read from.
+pub fn query_stdin(tag: &str) -> Option<String> {
     // Get input
     let mut line: String = String::new();
     print!("({}) >>> ", tag);
-    io::stdout().flush().unwrap();
-    io::stdin().read_line(&mut line).unwrap();
+
+    if io::stdout().flush().is_err() {
+        return None;
+    }
+
+    match io::stdin().read_line(&mut line) {
+        Ok(0) => return None,
+        Ok(_) => (),
+        Err(err) => {
+            logger::error(&format!("Couldn't read from STDIN -> {}", err));
+            return None;
+        }
+    }
+
     line = line.trim().to_string();
     logger::debug(&format!("Received Query String -> \"{}\"", line));
-    line
+    Some(line)
+}
+
+/// Prints the AST of a parsed query when the format calls for it
+/// rather than the REPL's usual output, returns whether it did.
+#[cfg_attr(not(feature = "serde"), allow(unused_variables))]
+pub fn print_ast(format: Format, query: &Query) -> bool {
+    match format {
+        Format::Text => false,
+        #[cfg(feature = "serde")]
+        Format::Json => {
+            match ast::to_json(query) {
+                Ok(json) => println!("{}", json),
+                Err(err) => logger::error(&format!("Couldn't serialize the query -> {}", err)),
+            }
+
+            true
+        }
+    }
 }
@@ -1,27 +1,171 @@
-// EAQL Logger - Will need to clean this up later
+// EAQL Logger
+//
+// Everything the library logs goes through here. Nothing is output
+// until a sink is installed, so embedding the crate stays quiet unless
+// asked otherwise. The REPLs install the Terminal sink, programs using
+// the `log` or `tracing` ecosystems can forward to them instead with
+// the matching cargo feature.
 use crate::utils::colors::{AnsiColor, colorize};
 use chrono::Local;
+use std::sync::{
+    RwLock,
+    atomic::{AtomicU8, Ordering},
+};
 
-// TODO: Move these to config?
-pub const TEST_MODE: bool = true;
-pub const LOG_LEVEL: i32 = 3;
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Level {
+    Debug = 1,
+    Info = 2,
+    Warning = 3,
+    Error = 4,
+    Off = 5,
+}
+
+/// Destination for log messages.
+pub trait Sink: Send + Sync {
+    fn log(&self, level: Level, msg: &str);
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Warning as u8);
+static SINK: RwLock<Option<Box<dyn Sink>>> = RwLock::new(None);
+
+impl Level {
+    fn name(&self) -> &'static str {
+        match self {
+            Level::Debug => "Debug",
+            Level::Info => "Info",
+            Level::Warning => "Warning",
+            Level::Error => "Error",
+            Level::Off => "Off",
+        }
+    }
+
+    fn color(&self) -> AnsiColor {
+        match self {
+            Level::Debug => AnsiColor::BrightBlack,
+            Level::Info => AnsiColor::BrightBlue,
+            Level::Warning => AnsiColor::BrightYellow,
+            Level::Error | Level::Off => AnsiColor::BrightRed,
+        }
+    }
+
+    /// Gets the level matching a name, case insensitive (i.e. "debug").
+    pub fn from_name(name: &str) -> Option<Level> {
+        match name.to_ascii_lowercase().as_str() {
+            "debug" => Some(Level::Debug),
+            "info" => Some(Level::Info),
+            "warning" | "warn" => Some(Level::Warning),
+            "error" => Some(Level::Error),
+            "off" => Some(Level::Off),
+            _ => None,
+        }
+    }
+
+    fn from_u8(value: u8) -> Level {
+        match value {
+            1 => Level::Debug,
+            2 => Level::Info,
+            3 => Level::Warning,
+            4 => Level::Error,
+            _ => Level::Off,
+        }
+    }
+}
+
+/// Color coded, timestamped output to STDOUT (debug and info) and
+/// STDERR (warnings and errors).
+pub struct Terminal;
 
-pub const DEBUG: (i32, &str, AnsiColor) = (1, "Debug", AnsiColor::BrightBlack);
-pub const ERROR: (i32, &str, AnsiColor) = (4, "Error", AnsiColor::BrightRed);
-pub const INFO: (i32, &str, AnsiColor) = (2, "Info", AnsiColor::BrightBlue);
-pub const WARNING: (i32, &str, AnsiColor) = (3, "Warning", AnsiColor::BrightYellow);
+impl Sink for Terminal {
+    fn log(&self, level: Level, msg: &str) {
+        let line: String = format!(
+            "[{}][{}] {}",
+            get_timestamp(),
+            colorize(level.name(), level.color()),
+            msg
+        );
 
-fn send_msg(log_level: &str, msg: &str) -> String {
-    let timestamp = get_timestamp();
-    let prefix = match log_level.to_ascii_lowercase().as_str() {
-        "debug" => &colorize(DEBUG.1, DEBUG.2),
-        "error" => &colorize(ERROR.1, ERROR.2),
-        "info" => &colorize(INFO.1, INFO.2),
-        "warning" => &colorize(WARNING.1, WARNING.2),
-        _ => "Unknown",
-    };
+        if level >= Level::Warning {
+            eprintln!("{}", line);
+        } else {
+            println!("{}", line);
+        }
+    }
+}
+
+/// Forwards messages to the `log` crate under the `eaql` target.
+#[cfg(feature = "log")]
+pub struct Log;
+
+#[cfg(feature = "log")]
+impl Sink for Log {
+    fn log(&self, level: Level, msg: &str) {
+        let level: log::Level = match level {
+            Level::Debug => log::Level::Debug,
+            Level::Info => log::Level::Info,
+            Level::Warning => log::Level::Warn,
+            Level::Error | Level::Off => log::Level::Error,
+        };
+
+        log::log!(target: "eaql", level, "{}", msg);
+    }
+}
 
-    format!("[{timestamp}][{prefix}] {msg}")
+/// Forwards messages to `tracing` as events under the `eaql` target.
+#[cfg(feature = "tracing")]
+pub struct Tracing;
+
+#[cfg(feature = "tracing")]
+impl Sink for Tracing {
+    fn log(&self, level: Level, msg: &str) {
+        match level {
+            Level::Debug => tracing::debug!(target: "eaql", "{}", msg),
+            Level::Info => tracing::info!(target: "eaql", "{}", msg),
+            Level::Warning => tracing::warn!(target: "eaql", "{}", msg),
+            Level::Error | Level::Off => tracing::error!(target: "eaql", "{}", msg),
+        }
+    }
+}
+
+/// Sets the lowest level of message that gets output.
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    Level::from_u8(LEVEL.load(Ordering::Relaxed))
+}
+
+/// Installs the sink messages are sent to, replacing the current one.
+pub fn set_sink(sink: impl Sink + 'static) {
+    if let Ok(mut current) = SINK.write() {
+        *current = Some(Box::new(sink));
+    }
+}
+
+/// Removes the current sink, silencing the logger.
+pub fn clear_sink() {
+    if let Ok(mut current) = SINK.write() {
+        *current = None;
+    }
+}
+
+/// Whether a message of the given level would be output, useful to
+/// skip building expensive messages.
+pub fn enabled(level: Level) -> bool {
+    level != Level::Off && level >= self::level() && SINK.read().is_ok_and(|sink| sink.is_some())
+}
+
+fn send_msg(level: Level, msg: &str) {
+    if level == Level::Off || level < self::level() {
+        return;
+    }
+
+    if let Ok(sink) = SINK.read()
+        && let Some(sink) = sink.as_ref()
+    {
+        sink.log(level, msg);
+    }
 }
 
 fn get_timestamp() -> String {
@@ -29,23 +173,58 @@ fn get_timestamp() -> String {
 }
 
 pub fn debug(msg: &str) -> () {
-    if TEST_MODE && LOG_LEVEL <= DEBUG.0 {
-        println!("{}", send_msg("debug", msg));
-    }
+    send_msg(Level::Debug, msg);
 }
 
-pub fn _info(msg: &str) -> () {
-    if TEST_MODE && LOG_LEVEL <= INFO.0 {
-        println!("{}", send_msg("info", msg));
-    }
+pub fn info(msg: &str) -> () {
+    send_msg(Level::Info, msg);
 }
 
 pub fn warning(msg: &str) -> () {
-    if TEST_MODE && LOG_LEVEL <= WARNING.0 {
-        eprintln!("{}", send_msg("warning", msg));
-    }
+    send_msg(Level::Warning, msg);
+}
+
+pub fn error(msg: &str) -> () {
+    send_msg(Level::Error, msg);
 }
 
-pub fn error(msg: &str) -> ! {
-    panic!("{}", send_msg("error", msg));
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    struct Capture(Arc<Mutex<Vec<(Level, String)>>>);
+
+    impl Sink for Capture {
+        fn log(&self, level: Level, msg: &str) {
+            self.0.lock().unwrap().push((level, msg.to_string()));
+        }
+    }
+
+    #[test]
+    fn unit_test_logger_levels_and_sinks() {
+        let captured: Arc<Mutex<Vec<(Level, String)>>> = Arc::new(Mutex::new(vec![]));
+
+        set_sink(Capture(captured.clone()));
+        set_level(Level::Info);
+        debug("hidden");
+        info("shown");
+        assert!(enabled(Level::Error));
+        assert!(!enabled(Level::Debug));
+
+        set_level(Level::Off);
+        error("hidden");
+
+        clear_sink();
+        set_level(Level::Warning);
+        warning("hidden");
+        assert!(!enabled(Level::Warning));
+
+        // Other tests may log at the same time, so only look for ours
+        let captured: Vec<(Level, String)> = captured.lock().unwrap().clone();
+
+        assert!(captured.contains(&(Level::Info, "shown".to_string())));
+        assert!(!captured.iter().any(|(_, msg)| msg == "hidden"));
+        assert_eq!(Level::from_name("WARN"), Some(Level::Warning));
+    }
 }
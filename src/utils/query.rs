@@ -1,47 +1,221 @@
 use crate::{
     language::{
-        lexer::{self, Lexer},
-        parser::parser::{self, Query},
-        tokens::{self},
+        errors::{EaqlError, ErrorContext, Warning},
+        lexer,
+        parser::parser::{self, PartialQuery, Query},
+        tokens::{self, Span, Token},
     },
-    utils::logger,
+    utils::logger::{self, Level},
 };
 
-// Process query to low level components (parsed)
-pub fn process_query(query: &String) -> Option<Query> {
-    // Tokenize input
-    let tokenized: Result<Lexer, String> = lexer::scan_tokens(&query);
+// Process query to low level components (parsed), warnings are logged
+pub fn process_query(query: &String) -> Result<Query, EaqlError> {
+    let (ast, warnings): (Query, Vec<Warning>) = process_query_with_warnings(query)?;
 
-    let tokens = match tokenized {
-        Ok(tokenized) => {
-            logger::debug(&format!("Tokenized String -> \n{tokenized}"));
-            tokenized.tokens
-        }
-        Err(e) => {
-            logger::warning(&e);
-            return None;
+    for warning in warnings {
+        logger::warning(warning.message());
+    }
+
+    Ok(ast)
+}
+
+// Process query to low level components (parsed) alongside any warnings
+pub fn process_query_with_warnings(query: &String) -> Result<(Query, Vec<Warning>), EaqlError> {
+    let tokens: Vec<Token> = tokenize(query)?;
+
+    let ast: Query = process_tokens(&tokens)?;
+    let warnings: Vec<Warning> = ast.warnings();
+
+    return Ok((ast, warnings));
+}
+
+// Process a script of one or more queries to low level components (parsed),
+// warnings are logged
+pub fn process_script(query: &String) -> Result<Vec<Query>, Vec<EaqlError>> {
+    let (queries, warnings): (Vec<Query>, Vec<Warning>) = process_script_with_warnings(query)?;
+
+    for warning in warnings {
+        logger::warning(warning.message());
+    }
+
+    Ok(queries)
+}
+
+// Process a script of one or more queries to low level components (parsed)
+// alongside any warnings. A query failing to parse doesn't stop the ones
+// after it from being parsed, every query's error is returned.
+pub fn process_script_with_warnings(
+    query: &String,
+) -> Result<(Vec<Query>, Vec<Warning>), Vec<EaqlError>> {
+    let tokens: Vec<Token> = tokenize(query).map_err(|err| vec![err])?;
+
+    // An empty script fails the same way an empty query would
+    if tokens.is_empty() {
+        return process_tokens(&tokens)
+            .map(|ast| (vec![ast], vec![]))
+            .map_err(|err| vec![err]);
+    }
+
+    let mut queries: Vec<Query> = vec![];
+    let mut warnings: Vec<Warning> = vec![];
+    let mut errors: Vec<EaqlError> = vec![];
+
+    // Each query runs up to and including its end-of-query token, so
+    // skipping to the next one recovers from any error in between
+    for statement in tokens.split_inclusive(|v| v.token_type == tokens::TokenType::EoqToken) {
+        match process_tokens(&statement.to_vec()) {
+            Ok(ast) => {
+                warnings.extend(ast.warnings());
+                queries.push(ast);
+            }
+            Err(err) => errors.push(err),
         }
-    };
+    }
 
-    // This will go once we see the ability for mutliple queries chained together
-    if tokens.len() != 0 && tokens[tokens.len() - 1].token_type != tokens::TokenType::EoqToken {
-        logger::warning("Missing end of query delimiter!");
-        return None;
+    if !errors.is_empty() {
+        return Err(errors);
     }
 
-    // Parse into an Abstract Syntax Tree
-    let parsed: Result<parser::Query, String> = parser::parse(&tokens);
+    Ok((queries, warnings))
+}
 
-    let ast = match parsed {
-        Ok(parsed) => {
-            logger::debug(&format!("Abstract Syntax Tree -> \n{parsed}"));
-            parsed
+// Process query to whatever could be parsed of it, alongside every
+// error found in it rather than just the first
+pub fn process_query_recovering(query: &String) -> (PartialQuery, Vec<EaqlError>) {
+    let mut tokens: Vec<Token> = match tokenize(query) {
+        Ok(tokens) => tokens,
+        Err(err) => return (PartialQuery::Empty, vec![err]),
+    };
+    let mut errors: Vec<EaqlError> = vec![];
+
+    // An unknown token ending the query (i.e. an unterminated string)
+    // swallowed whatever came after it, so the query ending early is
+    // down to that token rather than more errors of its own
+    let swallowed: bool = tokens
+        .last()
+        .is_some_and(|v| v.token_type == tokens::TokenType::UnknownToken);
+
+    // Unknown tokens are left out so the rest of the query can still be parsed
+    tokens.retain(|v| match v.token_type {
+        tokens::TokenType::UnknownToken => {
+            errors.push(unknown_token(v));
+            false
         }
-        Err(e) => {
-            logger::warning(&e);
-            return None;
+        _ => true,
+    });
+
+    // Parse as if the query had been ended, so what's there is still checked
+    let end: usize = tokens.last().map_or(0, |v| v.span.end);
+
+    if let Some(last) = tokens
+        .last()
+        .filter(|v| v.token_type != tokens::TokenType::EoqToken)
+    {
+        if !swallowed {
+            errors.push(missing_end_of_query(last));
         }
-    };
 
-    return Some(ast);
+        let mut end: Token =
+            Token::new(tokens::TokenType::EoqToken, &String::new(), &String::new());
+        end.span = Span::new(last.span.end, last.span.end);
+        end.position = last.position.advance(&last.lexeme);
+
+        tokens.push(end);
+    }
+
+    if let Some(next) = following_query(&tokens) {
+        errors.push(another_query(next));
+
+        // Only the first query is parsed
+        let end: usize = tokens
+            .iter()
+            .position(|v| v.token_type == tokens::TokenType::EoqToken)
+            .map_or(tokens.len(), |idx| idx + 1);
+        tokens.truncate(end);
+    }
+
+    let (partial, parse_errors): (PartialQuery, Vec<EaqlError>) = parser::parse_recovering(&tokens);
+    errors.extend(
+        parse_errors
+            .into_iter()
+            .filter(|err| !swallowed || err.span().is_some_and(|span| span.start < end)),
+    );
+
+    (partial, errors)
+}
+
+// Tokenize input
+fn tokenize(query: &String) -> Result<Vec<Token>, EaqlError> {
+    match lexer::scan_tokens(query) {
+        Ok(tokenized) => {
+            if logger::enabled(Level::Debug) {
+                logger::debug(&format!("Tokenized String -> \n{tokenized}"));
+            }
+
+            Ok(tokenized.tokens)
+        }
+        Err(e) => Err(EaqlError::UnknownToken(Box::new(ErrorContext::new(e)))),
+    }
+}
+
+// Parse the tokens of a single query
+fn process_tokens(tokens: &Vec<Token>) -> Result<Query, EaqlError> {
+    if let Some(unknown) = tokens
+        .iter()
+        .find(|v| v.token_type == tokens::TokenType::UnknownToken)
+    {
+        return Err(unknown_token(unknown));
+    }
+
+    if let Some(last) = tokens
+        .last()
+        .filter(|v| v.token_type != tokens::TokenType::EoqToken)
+    {
+        return Err(missing_end_of_query(last));
+    }
+
+    if let Some(next) = following_query(tokens) {
+        return Err(another_query(next));
+    }
+
+    // Parse into an Abstract Syntax Tree
+    let ast: Query = parser::parse(tokens)?;
+
+    if logger::enabled(Level::Debug) {
+        logger::debug(&format!("Abstract Syntax Tree -> \n{ast}"));
+    }
+
+    Ok(ast)
+}
+
+// The first token of any query following the first one
+fn following_query(tokens: &Vec<Token>) -> Option<&Token> {
+    tokens
+        .iter()
+        .position(|v| v.token_type == tokens::TokenType::EoqToken)
+        .and_then(|idx| tokens.get(idx + 1))
+}
+
+fn unknown_token(token: &Token) -> EaqlError {
+    EaqlError::UnknownToken(Box::new(ErrorContext::at(
+        format!("Couldn't make sense of \"{}\"!", token.lexeme),
+        token,
+    )))
+}
+
+fn missing_end_of_query(last: &Token) -> EaqlError {
+    EaqlError::MissingEndOfQuery(Box::new(
+        ErrorContext::after("Missing end of query delimiter!".to_string(), last)
+            .with_hint("end the query with `;`, `.` or `!`"),
+    ))
+}
+
+fn another_query(next: &Token) -> EaqlError {
+    EaqlError::Unsupported(Box::new(
+        ErrorContext::at(
+            "Expected a single query, but another one follows it!".to_string(),
+            next,
+        )
+        .with_hint("scripts of more than one query are parsed with `ast::parse_script`"),
+    ))
 }
@@ -1,25 +1,49 @@
-use crate::utils::{
-    colors::{AnsiColor, colorize},
-    io,
-    query::process_query,
+use crate::{
+    language::{
+        diagnostics,
+        errors::{EaqlError, Warning},
+        parser::parser::{PartialQuery, Query},
+    },
+    utils::{
+        colors::{AnsiColor, colorize},
+        io::{self, Format},
+        query::{process_query, process_query_recovering, process_query_with_warnings},
+    },
 };
 
 /// Starts a Validator loop that accepts queries from STDIN
 /// and validates queries while outputting error information
-pub fn repl_loop() {
+pub fn repl_loop(format: Format) {
     loop {
         // Get input
-        let query: String = io::query_stdin("validator");
-
-        match process_query(&query) {
-            Some(_) => println!("{}", colorize("Valid query!", AnsiColor::BrightGreen)),
-            None => println!(
-                "{}",
-                colorize(
-                    "Invalid query, see above warnings for issues!",
-                    AnsiColor::BrightRed
+        let Some(query) = io::query_stdin("validator") else {
+            println!();
+            return;
+        };
+
+        match process_query_with_warnings(&query) {
+            Ok((parsed, warnings)) => {
+                for warning in warnings {
+                    eprintln!("{}", diagnostics::render_warning_color(&warning, &query));
+                }
+
+                if !io::print_ast(format, &parsed) {
+                    println!("{}", colorize("Valid query!", AnsiColor::BrightGreen))
+                }
+            }
+            Err(_) => {
+                // Show every problem at once rather than just the first
+                let (_, errors): (PartialQuery, Vec<EaqlError>) = process_query_recovering(&query);
+
+                for err in errors {
+                    eprintln!("{}", diagnostics::render_color(&err, &query));
+                }
+
+                println!(
+                    "{}",
+                    colorize("Invalid query, see above for issues!", AnsiColor::BrightRed)
                 )
-            ),
+            }
         };
     }
 }
@@ -28,14 +52,51 @@ pub fn repl_loop() {
 ///
 /// # Example
 /// ```
-/// use eaql::validator::engine;
-/// assert_eq!(engine(&"Get everything from db_1!"), true);
-/// assert_eq!(engine(&"Get everything from db_1"), false);
+/// use eaql::{language::errors::EaqlError, validator::engine};
+/// assert_eq!(engine(&"Get everything from db_1!"), Ok(()));
+/// assert!(matches!(
+///     engine(&"Get everything from db_1"),
+///     Err(EaqlError::MissingEndOfQuery(_))
+/// ));
 /// ```
 ///
-pub fn engine(query: &str) -> bool {
-    match process_query(&query.to_string()) {
-        Some(_) => true,
-        None => false,
-    }
+pub fn engine(query: &str) -> Result<(), EaqlError> {
+    process_query(&query.to_string())?;
+
+    Ok(())
+}
+
+/// Validate Input Query (String), returning the warnings found in
+/// an otherwise valid query
+///
+/// # Example
+/// ```
+/// use eaql::validator::engine_with_warnings;
+/// let warnings = engine_with_warnings(&"Get name and name from drinks!").unwrap();
+/// assert_eq!(warnings[0].message(), "Column \"name\" is requested more than once.");
+/// ```
+///
+pub fn engine_with_warnings(query: &str) -> Result<Vec<Warning>, EaqlError> {
+    let (_, warnings): (Query, Vec<Warning>) = process_query_with_warnings(&query.to_string())?;
+
+    Ok(warnings)
+}
+
+/// Validate Input Query (String), returning every error in the query
+/// rather than just the first, alongside whatever could be parsed of
+/// it. A valid query has no errors.
+///
+/// # Example
+/// ```
+/// use eaql::{language::ast::PartialQuery, validator::engine_recovering};
+/// let (partial, errors) = engine_recovering(&"Get from drinks where price <!");
+/// assert_eq!(errors.len(), 2);
+///
+/// let PartialQuery::Get(get) = partial else { unreachable!() };
+/// assert_eq!(get.table().map(|v| v.name()), Some("drinks"));
+/// assert!(get.columns().is_none());
+/// ```
+///
+pub fn engine_recovering(query: &str) -> (PartialQuery, Vec<EaqlError>) {
+    process_query_recovering(&query.to_string())
 }
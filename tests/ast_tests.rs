@@ -0,0 +1,427 @@
+use eaql::language::ast::{
+    ComparisonOperator, ConditionChild, DatabaseOperation, LogicalOperator, SortDirection,
+    Statement, Value, parse,
+};
+
+// Get Query Tests (AST)
+#[test]
+fn ast_integration_test_get_accessors() {
+    let query = parse(
+        "get name and price from drinks where price < 5 or size is \"large\" \
+then sort by name descending and limit it to 10;",
+    )
+    .unwrap();
+
+    let Statement::Get(get) = query.statement() else {
+        panic!("Expected a get statement");
+    };
+
+    assert_eq!(get.table().name(), "drinks");
+    assert_eq!(get.columns().names(), ["name", "price"]);
+    assert!(!get.columns().is_wildcard());
+    assert_eq!(get.limit().map(|v| v.value()), Some(10));
+
+    let sort = get.sort().unwrap();
+
+    assert_eq!(sort.keys()[0].column(), "name");
+    assert_eq!(sort.keys()[0].direction(), SortDirection::Descending);
+
+    let ConditionChild::Op(or) = get.filter().unwrap().condition().condition() else {
+        panic!("Expected an operand at the root of the condition");
+    };
+
+    assert_eq!(or.operator(), LogicalOperator::Or);
+
+    // Expressions sit on the left of "AND" nodes padded with booleans
+    let ConditionChild::Op(and) = or.left() else {
+        panic!("Expected an operand on the left");
+    };
+    let ConditionChild::Expr(expression) = and.left() else {
+        panic!("Expected an expression on the left");
+    };
+
+    assert_eq!(expression.column(), "price");
+    assert_eq!(expression.operator(), ComparisonOperator::LessThan);
+    assert_eq!(expression.value(), Value::Number("5"));
+}
+
+#[test]
+fn ast_integration_test_get_wildcard() {
+    let query = parse("get everything from drinks;").unwrap();
+
+    let Statement::Get(get) = query.into_statement() else {
+        panic!("Expected a get statement");
+    };
+
+    assert!(get.columns().is_wildcard());
+    assert!(get.columns().names().is_empty());
+    assert!(get.filter().is_none());
+    assert!(get.postprocessor().is_none());
+}
+
+// Database Query Tests (AST)
+#[test]
+fn ast_integration_test_database_accessors() {
+    let query = parse("delete databases shop and stock;").unwrap();
+
+    let Statement::Database(database) = query.statement() else {
+        panic!("Expected a database statement");
+    };
+    let Some(DatabaseOperation::Destroy(destroy)) = database.operation() else {
+        panic!("Expected a destroy operation");
+    };
+
+    assert_eq!(destroy.names(), ["shop", "stock"]);
+
+    let query = parse("use database shop;").unwrap();
+
+    assert!(matches!(
+        query.statement(),
+        Statement::Database(database)
+            if matches!(database.operation(), Some(DatabaseOperation::Use(node)) if node.name() == "shop")
+    ));
+}
+
+#[test]
+fn ast_integration_test_parse_error() {
+    assert!(parse("get name from drinks").is_err());
+}
+
+// Serialization Tests (AST)
+#[cfg(feature = "serde")]
+#[test]
+fn ast_integration_test_json_round_trip() {
+    use eaql::language::ast::{from_json, to_json};
+    use eaql::transpiler::dialect::DIALECTS;
+
+    let queries = [
+        "get everything from drinks;",
+        "get name, price and size from drinks where price < 5 or not (size is \"large\" and price >= 2) \
+then sort by name descending, price and limit it to 10;",
+        "get name from drinks where name is not \"tea\" then limit it to 0;",
+        "get name from drinks where (price + tax) * -2 > cost or not -(a / b) = c;",
+        "get name from drinks where size is empty or name contains \"latte\" ignoring case \
+and id is not one of (1, 2) and price is between -2 and 5.5 and iced is not false;",
+        "create database shop;",
+        "delete databases shop and stock;",
+        "use database shop;",
+        "show databases;",
+    ];
+
+    for query in queries {
+        let parsed = parse(query).unwrap();
+        let json = to_json(&parsed).unwrap();
+        let deserialized = from_json(&json).unwrap();
+
+        assert_eq!(to_json(&deserialized).unwrap(), json);
+        assert_eq!(deserialized.to_string(), parsed.to_string());
+
+        for dialect in DIALECTS {
+            assert_eq!(
+                deserialized.transpile_raw(*dialect),
+                parsed.transpile_raw(*dialect)
+            );
+            assert_eq!(
+                deserialized.transpile_color(*dialect),
+                parsed.transpile_color(*dialect)
+            );
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn ast_integration_test_json_rejects_injection() {
+    use eaql::language::{
+        ast::{from_json, to_json},
+        errors::EaqlError,
+    };
+
+    let json =
+        to_json(&parse("get name from drinks where price = 5 and size < 2 * 3;").unwrap()).unwrap();
+    let cases = [
+        // A number that isn't one
+        json.replacen("\"5\"", "\"5; DROP TABLE drinks; --\"", 2),
+        // An operator a comparison is never parsed with
+        json.replacen("\"Equal\"", "\"Get\"", 1),
+        json.replacen("\"Lt\"", "\"Contains\"", 1),
+        json.replacen("\"Star\"", "\"Lt\"", 1),
+        // A column that isn't a single name
+        json.replacen("\"price\"", "\"price = 1 or 1\"", 2),
+    ];
+
+    for case in cases {
+        assert_ne!(case, json);
+        assert!(
+            matches!(
+                from_json(&case),
+                Err(EaqlError::InvalidValue(_) | EaqlError::UnexpectedToken(_))
+            ),
+            "{}",
+            case
+        );
+    }
+
+    assert!(matches!(
+        from_json("{\"not\": \"a query\"}"),
+        Err(EaqlError::InvalidValue(_))
+    ));
+}
+
+// Builder Tests (AST)
+#[test]
+fn ast_integration_test_builder_matches_parser() {
+    use eaql::language::{
+        ast::{Query, check},
+        builder::{Expr, col},
+    };
+    use eaql::transpiler::dialect::DIALECTS;
+
+    let pairs = [
+        (
+            Query::get_all().from("drinks").build().unwrap(),
+            "get everything from drinks;",
+        ),
+        (
+            Query::get(["name", "price"])
+                .from("drinks")
+                .filter(col("price").lt(5))
+                .limit(10)
+                .build()
+                .unwrap(),
+            "get name, price from drinks where price < 5 then limit it to 10;",
+        ),
+        (
+            Query::get(["name", "price", "size"])
+                .from("drinks")
+                .filter(
+                    col("price")
+                        .lt(5)
+                        .or(!(col("size").eq("large").and(col("price").gte(2)))),
+                )
+                .sort_by("name", SortDirection::Descending)
+                .sort_by("price", SortDirection::Ascending)
+                .limit(10)
+                .build()
+                .unwrap(),
+            "get name, price and size from drinks where price < 5 or not (size is \"large\" and price >= 2) \
+then sort by name descending, price and limit it to 10;",
+        ),
+        (
+            Query::get(["name"])
+                .from("drinks")
+                .filter(col("name").ne("tea"))
+                .filter(col("price").gt(-1.5).or(col("price").lte(0)))
+                .build()
+                .unwrap(),
+            "get name from drinks where name is not \"tea\" and (price > -1.5 or price <= 0);",
+        ),
+        (
+            Query::get(["name"])
+                .from("drinks")
+                .filter(
+                    col("name").contains("latte").ignoring_case().or(col("size")
+                        .like("l%")
+                        .ignoring_case()
+                        .and(col("code").ends_with("_1"))),
+                )
+                .build()
+                .unwrap(),
+            "get name from drinks where name contains \"latte\" ignoring case \
+or size like \"l%\" ignoring case and code ends with \"_1\";",
+        ),
+        (
+            Query::get(["name"])
+                .from("drinks")
+                .filter(
+                    col("size")
+                        .one_of(["small", "medium"])
+                        .and(col("id").not_one_of([1, 2]))
+                        .and(
+                            col("price")
+                                .between(2, 5.5)
+                                .or(col("price").not_between(8, 9)),
+                        ),
+                )
+                .build()
+                .unwrap(),
+            "get name from drinks where size is one of (\"small\", \"medium\") and id is not one of (1 or 2) \
+and (price is between 2 and 5.5 or price is not between 8 and 9);",
+        ),
+        (
+            Query::get(["name"])
+                .from("drinks")
+                .filter(
+                    col("size")
+                        .empty()
+                        .or(col("name").not_empty().and(col("iced").ne(false))),
+                )
+                .build()
+                .unwrap(),
+            "get name from drinks where size is empty or name is not null and iced is not false;",
+        ),
+        (
+            Query::get(["name"])
+                .from("drinks")
+                .filter(
+                    ((col("price") + col("tax")) * 2)
+                        .gt(col("budget") - -Expr::from(1.5))
+                        .and(col("size").lte(col("width") / -col("height")))
+                        .or(Expr::from(5).eq(col("price"))),
+                )
+                .build()
+                .unwrap(),
+            "get name from drinks where (price + tax) * 2 > budget - -(1.5) and size <= width / -height \
+or 5 is price;",
+        ),
+    ];
+
+    for (built, query) in pairs {
+        let parsed = parse(query).unwrap();
+
+        assert_eq!(check(&built), Ok(()));
+        assert_eq!(check(&parsed), Ok(()));
+        assert_eq!(built.warnings(), parsed.warnings());
+
+        for dialect in DIALECTS {
+            assert_eq!(
+                built.transpile_raw(*dialect),
+                parsed.transpile_raw(*dialect)
+            );
+        }
+    }
+}
+
+#[test]
+fn ast_integration_test_builder_errors() {
+    use eaql::language::{
+        ast::{Case, ComparisonOperator, Query},
+        builder::{Condition, Literal, col},
+    };
+
+    assert!(Query::get(["name"]).build().is_err());
+    assert!(Query::get(["name"]).from("from").build().is_err());
+    assert!(Query::get(["first name"]).from("drinks").build().is_err());
+    assert!(
+        Query::get(Vec::<&str>::new())
+            .from("drinks")
+            .build()
+            .is_err()
+    );
+    assert!(
+        Query::get_all()
+            .from("drinks")
+            .filter(col("size").eq("\"large\""))
+            .build()
+            .is_err()
+    );
+    assert!(
+        Query::get_all()
+            .from("drinks")
+            .filter(col("price").lt(f64::INFINITY))
+            .build()
+            .is_err()
+    );
+    assert!(
+        Query::get_all()
+            .from("drinks")
+            .filter(Condition::Compare(
+                "price".to_string(),
+                ComparisonOperator::Contains(Case::Sensitive),
+                Literal::Number("5".to_string())
+            ))
+            .build()
+            .is_err()
+    );
+    assert!(
+        Query::get_all()
+            .from("drinks")
+            .filter(col("size").one_of(Vec::<&str>::new()))
+            .build()
+            .is_err()
+    );
+    assert!(
+        Query::get_all()
+            .from("drinks")
+            .filter(col("price").gt(Literal::Null))
+            .build()
+            .is_err()
+    );
+    assert!(
+        Query::get_all()
+            .from("drinks")
+            .filter(col("size").one_of([Literal::Null]))
+            .build()
+            .is_err()
+    );
+    assert!(
+        Query::get_all()
+            .from("drinks")
+            .filter((col("price") + true).gt(5))
+            .build()
+            .is_err()
+    );
+    assert!(
+        Query::get_all()
+            .from("drinks")
+            .filter(col("price").eq(col("where") * 2))
+            .build()
+            .is_err()
+    );
+}
+
+// Script Tests (AST)
+#[test]
+fn ast_integration_test_script_normal() {
+    use eaql::language::{ast::parse_script, tokens::Spanned};
+
+    let script = "create database shop;\nuse database shop.\nget name from drinks where price < 5!";
+    let queries = parse_script(script).unwrap();
+
+    assert_eq!(queries.len(), 3);
+    assert!(matches!(queries[0].statement(), Statement::Database(_)));
+    assert!(matches!(queries[2].statement(), Statement::Get(_)));
+
+    // Spans point into the script, not just the query they're in
+    let spans: Vec<&str> = queries
+        .iter()
+        .map(|v| &script[v.span().start..v.span().end])
+        .collect();
+
+    assert_eq!(
+        spans,
+        vec![
+            "create database shop",
+            "use database shop",
+            "get name from drinks where price < 5"
+        ]
+    );
+    assert_eq!(parse_script("get name from drinks;").unwrap().len(), 1);
+}
+
+#[test]
+fn ast_integration_test_script_error() {
+    use eaql::language::{ast::parse_script, errors::EaqlError};
+
+    let script = "get from drinks; show databases; get name drinks; get all from drinks where name is \"latte;";
+    let errors = parse_script(script).unwrap_err();
+
+    // Every broken query is reported, the valid one in between doesn't hide them
+    assert_eq!(errors.len(), 3);
+    assert!(matches!(errors[0], EaqlError::UnexpectedToken(_)));
+    assert!(matches!(errors[1], EaqlError::UnexpectedToken(_)));
+    assert!(matches!(errors[2], EaqlError::UnknownToken(_)));
+    let span = errors[1].span().unwrap();
+
+    assert_eq!(&script[span.start..span.end], "drinks");
+
+    assert!(matches!(
+        parse_script("show databases; get all from drinks").unwrap_err()[..],
+        [EaqlError::MissingEndOfQuery(_)]
+    ));
+    assert!(parse_script("").is_err());
+    assert!(matches!(
+        parse("get name from drinks; show databases;"),
+        Err(EaqlError::Unsupported(_))
+    ));
+}
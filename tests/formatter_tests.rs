@@ -0,0 +1,194 @@
+use eaql::{
+    formatter::{Style, engine, engine_with_style},
+    language::ast::parse,
+    transpiler::dialect::DIALECTS,
+};
+
+// Canonical Output Tests (Formatter)
+#[test]
+fn formatter_integration_test_get_normal() {
+    let pairs = [
+        ("retrieve all from drinks!", "Get everything from drinks."),
+        (
+            "find the name, price from drinks wherever price < 5 after limit 10;",
+            "Get name and price from drinks where price is less than 5 then limit it to 10.",
+        ),
+        (
+            "get name, price and size from drinks where price < 5 or not (size = \"large\" and price >= 2) \
+then limit it to 10 and sort by name in descending order, price ascending;",
+            "Get name, price and size from drinks where price is less than 5 or not (size is \"large\" and price is at least 2) \
+then sort by name descending and price and limit it to 10.",
+        ),
+        (
+            "get name from drinks where name != \"tea\" and ((price <> 1.5));",
+            "Get name from drinks where name is not \"tea\" and price is not 1.5.",
+        ),
+        (
+            "get * from drinks where ((price plus tax)) times 2 != cost minus -1;",
+            "Get everything from drinks where (price + tax) * 2 is not equal to cost - -1.",
+        ),
+        (
+            "get all from drinks where size = empty or size != missing and price = above;",
+            "Get everything from drinks where size is equal to empty or size is not equal to missing \
+and price is equal to above.",
+        ),
+    ];
+
+    for (query, expected) in pairs {
+        assert_eq!(engine(query), Ok(expected.to_string()));
+    }
+}
+
+#[test]
+fn formatter_integration_test_database_normal() {
+    let pairs = [
+        ("make database shop;", "Create database shop."),
+        (
+            "remove databases shop, stock and old;",
+            "Delete databases shop, stock and old.",
+        ),
+        ("destroy databases shop;", "Delete database shop."),
+        ("enter database shop;", "Use database shop."),
+        ("list databases;", "Show databases."),
+    ];
+
+    for (query, expected) in pairs {
+        assert_eq!(engine(query), Ok(expected.to_string()));
+    }
+}
+
+#[test]
+fn formatter_integration_test_style() {
+    let mut style = Style::default();
+
+    for keyword in ["retrieve", "any", "whenever", "after", "list", "!"] {
+        style.prefer(keyword).unwrap();
+    }
+
+    style.capitalize(false);
+
+    assert_eq!(
+        engine_with_style(
+            "get everything from drinks where price < 5 then limit it to 1;",
+            &style
+        ),
+        Ok(
+            "retrieve any from drinks whenever price is less than 5 after limit it to 1!"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        engine_with_style("show databases;", &style),
+        Ok("list databases!".to_string())
+    );
+
+    assert!(style.prefer("and").is_err());
+    assert!(style.prefer("drinks").is_err());
+    assert!(style.prefer("get all").is_err());
+}
+
+#[test]
+fn formatter_integration_test_error() {
+    assert!(engine("get name from drinks").is_err());
+}
+
+// Round Trip Tests (Formatter)
+#[test]
+fn formatter_integration_test_round_trip() {
+    let pairs = [
+        ("get everything from drinks;", "Get everything from drinks."),
+        (
+            "get name, price and size from drinks where price < 5 or not (size is \"large\" and price >= 2) \
+then sort by name descending, price and limit it to 10;",
+            "Get name, price and size from drinks where price is less than 5 or not (size is \"large\" and price is at least 2) \
+then sort by name descending and price and limit it to 10.",
+        ),
+        (
+            "get a from b where a = 1 and b = 2 or c = 3 and not (d = 4 or e = -1.5);",
+            "Get a from b where a is 1 and b is 2 or c is 3 and not (d is 4 or e is -1.5).",
+        ),
+        (
+            "get a from b where (a = 1 or b = 2) and not c = 3 then limit it to 0;",
+            "Get a from b where (a is 1 or b is 2) and not c is 3 then limit it to 0.",
+        ),
+        (
+            "get a from b where ((a = 1)) and (b = 2 or (c = 3 and d = 4));",
+            "Get a from b where a is 1 and (b is 2 or c is 3 and d is 4).",
+        ),
+        (
+            "get a from b where a contains \"x\" ignoring case or not (b begins with \"y\" and c is like \"%z\\_\");",
+            "Get a from b where a contains \"x\" ignoring case or not (b starts with \"y\" and c like \"%z\\_\").",
+        ),
+        (
+            "get a from b where a is any of (1, 2 and 3) and b is not between \"a\" and \"m\" or c between 1 and 2;",
+            "Get a from b where a is one of (1, 2, 3) and b is not between \"a\" and \"m\" or c is between 1 and 2.",
+        ),
+        (
+            "get a from b where a is empty or b is not nothing and (c = true or d is not False);",
+            "Get a from b where a is null or b is not null and (c is true or d is not false).",
+        ),
+        (
+            "get a from b where a > b and not (a - (b - c)) / 2 <= - -c or (a times b = 1);",
+            "Get a from b where a is greater than b and not (a - (b - c)) / 2 is at most -(-c) or a * b is equal to 1.",
+        ),
+        (
+            "get a from b where a = empty or b != missing and c = above or d is empty;",
+            "Get a from b where a is equal to empty or b is not equal to missing and c is equal to above or d is null.",
+        ),
+        (
+            "get a from b where a = 1 and (b = 2) or c = 3;",
+            "Get a from b where a is 1 and b is 2 or c is 3.",
+        ),
+        (
+            "get a from b where a = 1 and (b = 2 or d = 4) or c = 3;",
+            "Get a from b where a is 1 and (b is 2 or d is 4) or c is 3.",
+        ),
+        ("create database shop;", "Create database shop."),
+        (
+            "delete databases shop and stock;",
+            "Delete databases shop and stock.",
+        ),
+        ("use database shop;", "Use database shop."),
+        ("show databases;", "Show databases."),
+    ];
+
+    for (query, expected) in pairs {
+        let parsed = parse(query).unwrap();
+        let formatted = parsed.to_eaql(&Style::default()).unwrap();
+        let reparsed = parse(&formatted).unwrap();
+
+        assert_eq!(formatted, expected);
+
+        // Formatting is canonical, so formatting again changes nothing
+        assert_eq!(reparsed.to_eaql(&Style::default()), Ok(formatted.clone()));
+        assert_eq!(
+            parse(&reparsed.to_eaql(&Style::default()).unwrap()).unwrap(),
+            reparsed
+        );
+
+        for dialect in DIALECTS {
+            assert_eq!(
+                reparsed.transpile_raw(*dialect),
+                parsed.transpile_raw(*dialect)
+            );
+        }
+    }
+}
+
+#[test]
+fn formatter_integration_test_round_trip_canonical() {
+    // Already canonical queries come back as the exact same tree
+    let queries = [
+        "Get name and price from drinks where price is less than 5 then limit it to 10.",
+        "Get a from b where a is 1 and b is 2 or c is 3 and not (d is 4 or e is -1.5).",
+        "Get a from b where (a is 1 or b is 2) and not c is 3 then sort by a descending and b.",
+        "Get a from b where a is at most 2 or b is greater than (c + 1) * -d and e is at least f.",
+    ];
+
+    for query in queries {
+        let parsed = parse(query).unwrap();
+        let reparsed = parse(&parsed.to_eaql(&Style::default()).unwrap()).unwrap();
+
+        assert_eq!(reparsed, parsed);
+    }
+}
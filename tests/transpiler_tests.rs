@@ -1,4 +1,10 @@
-use eaql::transpiler::engine;
+use eaql::language::errors::EaqlError;
+use eaql::transpiler::{
+    dialect::{Ansi, DIALECTS, Generic, MySql, Postgres, SqlServer, Sqlite},
+    engine, engine_parameterized, engine_with_dialect, engine_with_warnings,
+    parameters::BoundValue,
+    reverse,
+};
 
 // Database Query Tests (Validator)
 // Normal
@@ -157,14 +163,324 @@ fn transpile_integration_test_table_accessor_normal_filter() {
     // Test different conditionals
     assert_eq!(
         engine("get all from test_table where id = 3 and price = 2.0."),
-        Ok("SELECT * FROM test_table WHERE id = 3 and price = 2.0;".to_string())
+        Ok("SELECT * FROM test_table WHERE id = 3 AND price = 2.0;".to_string())
     );
     assert_eq!(
         engine("get all from test_table where id = 3 or (price <= 2 and name is \"3\")!"),
-        Ok("SELECT * FROM test_table WHERE id = 3 or (price <= 2 and name = \"3\");".to_string())
+        Ok("SELECT * FROM test_table WHERE id = 3 OR price <= 2 AND name = '3';".to_string())
     );
     assert_eq!(engine("get all from test_table where (price < 3 or name is \"test\" and (id = 3 or (value < 4 and time >= 5)));"),
-    Ok("SELECT * FROM test_table WHERE (price < 3 or name = \"test\" and (id = 3 or (value < 4 and time >= 5)));".to_string()));
+    Ok("SELECT * FROM test_table WHERE price < 3 OR name = 'test' AND (id = 3 OR value < 4 AND time >= 5);".to_string()));
+}
+
+#[test]
+fn transpile_integration_test_table_accessor_normal_filter_phrases() {
+    use eaql::language::tokens::{COMPARISON_PHRASES, TokenType};
+
+    // Test every phrase against the operator it stands for
+    for (phrase, token_type) in COMPARISON_PHRASES {
+        let (value, expression): (&str, &str) = match token_type {
+            TokenType::Gte => ("5", "price >= 5"),
+            TokenType::Lte => ("5", "price <= 5"),
+            TokenType::Gt => ("5", "price > 5"),
+            TokenType::Lt => ("5", "price < 5"),
+            TokenType::Equal => ("5", "price = 5"),
+            TokenType::NotEqual => ("5", "price <> 5"),
+            TokenType::StartsWith => ("\"5\"", "price LIKE '5%'"),
+            TokenType::EndsWith => ("\"5\"", "price LIKE '%5'"),
+            TokenType::Like => ("\"5\"", "price LIKE '5'"),
+            TokenType::OneOf => ("(5)", "price IN (5)"),
+            TokenType::NotOneOf => ("(5)", "price NOT IN (5)"),
+            TokenType::Between => ("5 and 6", "price BETWEEN 5 AND 6"),
+            TokenType::NotBetween => ("5 and 6", "price NOT BETWEEN 5 AND 6"),
+            TokenType::IsEmpty => ("", "price IS NULL"),
+            TokenType::IsNotEmpty => ("", "price IS NOT NULL"),
+            _ => unreachable!("Phrases are only comparisons"),
+        };
+
+        assert_eq!(
+            engine(&format!(
+                "get all from drinks where price {} {};",
+                phrase, value
+            )),
+            Ok(format!("SELECT * FROM drinks WHERE {};", expression)),
+            "{}",
+            phrase
+        );
+    }
+
+    // Test phrases as they'd be written
+    assert_eq!(
+        engine(
+            "get all from people where age Is Under 18 or name equals \"Bob\" \
+and count is no more than 3 and score exceeds 9;"
+        ),
+        Ok(
+            "SELECT * FROM people WHERE age < 18 OR name = 'Bob' AND count <= 3 AND score > 9;"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        engine("get all from drinks where price is\ngreater than 5 and size is not 2;"),
+        Ok("SELECT * FROM drinks WHERE price > 5 AND size <> 2;".to_string())
+    );
+}
+
+#[test]
+fn transpile_integration_test_table_accessor_normal_filter_patterns() {
+    // Test every kind of pattern
+    assert_eq!(
+        engine("get all from drinks where name contains \"latte\";"),
+        Ok("SELECT * FROM drinks WHERE name LIKE '%latte%';".to_string())
+    );
+    assert_eq!(
+        engine("get all from drinks where name starts with \"ice\" or name begins with \"cold\";"),
+        Ok("SELECT * FROM drinks WHERE name LIKE 'ice%' OR name LIKE 'cold%';".to_string())
+    );
+    assert_eq!(
+        engine("get all from drinks where name ends with \"brew\";"),
+        Ok("SELECT * FROM drinks WHERE name LIKE '%brew';".to_string())
+    );
+    assert_eq!(
+        engine("get all from drinks where name like \"%a_b%\" and not size is like \"l%\";"),
+        Ok("SELECT * FROM drinks WHERE name LIKE '%a_b%' AND NOT size LIKE 'l%';".to_string())
+    );
+
+    // Test wildcards in values being matched as written
+    assert_eq!(
+        engine("get all from deals where name contains \"50%_off\";"),
+        Ok("SELECT * FROM deals WHERE name LIKE '%50\\%\\_off%' ESCAPE '\\';".to_string())
+    );
+    assert_eq!(
+        engine("get all from deals where name like \"50\\%%\";"),
+        Ok("SELECT * FROM deals WHERE name LIKE '50\\%%' ESCAPE '\\';".to_string())
+    );
+
+    // Test matching ignoring case
+    assert_eq!(
+        engine(
+            "get all from drinks where name Contains \"Latte\" Ignoring Case then limit it to 5;"
+        ),
+        Ok("SELECT * FROM drinks WHERE LOWER(name) LIKE LOWER('%Latte%') LIMIT 5;".to_string())
+    );
+}
+
+#[test]
+fn transpile_integration_test_table_accessor_normal_filter_lists_ranges() {
+    // Test lists of values
+    assert_eq!(
+        engine("get all from drinks where size is one of (\"small\", \"medium\");"),
+        Ok("SELECT * FROM drinks WHERE size IN ('small', 'medium');".to_string())
+    );
+    assert_eq!(
+        engine("get all from drinks where id is not one of (1, 2 or 3) and price is any of (5);"),
+        Ok("SELECT * FROM drinks WHERE id NOT IN (1, 2, 3) AND price IN (5);".to_string())
+    );
+    assert_eq!(
+        engine("get all from drinks where name is one of (\"latte\" and \"mocha\");"),
+        Ok("SELECT * FROM drinks WHERE name IN ('latte', 'mocha');".to_string())
+    );
+
+    // Test the "and" of a range not being read as joining conditions
+    assert_eq!(
+        engine("get all from drinks where price is between 2 and 5 and size is 1;"),
+        Ok("SELECT * FROM drinks WHERE price BETWEEN 2 AND 5 AND size = 1;".to_string())
+    );
+    assert_eq!(
+        engine("get all from drinks where size = 1 or price is not between 2.5 and 5 and id > 1;"),
+        Ok(
+            "SELECT * FROM drinks WHERE size = 1 OR price NOT BETWEEN 2.5 AND 5 AND id > 1;"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        engine("get all from drinks where not (name is between \"a\" and \"m\");"),
+        Ok("SELECT * FROM drinks WHERE NOT name BETWEEN 'a' AND 'm';".to_string())
+    );
+}
+
+#[test]
+fn transpile_integration_test_table_accessor_normal_filter_empty_values() {
+    // Test null never being compared with "="
+    assert_eq!(
+        engine("get all from drinks where size is null or name = nothing;"),
+        Ok("SELECT * FROM drinks WHERE size IS NULL OR name IS NULL;".to_string())
+    );
+    assert_eq!(
+        engine("get all from drinks where size is not null and name != null;"),
+        Ok("SELECT * FROM drinks WHERE size IS NOT NULL AND name IS NOT NULL;".to_string())
+    );
+    assert_eq!(
+        engine(
+            "get all from drinks where size is empty or name is missing and not price is not empty;"
+        ),
+        Ok(
+            "SELECT * FROM drinks WHERE size IS NULL OR name IS NULL AND NOT price IS NOT NULL;"
+                .to_string()
+        )
+    );
+
+    // Test booleans
+    assert_eq!(
+        engine(
+            "get all from drinks where cost < 15 and (year > 2026 or best_by_date_exists = False);"
+        ),
+        Ok(
+            "SELECT * FROM drinks WHERE cost < 15 AND (year > 2026 OR best_by_date_exists = FALSE);"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        engine("get all from drinks where iced is true and hot is not TRUE;"),
+        Ok("SELECT * FROM drinks WHERE iced = TRUE AND hot <> TRUE;".to_string())
+    );
+}
+
+#[test]
+fn transpile_integration_test_table_accessor_normal_filter_arithmetic() {
+    // Test columns compared against other columns
+    assert_eq!(
+        engine("get all from drinks where price > cost and size is not name;"),
+        Ok("SELECT * FROM drinks WHERE price > cost AND size <> name;".to_string())
+    );
+    assert_eq!(
+        engine("get all from drinks where 5 < price;"),
+        Ok("SELECT * FROM drinks WHERE 5 < price;".to_string())
+    );
+
+    // Test arithmetic and its precedence
+    assert_eq!(
+        engine("get all from drinks where price * quantity + tax >= budget - 10 / 2;"),
+        Ok("SELECT * FROM drinks WHERE price * quantity + tax >= budget - 10 / 2;".to_string())
+    );
+    assert_eq!(
+        engine(
+            "get all from drinks where price times 2 plus tax is cost minus 1 or cost multiplied by 3 divided by 4 < 5;"
+        ),
+        Ok(
+            "SELECT * FROM drinks WHERE price * 2 + tax = cost - 1 OR cost * 3 / 4 < 5;"
+                .to_string()
+        )
+    );
+
+    // Test only the parentheses needed being kept
+    assert_eq!(
+        engine(
+            "get all from drinks where ((price + tax)) * 2 > (cost) and a - (b - c) < (a - b) - c;"
+        ),
+        Ok(
+            "SELECT * FROM drinks WHERE (price + tax) * 2 > cost AND a - (b - c) < a - b - c;"
+                .to_string()
+        )
+    );
+
+    // Test arithmetic parentheses being told apart from grouped conditions
+    assert_eq!(
+        engine("get all from drinks where (price + tax) * 2 > 10 and (size = 1 or (size) = 2);"),
+        Ok(
+            "SELECT * FROM drinks WHERE (price + tax) * 2 > 10 AND (size = 1 OR size = 2);"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        engine("get all from drinks where not (price - cost) / 2 > 1 or not ((a + b) = c);"),
+        Ok("SELECT * FROM drinks WHERE NOT (price - cost) / 2 > 1 OR NOT a + b = c;".to_string())
+    );
+
+    // Test negation never writing two minuses in a row, which starts a comment
+    assert_eq!(
+        engine("get all from drinks where -price < 5 and price - - 5 < - -5 and price > -(-cost);"),
+        Ok(
+            "SELECT * FROM drinks WHERE -price < 5 AND price - -5 < -(-5) AND price > -(-cost);"
+                .to_string()
+        )
+    );
+
+    // Test a negative number after something to subtract it from being subtracted
+    assert_eq!(
+        engine("get all from drinks where price -2 > 1 and 5-1 < cost and price is -2;"),
+        Ok("SELECT * FROM drinks WHERE price - 2 > 1 AND 5 - 1 < cost AND price = -2;".to_string())
+    );
+    // Test a '-' inside a word still being part of the column's name
+    assert_eq!(
+        engine("get all from drinks where price-2 > 1;"),
+        Ok("SELECT * FROM drinks WHERE \"price-2\" > 1;".to_string())
+    );
+
+    // Test "*" being everything when getting columns
+    assert_eq!(
+        engine("get * from drinks where price * 2 > 5;"),
+        Ok("SELECT * FROM drinks WHERE price * 2 > 5;".to_string())
+    );
+}
+
+#[test]
+fn transpile_integration_test_table_accessor_normal_filter_precedence() {
+    // Test that only parentheses changing the meaning are kept
+    assert_eq!(
+        engine("get all from test_table where (id = 3 or price = 2) and name is \"3\";"),
+        Ok("SELECT * FROM test_table WHERE (id = 3 OR price = 2) AND name = '3';".to_string())
+    );
+    assert_eq!(
+        engine("get all from test_table where ((id = 3)) or (price = 2 or (name is \"3\"));"),
+        Ok("SELECT * FROM test_table WHERE id = 3 OR price = 2 OR name = '3';".to_string())
+    );
+    assert_eq!(
+        engine("get all from test_table where id = 3 and (price = 2 and (time = 1 or value = 4));"),
+        Ok(
+            "SELECT * FROM test_table WHERE id = 3 AND price = 2 AND (time = 1 OR value = 4);"
+                .to_string()
+        )
+    );
+    // Test an "or" after a group on the right of an "and" closing out the "and"
+    assert_eq!(
+        engine("get x from t where a = 1 and (b = 2) or c = 3;"),
+        Ok("SELECT x FROM t WHERE a = 1 AND b = 2 OR c = 3;".to_string())
+    );
+    assert_eq!(
+        engine("get x from t where a = 1 and (b = 2 or d = 4) or c = 3;"),
+        Ok("SELECT x FROM t WHERE a = 1 AND (b = 2 OR d = 4) OR c = 3;".to_string())
+    );
+    assert_eq!(
+        engine("get x from t where a = 1 and ((b = 2) or e = 5) or c = 3;"),
+        Ok("SELECT x FROM t WHERE a = 1 AND (b = 2 OR e = 5) OR c = 3;".to_string())
+    );
+}
+
+#[test]
+fn transpile_integration_test_table_accessor_normal_filter_negation() {
+    // Test "not equal" operators
+    assert_eq!(
+        engine("get all from drinks where price is not 5;"),
+        Ok("SELECT * FROM drinks WHERE price <> 5;".to_string())
+    );
+    assert_eq!(
+        engine("get all from drinks where price != 5!"),
+        Ok("SELECT * FROM drinks WHERE price <> 5;".to_string())
+    );
+    assert_eq!(
+        engine("get all from drinks where price <> 5."),
+        Ok("SELECT * FROM drinks WHERE price <> 5;".to_string())
+    );
+
+    // Test negated expressions and groups
+    assert_eq!(
+        engine("get all from drinks where not (price = 1 or size = 2);"),
+        Ok("SELECT * FROM drinks WHERE NOT (price = 1 OR size = 2);".to_string())
+    );
+    assert_eq!(
+        engine("get all from drinks where not price = 1 and size is not 2 then limit 5;"),
+        Ok("SELECT * FROM drinks WHERE NOT price = 1 AND size <> 2 LIMIT 5;".to_string())
+    );
+    assert_eq!(
+        engine(
+            "get all from drinks where id = 3 or not (price < 2 and not (size = 1)) and id > 1;"
+        ),
+        Ok(
+            "SELECT * FROM drinks WHERE id = 3 OR NOT (price < 2 AND NOT size = 1) AND id > 1;"
+                .to_string()
+        )
+    );
 }
 
 #[test]
@@ -189,7 +505,7 @@ fn transpile_integration_test_table_accessor_normal_postprocessor() {
             "get all from test_table where id = 3 or (price <= 2 and name is \"3\") then limit 5."
         ),
         Ok(
-            "SELECT * FROM test_table WHERE id = 3 or (price <= 2 and name = \"3\") LIMIT 5;"
+            "SELECT * FROM test_table WHERE id = 3 OR price <= 2 AND name = '3' LIMIT 5;"
                 .to_string()
         )
     );
@@ -208,6 +524,46 @@ fn transpile_integration_test_table_accessor_normal_postprocessor_limit() {
     );
 }
 
+#[test]
+fn transpile_integration_test_table_accessor_normal_postprocessor_sort() {
+    // Test "post-processor sort" keywords
+    assert_eq!(
+        engine("get all from drinks then sort by price;"),
+        Ok("SELECT * FROM drinks ORDER BY price ASC;".to_string())
+    );
+    assert_eq!(
+        engine("get all from drinks then order it by price descending;"),
+        Ok("SELECT * FROM drinks ORDER BY price DESC;".to_string())
+    );
+    assert_eq!(
+        engine(
+            "Get me everything from drinks wherever the price is 5 and the category is \"coffee\" then sort it by price in ascending order."
+        ),
+        Ok(
+            "SELECT * FROM drinks WHERE price = 5 AND category = 'coffee' ORDER BY price ASC;"
+                .to_string()
+        )
+    );
+
+    // Test multiple sort keys
+    assert_eq!(
+        engine(
+            "get all from drinks then sort by price in descending order, name and size ascending;"
+        ),
+        Ok("SELECT * FROM drinks ORDER BY price DESC, name ASC, size ASC;".to_string())
+    );
+
+    // Test sort combined with limit in either order
+    assert_eq!(
+        engine("get all from drinks then sort by price descending and limit it to 5;"),
+        Ok("SELECT * FROM drinks ORDER BY price DESC LIMIT 5;".to_string())
+    );
+    assert_eq!(
+        engine("get all from drinks then limit it to 5 and sort by name and price descending;"),
+        Ok("SELECT * FROM drinks ORDER BY name ASC, price DESC LIMIT 5;".to_string())
+    );
+}
+
 // Error
 #[test]
 fn transpile_integration_test_table_accessor_error_get() {
@@ -225,17 +581,97 @@ fn transpile_integration_test_table_accessor_error_get() {
 fn transpile_integration_test_table_accessor_error_filter() {
     // Test bad conditions
     assert!(engine("get all from test_table where id = 3").is_err());
-    assert!(engine("get all from test_table where id is equal to 3;").is_err());
+    assert!(engine("get all from test_table where id is equal 3;").is_err());
     assert!(engine("get all from test_table where id <== 3;").is_err());
+    assert!(engine("get all from test_table where;").is_err());
+    assert!(engine("get all from test_table where () then limit 5;").is_err());
+
+    // Test operators missing a condition on either side
+    assert!(engine("get all from test_table where and or;").is_err());
+    assert!(engine("get all from test_table where and id = 3;").is_err());
+    assert!(engine("get all from test_table where id = 1 and;").is_err());
+    assert!(engine("get all from test_table where id = 1 or;").is_err());
+    assert!(engine("get all from test_table where id = 1 and or id = 2;").is_err());
+    assert!(engine("get all from test_table where (id = 1 and) or id = 2;").is_err());
+    assert!(engine("get all from test_table where (id = 1) or then limit 5;").is_err());
+    assert!(engine("get all from test_table where not (or id = 1);").is_err());
 
     // Test different bad conditionals
     assert!(
-        engine("get all from test_table where id = 3 or (price <= 2 and name is id)!").is_err()
+        engine("get all from test_table where id = 3 or (price <= 2 and name is id +)!").is_err()
     );
     assert!(engine("get all from test_table where (price < 3 or name is \"test\" and (id = 3 or (value < 4 and time >= 5)))));").is_err());
     assert!(engine("get all from test_table where price < 3 or name is \"test\" and (id = 3 or (value < 4 and time >= 5)))));").is_err());
 }
 
+#[test]
+fn transpile_integration_test_table_accessor_error_filter_patterns() {
+    // Test bad patterns
+    assert!(engine("get all from drinks where name contains 5;").is_err());
+    assert!(engine("get all from drinks where name starts \"a\";").is_err());
+    assert!(engine("get all from drinks where name ends \"a\";").is_err());
+    assert!(engine("get all from drinks where name like;").is_err());
+    assert!(engine("get all from drinks where price is 5 ignoring case;").is_err());
+    assert!(engine("get all from drinks where name contains \"a\" ignoring;").is_err());
+}
+
+#[test]
+fn transpile_integration_test_table_accessor_error_filter_lists_ranges() {
+    // Test bad lists
+    assert!(engine("get all from drinks where id is one of 1, 2;").is_err());
+    assert!(engine("get all from drinks where id is one of ();").is_err());
+    assert!(engine("get all from drinks where id is one of (1, 2;").is_err());
+    assert!(engine("get all from drinks where id is one of (1 and 2, 3);").is_err());
+    assert!(engine("get all from drinks where id is one of (1,);").is_err());
+
+    // Test bad ranges
+    assert!(engine("get all from drinks where price is between 2;").is_err());
+    assert!(engine("get all from drinks where price is between 2 or 5;").is_err());
+    assert!(engine("get all from drinks where price is between and 5;").is_err());
+    assert!(engine("get all from drinks where price is between 2 and;").is_err());
+}
+
+#[test]
+fn transpile_integration_test_table_accessor_error_filter_empty_values() {
+    // Test true, false and null only being compared with "is"
+    assert!(engine("get all from drinks where price > null;").is_err());
+    assert!(engine("get all from drinks where price <= false;").is_err());
+    assert!(engine("get all from drinks where name contains true;").is_err());
+    assert!(engine("get all from drinks where name is one of (null, 1);").is_err());
+    assert!(engine("get all from drinks where price is between false and true;").is_err());
+
+    // Test "is empty" not taking a value
+    assert!(engine("get all from drinks where size is empty 5;").is_err());
+    assert!(engine("get all from drinks where size is empty ignoring case;").is_err());
+    assert!(engine("get all from drinks where size is not;").is_err());
+}
+
+#[test]
+fn transpile_integration_test_table_accessor_error_filter_arithmetic() {
+    // Test incomplete arithmetic
+    assert!(engine("get all from drinks where price * > 5;").is_err());
+    assert!(engine("get all from drinks where price + 1;").is_err());
+    assert!(engine("get all from drinks where (price + 1 > 5;").is_err());
+    assert!(engine("get all from drinks where price * (2 > 5);").is_err());
+
+    // Test arithmetic only being compared with plain comparisons
+    assert!(engine("get all from drinks where price * 2 is one of (1, 2);").is_err());
+    assert!(engine("get all from drinks where name plus 1 contains \"a\";").is_err());
+    assert!(engine("get all from drinks where price + 1 is empty;").is_err());
+    assert!(engine("get all from drinks where price * null > 5;").is_err());
+    assert!(engine("get all from drinks where price > cost - true;").is_err());
+}
+
+#[test]
+fn transpile_integration_test_table_accessor_error_filter_negation() {
+    // Test bad negations
+    assert!(engine("get all from drinks where not;").is_err());
+    assert!(engine("get all from drinks where price not 5;").is_err());
+    assert!(engine("get all from drinks where not (price = 5;").is_err());
+    assert!(engine("get all from drinks where price is not;").is_err());
+    assert!(engine("get all from drinks where not \"price\" is one of (5);").is_err());
+}
+
 #[test]
 fn transpile_integration_test_table_accessor_error_postprocessor() {
     // Generic tests
@@ -258,4 +694,663 @@ fn transpile_integration_test_table_accessor_error_postprocessor_limit() {
     // Test bad limit
     assert!(engine("get all from test_table then limit = 5;").is_err());
     assert!(engine("get all from test_table then limit id;").is_err());
+
+    // Test limiting more than once
+    assert!(engine("get all from test_table then limit 5 and limit 10;").is_err());
+    assert!(engine("get all from test_table then limit 5 and sort by id and limit 5;").is_err());
+}
+
+#[test]
+fn transpile_integration_test_table_accessor_error_postprocessor_sort() {
+    // Test bad sort
+    assert!(engine("get all from drinks then sort price;").is_err());
+    assert!(engine("get all from drinks then sort by;").is_err());
+    assert!(engine("get all from drinks then sort by \"price\";").is_err());
+    assert!(engine("get all from drinks then sort by price and;").is_err());
+    assert!(engine("get all from drinks then sort by price and sort by name;").is_err());
+    assert!(engine("get all from drinks then;").is_err());
+}
+
+// Dialect Tests
+#[test]
+fn transpile_integration_test_dialect_table_accessor() {
+    // "group" and "user" are reserved in SQL so they must be quoted
+    let query: &str = "get name and group from user where name is \"latte\" then sort by group and limit it to 5;";
+
+    assert_eq!(
+        engine_with_dialect(query, &Ansi),
+        Ok("SELECT name, \"group\" FROM \"user\" WHERE name = 'latte' ORDER BY \"group\" ASC FETCH FIRST 5 ROWS ONLY;".to_string())
+    );
+    assert_eq!(
+        engine_with_dialect(query, &Postgres),
+        Ok("SELECT name, \"group\" FROM \"user\" WHERE name = 'latte' ORDER BY \"group\" ASC LIMIT 5;".to_string())
+    );
+    assert_eq!(
+        engine_with_dialect(query, &MySql),
+        Ok(
+            "SELECT name, `group` FROM `user` WHERE name = 'latte' ORDER BY `group` ASC LIMIT 5;"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        engine_with_dialect(query, &Sqlite),
+        Ok("SELECT name, \"group\" FROM \"user\" WHERE name = 'latte' ORDER BY \"group\" ASC LIMIT 5;".to_string())
+    );
+    assert_eq!(
+        engine_with_dialect(query, &SqlServer),
+        Ok(
+            "SELECT TOP 5 name, [group] FROM [user] WHERE name = 'latte' ORDER BY [group] ASC;"
+                .to_string()
+        )
+    );
+}
+
+#[test]
+fn transpile_integration_test_dialect_patterns() {
+    let query: &str =
+        "get all from deals where name contains \"50%\" or code starts with \"a_\" ignoring case;";
+
+    assert_eq!(
+        engine_with_dialect(query, &Ansi),
+        Ok("SELECT * FROM deals WHERE name LIKE '%50\\%%' ESCAPE '\\' OR LOWER(code) LIKE LOWER('a\\_%') ESCAPE '\\';".to_string())
+    );
+    assert_eq!(
+        engine_with_dialect(query, &Postgres),
+        Ok("SELECT * FROM deals WHERE name LIKE '%50\\%%' OR code ILIKE 'a\\_%';".to_string())
+    );
+    assert_eq!(
+        engine_with_dialect(query, &MySql),
+        Ok(
+            "SELECT * FROM deals WHERE name LIKE '%50\\\\%%' OR LOWER(code) LIKE LOWER('a\\\\_%');"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        engine_with_dialect(query, &Sqlite),
+        Ok("SELECT * FROM deals WHERE name LIKE '%50\\%%' ESCAPE '\\' OR LOWER(code) LIKE LOWER('a\\_%') ESCAPE '\\';".to_string())
+    );
+    assert_eq!(
+        engine_with_dialect(query, &SqlServer),
+        Ok(
+            "SELECT * FROM deals WHERE name LIKE '%50[%]%' OR LOWER(code) LIKE LOWER('a[_]%');"
+                .to_string()
+        )
+    );
+
+    // Patterns are bound as a whole, wildcards and all
+    assert_eq!(
+        engine_parameterized(query, &Postgres),
+        Ok((
+            "SELECT * FROM deals WHERE name LIKE $1 OR code ILIKE $2;".to_string(),
+            vec![
+                BoundValue::Text("%50\\%%".to_string()),
+                BoundValue::Text("a\\_%".to_string()),
+            ]
+        ))
+    );
+}
+
+#[test]
+fn transpile_integration_test_dialect_booleans() {
+    let query: &str = "get all from drinks where iced is true and hot is false or size is empty;";
+
+    assert_eq!(
+        engine_with_dialect(query, &Sqlite),
+        Ok("SELECT * FROM drinks WHERE iced = TRUE AND hot = FALSE OR size IS NULL;".to_string())
+    );
+    assert_eq!(
+        engine_with_dialect(query, &SqlServer),
+        Ok("SELECT * FROM drinks WHERE iced = 1 AND hot = 0 OR size IS NULL;".to_string())
+    );
+
+    // Booleans and null are part of the query rather than bound
+    assert_eq!(
+        engine_parameterized(query, &Postgres),
+        Ok((
+            "SELECT * FROM drinks WHERE iced = TRUE AND hot = FALSE OR size IS NULL;".to_string(),
+            vec![]
+        ))
+    );
+}
+
+#[test]
+fn transpile_integration_test_dialect_db_use() {
+    assert_eq!(
+        engine_with_dialect("use database test;", &MySql),
+        Ok("USE test;".to_string())
+    );
+    assert_eq!(
+        engine_with_dialect("use database test;", &SqlServer),
+        Ok("USE test;".to_string())
+    );
+    assert_eq!(
+        engine_with_dialect("use database test;", &Ansi),
+        Ok("SET SCHEMA 'test';".to_string())
+    );
+
+    // Dialects without database switching
+    assert!(engine_with_dialect("use database test;", &Postgres).is_err());
+    assert!(engine_with_dialect("use database test;", &Sqlite).is_err());
+}
+
+#[test]
+fn transpile_integration_test_dialect_db_show() {
+    assert_eq!(
+        engine_with_dialect("show databases;", &MySql),
+        Ok("SHOW DATABASES;".to_string())
+    );
+    assert_eq!(
+        engine_with_dialect("show databases;", &Postgres),
+        Ok("SELECT datname FROM pg_database WHERE NOT datistemplate;".to_string())
+    );
+    assert_eq!(
+        engine_with_dialect("show databases;", &Sqlite),
+        Ok("PRAGMA database_list;".to_string())
+    );
+    assert_eq!(
+        engine_with_dialect("show databases;", &SqlServer),
+        Ok("SELECT name FROM sys.databases;".to_string())
+    );
+    assert_eq!(
+        engine_with_dialect("show databases;", &Ansi),
+        Ok("SELECT schema_name FROM information_schema.schemata;".to_string())
+    );
+}
+
+#[test]
+fn transpile_integration_test_dialect_db_create_destroy() {
+    assert_eq!(
+        engine_with_dialect("create database test;", &Postgres),
+        Ok("CREATE DATABASE test;".to_string())
+    );
+    assert_eq!(
+        engine_with_dialect("create database test;", &Sqlite),
+        Ok("ATTACH DATABASE 'test.db' AS test;".to_string())
+    );
+    assert_eq!(
+        engine_with_dialect("delete databases db1 and db2;", &MySql),
+        Ok("DROP DATABASE db1; DROP DATABASE db2;".to_string())
+    );
+    assert_eq!(
+        engine_with_dialect("delete databases db1 and db2;", &SqlServer),
+        Ok("DROP DATABASE db1, db2;".to_string())
+    );
+    assert_eq!(
+        engine_with_dialect("delete databases db1 and db2;", &Ansi),
+        Ok("DROP SCHEMA db1; DROP SCHEMA db2;".to_string())
+    );
+}
+
+// Escaping Tests
+#[test]
+fn transpile_integration_test_escaping_string_literal() {
+    assert_eq!(
+        engine("get all from drinks where name is \"latte's\";"),
+        Ok("SELECT * FROM drinks WHERE name = 'latte''s';".to_string())
+    );
+
+    // Breaking out of the string literal is neutralised by doubling the quotes
+    assert_eq!(
+        engine("get all from drinks where name is \"x' OR '1'='1\";"),
+        Ok("SELECT * FROM drinks WHERE name = 'x'' OR ''1''=''1';".to_string())
+    );
+    assert_eq!(
+        engine_with_dialect(
+            "get all from drinks where name is \"x'; DROP TABLE drinks; --\";",
+            &Postgres
+        ),
+        Ok("SELECT * FROM drinks WHERE name = 'x''; DROP TABLE drinks; --';".to_string())
+    );
+
+    // MySQL treats backslashes as escapes so they're escaped too
+    assert_eq!(
+        engine_with_dialect(
+            "get all from drinks where name is \"x\\' OR 1=1 -- \";",
+            &MySql
+        ),
+        Ok("SELECT * FROM drinks WHERE name = 'x\\\\'' OR 1=1 -- ';".to_string())
+    );
+}
+
+#[test]
+fn transpile_integration_test_escaping_identifier() {
+    // Reserved words and unusual characters get quoted
+    assert_eq!(
+        engine("get select and price$ from drinks-2024;"),
+        Ok("SELECT \"select\", \"price$\" FROM \"drinks-2024\";".to_string())
+    );
+
+    // Closing quotes inside identifiers are doubled
+    assert_eq!(
+        engine_with_dialect("get all from drinks]--;", &SqlServer),
+        Ok("SELECT * FROM [drinks]]--];".to_string())
+    );
+    assert_eq!(
+        engine_with_dialect("get all from drinks`--;", &MySql),
+        Ok("SELECT * FROM `drinks``--`;".to_string())
+    );
+    assert_eq!(
+        engine_with_dialect("use database `x`;", &MySql),
+        Ok("USE ```x```;".to_string())
+    );
+}
+
+#[test]
+fn transpile_integration_test_escaping_unicode() {
+    // Names outside of ASCII are kept whole and quoted
+    assert_eq!(
+        engine("get caf\u{e9} and gr\u{f6}\u{df}e from b\u{fc}cher;"),
+        Ok("SELECT \"caf\u{e9}\", \"gr\u{f6}\u{df}e\" FROM \"b\u{fc}cher\";".to_string())
+    );
+    assert_eq!(
+        engine_with_dialect(
+            "get all from drinks where name is \"\u{2615} latte\";",
+            &SqlServer
+        ),
+        Ok("SELECT * FROM drinks WHERE name = '\u{2615} latte';".to_string())
+    );
+}
+
+#[test]
+fn transpile_integration_test_escaping_error_control_characters() {
+    assert!(engine("get all from drinks where name is \"x\nDROP TABLE drinks\";").is_err());
+    assert!(engine("get all from drinks where name is \"x\0\";").is_err());
+    assert!(engine("get all from drin\tks;").is_err());
+    assert!(engine_with_dialect("create database te\rst;", &Sqlite).is_err());
+}
+
+// Parameterized Tests
+#[test]
+fn transpile_integration_test_parameterized_normal() {
+    let query: &str = "get name from drinks where name is \"x' OR '1'='1\" or price <= -2.5 then sort by price and limit it to 10;";
+    let values: Vec<BoundValue> = vec![
+        BoundValue::Text("x' OR '1'='1".to_string()),
+        BoundValue::Float(-2.5),
+        BoundValue::Integer(10),
+    ];
+
+    assert_eq!(
+        engine_parameterized(query, &MySql),
+        Ok((
+            "SELECT name FROM drinks WHERE name = ? OR price <= ? ORDER BY price ASC LIMIT ?;"
+                .to_string(),
+            values.clone()
+        ))
+    );
+    assert_eq!(
+        engine_parameterized(query, &Postgres),
+        Ok((
+            "SELECT name FROM drinks WHERE name = $1 OR price <= $2 ORDER BY price ASC LIMIT $3;"
+                .to_string(),
+            values.clone()
+        ))
+    );
+    assert_eq!(
+        engine_parameterized(query, &Sqlite),
+        Ok((
+            "SELECT name FROM drinks WHERE name = :p1 OR price <= :p2 ORDER BY price ASC LIMIT :p3;"
+                .to_string(),
+            values.clone()
+        ))
+    );
+    assert_eq!(
+        engine_parameterized(query, &Ansi),
+        Ok((
+            "SELECT name FROM drinks WHERE name = ? OR price <= ? ORDER BY price ASC FETCH FIRST ? ROWS ONLY;"
+                .to_string(),
+            values.clone()
+        ))
+    );
+}
+
+#[test]
+fn transpile_integration_test_parameterized_top() {
+    // The limit comes first when selecting with TOP, so it's bound first
+    assert_eq!(
+        engine_parameterized(
+            "get name from drinks where price > 3 then limit it to 10;",
+            &SqlServer
+        ),
+        Ok((
+            "SELECT TOP (?) name FROM drinks WHERE price > ?;".to_string(),
+            vec![BoundValue::Integer(10), BoundValue::Integer(3)]
+        ))
+    );
+}
+
+#[test]
+fn transpile_integration_test_parameterized_lists_ranges() {
+    // Each value in a list or range is bound on its own
+    assert_eq!(
+        engine_parameterized(
+            "get all from drinks where size is one of (\"small\", 2) and price is between 2 and 5.5;",
+            &Postgres
+        ),
+        Ok((
+            "SELECT * FROM drinks WHERE size IN ($1, $2) AND price BETWEEN $3 AND $4;".to_string(),
+            vec![
+                BoundValue::Text("small".to_string()),
+                BoundValue::Integer(2),
+                BoundValue::Integer(2),
+                BoundValue::Float(5.5),
+            ]
+        ))
+    );
+}
+
+#[test]
+fn transpile_integration_test_parameterized_arithmetic() {
+    // Values in arithmetic are bound like any other
+    assert_eq!(
+        engine_parameterized(
+            "get all from drinks where price * 2 > cost - -1.5;",
+            &Postgres
+        ),
+        Ok((
+            "SELECT * FROM drinks WHERE price * $1 > cost - $2;".to_string(),
+            vec![BoundValue::Integer(2), BoundValue::Float(-1.5)]
+        ))
+    );
+}
+
+#[test]
+fn transpile_integration_test_parameterized_no_values() {
+    assert_eq!(
+        engine_parameterized("get all from drinks;", &Postgres),
+        Ok(("SELECT * FROM drinks;".to_string(), vec![]))
+    );
+    assert_eq!(
+        engine_parameterized("create database test;", &Postgres),
+        Ok(("CREATE DATABASE test;".to_string(), vec![]))
+    );
+}
+
+#[test]
+fn transpile_integration_test_parameterized_error() {
+    assert!(engine_parameterized("get all from drinks where;", &Postgres).is_err());
+    assert!(engine_parameterized("use database test;", &Postgres).is_err());
+}
+
+// Error Tests
+#[test]
+fn transpile_integration_test_error_kinds() {
+    assert!(matches!(
+        engine("get all from drinks where price <;"),
+        Err(EaqlError::UnexpectedToken(_))
+    ));
+    assert!(matches!(
+        engine_with_dialect("use database test;", &Postgres),
+        Err(EaqlError::Unsupported(_))
+    ));
+    assert!(matches!(
+        engine("get all from drinks where name is \"x\0\";"),
+        Err(EaqlError::InvalidValue(_))
+    ));
+}
+
+// Warning Tests
+#[test]
+fn transpile_integration_test_warnings() {
+    let (sql, warnings) = engine_with_warnings(
+        "get name, price and name from drinks then limit it to 0;",
+        &Postgres,
+    )
+    .unwrap();
+
+    assert_eq!(sql, "SELECT name, price, name FROM drinks LIMIT 0;");
+    assert_eq!(
+        warnings.iter().map(|v| v.message()).collect::<Vec<&str>>(),
+        vec![
+            "Column \"name\" is requested more than once.",
+            "Limiting to 0 rows will always return nothing."
+        ]
+    );
+    assert_eq!(
+        engine_with_warnings("get name from drinks then limit it to 1;", &Postgres),
+        Ok(("SELECT name FROM drinks LIMIT 1;".to_string(), vec![]))
+    );
+    assert!(engine_with_warnings("get name from drinks", &Postgres).is_err());
+}
+
+// Malformed Input Tests
+#[test]
+fn transpile_integration_test_malformed_never_panics() {
+    let queries: [&str; 23] = [
+        "",
+        " ",
+        ";",
+        "\n\t",
+        "get",
+        "get;",
+        "get all",
+        "get all from",
+        "get all from;",
+        "get , from drinks;",
+        "get all from drinks where",
+        "get all from drinks where (((;",
+        "get all from drinks where ));",
+        "get all from drinks where not;",
+        "get all from drinks where price < \"open;",
+        "get all from drinks then;",
+        "get all from drinks then limit;",
+        "get all from drinks then sort by;",
+        "get all from drinks then limit it to 5 and;",
+        "create database;",
+        "delete databases a and;",
+        "use;",
+        "\u{1F375} get all from drinks;",
+    ];
+
+    for query in queries {
+        for dialect in DIALECTS {
+            assert!(engine_with_dialect(query, *dialect).is_err(), "{:?}", query);
+            assert!(
+                engine_parameterized(query, *dialect).is_err(),
+                "{:?}",
+                query
+            );
+        }
+    }
+}
+
+// Reverse Transpiling Tests
+// Every successful query from the tests above, SQL -> EAQL -> SQL
+// should give back the same SQL in each dialect
+const ROUND_TRIP_QUERIES: &[&str] = &[
+    "create database test;",
+    "use database test;",
+    "show databases;",
+    "delete database db1!",
+    "delete databases db1, db2, db3;",
+    "get all from test_table;",
+    "get me id and value from test_table.",
+    "get me id, price, value from test_table!",
+    "get all from test_table where id = 3;",
+    "get all from test_table where id = 3 and price = 2.0.",
+    "get all from test_table where id = 3 or (price <= 2 and name is \"3\")!",
+    "get all from test_table where (price < 3 or name is \"test\" and (id = 3 or (value < 4 and time >= 5)));",
+    "get all from test_table where (id = 3 or price = 2) and name is \"3\";",
+    "get all from test_table where ((id = 3)) or (price = 2 or (name is \"3\"));",
+    "get all from test_table where id = 3 and (price = 2 and (time = 1 or value = 4));",
+    "get all from test_table where id = 3 and (price = 2) or name is \"3\";",
+    "get all from test_table where id = 3 and (price = 2 or time = 1) or name is \"3\";",
+    "get all from drinks where price is not 5;",
+    "get all from drinks where not (price = 1 or size = 2);",
+    "get all from drinks where not price = 1 and size is not 2 then limit 5;",
+    "get all from drinks where id = 3 or not (price < 2 and not (size = 1)) and id > 1;",
+    "get all from test_table where id = 3 or (price <= 2 and name is \"3\") then limit 5.",
+    "get all from test_table then limit it to 5;",
+    "get all from drinks then sort by price;",
+    "get all from drinks then order it by price descending;",
+    "Get me everything from drinks wherever the price is 5 and the category is \"coffee\" then sort it by price in ascending order.",
+    "get all from drinks then sort by price in descending order, name and size ascending;",
+    "get all from drinks then sort by price descending and limit it to 5;",
+    "get all from drinks then limit it to 5 and sort by name and price descending;",
+    "get name and group from user where name is \"latte\" then sort by group and limit it to 5;",
+    "get all from drinks where name is \"latte's\";",
+    "get all from drinks where name is \"x' OR '1'='1\";",
+    "get all from drinks where name is \"x'; DROP TABLE drinks; --\";",
+    "get all from drinks where name is \"x\\' OR 1=1 -- \";",
+    "get select and price$ from drinks-2024;",
+    "get all from drinks]--;",
+    "get all from drinks`--;",
+    "use database `x`;",
+    "get all from drinks where price < -1.5 then limit it to 0;",
+    "get all from drinks where name contains \"latte\";",
+    "get all from drinks where name starts with \"ice\" ignoring case or name ends with \"brew\";",
+    "get all from deals where name contains \"50%_off\\\\\" and code like \"a\\%_%\" ignoring case;",
+    "get all from deals where name like \"%\" or name like \"[x]\";",
+    "get all from drinks where size is one of (\"small\", \"medium\") or id is not one of (1, 2 or 3);",
+    "get all from drinks where price is between 2 and 5 and size is 1;",
+    "get all from drinks where not (name is between \"a\" and \"m\") or price is not between 2.5 and 5;",
+    "get all from drinks where size is empty or name is not missing and price != null;",
+    "get all from drinks where iced is true and not (hot = false);",
+    "get all from drinks where price > cost and 5 <= size;",
+    "get all from drinks where (price + tax) * 2 >= budget - 1 / 3 or not (a - (b - c)) / 2 = -d;",
+    "get all from drinks where price - - 5 < -(-5) and ((a * b > c));",
+];
+
+#[test]
+fn transpile_integration_test_reverse_round_trip() {
+    for query in ROUND_TRIP_QUERIES {
+        for dialect in DIALECTS {
+            // Some dialects can't express every query (i.e. `use` in SQLite)
+            let Ok(sql) = engine_with_dialect(query, *dialect) else {
+                continue;
+            };
+
+            let eaql = reverse::engine(&sql, *dialect).unwrap_or_else(|err| {
+                panic!(
+                    "{} ({}) couldn't be read back -> {:?}",
+                    sql,
+                    dialect.name(),
+                    err
+                )
+            });
+
+            assert_eq!(engine_with_dialect(&eaql, *dialect), Ok(sql), "{}", eaql);
+        }
+    }
+}
+
+#[test]
+fn transpile_integration_test_reverse_normal() {
+    assert_eq!(
+        reverse::engine(
+            "select name, price from drinks where price < 5 and not (size = 'large' or size <> 'small') order by price desc, name;",
+            &Generic
+        ),
+        Ok("Get name and price from drinks where price is less than 5 and not (size is \"large\" or size is not \"small\") \
+then sort by price descending and name."
+            .to_string())
+    );
+    assert_eq!(
+        reverse::engine("SELECT TOP 5 * FROM [user];", &SqlServer),
+        Ok("Get everything from user then limit it to 5.".to_string())
+    );
+    assert_eq!(
+        reverse::engine(
+            "SELECT * FROM drinks WHERE (price + tax) * 2 > cost AND (size = 1 OR size = -width);",
+            &Generic
+        ),
+        Ok("Get everything from drinks where (price + tax) * 2 is greater than cost and (size is 1 or size is equal to -width)."
+            .to_string())
+    );
+    // Groups on the right of an "and" keep their grouping
+    for (sql, expected) in [
+        (
+            "SELECT * FROM t WHERE a = 1 AND (b = 2) OR c = 3;",
+            "SELECT * FROM t WHERE a = 1 AND b = 2 OR c = 3;",
+        ),
+        (
+            "SELECT * FROM t WHERE a = 1 AND (b = 2 OR d = 4) OR c = 3;",
+            "SELECT * FROM t WHERE a = 1 AND (b = 2 OR d = 4) OR c = 3;",
+        ),
+    ] {
+        assert_eq!(
+            reverse::engine(sql, &Generic).and_then(|eaql| engine(&eaql)),
+            Ok(expected.to_string())
+        );
+    }
+    // Columns named like the words after `is` stay columns
+    assert_eq!(
+        reverse::engine(
+            "SELECT * FROM drinks WHERE size = empty OR size <> missing;",
+            &Generic
+        )
+        .and_then(|eaql| engine(&eaql)),
+        Ok("SELECT * FROM drinks WHERE size = empty OR size <> missing;".to_string())
+    );
+    assert_eq!(
+        reverse::engine("DROP SCHEMA a; DROP SCHEMA b;", &Ansi),
+        Ok("Delete databases a and b.".to_string())
+    );
+    assert_eq!(
+        reverse::engine("PRAGMA database_list", &Sqlite),
+        Ok("Show databases.".to_string())
+    );
+}
+
+#[test]
+fn transpile_integration_test_reverse_error() {
+    // Outside of the subset the transpiler emits
+    assert!(reverse::engine("SELECT name FROM drinks GROUP BY name;", &Generic).is_err());
+    assert!(reverse::engine("SELECT COUNT(*) FROM drinks;", &Generic).is_err());
+    assert!(reverse::engine("INSERT INTO drinks VALUES (1);", &Generic).is_err());
+    assert!(reverse::engine("SELECT * FROM a; SELECT * FROM b;", &Generic).is_err());
+    assert!(reverse::engine("", &Generic).is_err());
+
+    // Valid SQL that EAQL has no way of writing
+    assert!(reverse::engine("SELECT * FROM \"where\";", &Generic).is_err());
+    assert!(reverse::engine("SELECT * FROM drinks WHERE name = '\"';", &Generic).is_err());
+    assert!(reverse::engine("SELECT * FROM drinks WHERE name = 'a;", &Generic).is_err());
+    assert!(reverse::engine("SELECT * FROM drinks WHERE price + 1 IS NULL;", &Generic).is_err());
+    assert!(reverse::engine("SELECT * FROM drinks WHERE price = 1 --2;", &Generic).is_err());
+}
+
+// Script Tests
+#[test]
+fn transpile_integration_test_script_normal() {
+    use eaql::transpiler::engine_script;
+
+    assert_eq!(
+        engine_script(
+            "create database shop. use database shop; get all from drinks where price < 5!",
+            &Generic
+        ),
+        Ok(
+            "CREATE DATABASE shop;\nUSE DATABASE shop;\nSELECT * FROM drinks WHERE price < 5;"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        engine_script("get name from user; get name from drinks;", &Postgres),
+        Ok("SELECT name FROM \"user\";\nSELECT name FROM drinks;".to_string())
+    );
+}
+
+#[test]
+fn transpile_integration_test_script_error() {
+    use eaql::transpiler::engine_script;
+
+    assert_eq!(
+        engine_script("get all from; show databases; get name;", &Generic)
+            .unwrap_err()
+            .len(),
+        2
+    );
+
+    // Statements a dialect can't express are reported alongside parse errors
+    let errors = engine_script("use database a; use database b;", &Postgres).unwrap_err();
+
+    assert_eq!(errors.len(), 2);
+    assert!(
+        errors
+            .iter()
+            .all(|v| matches!(v, EaqlError::Unsupported(_)))
+    );
+    assert!(matches!(
+        engine("get all from drinks; get all from food;"),
+        Err(EaqlError::Unsupported(_))
+    ));
 }
@@ -1,71 +1,79 @@
-use eaql::validator::engine;
+use eaql::{
+    language::{
+        ast::PartialQuery,
+        diagnostics::{render, suggestion},
+        errors::EaqlError,
+        tokens::{Position, Span},
+    },
+    validator::{engine, engine_recovering, engine_with_warnings},
+};
 
 // Database Query Tests (Validator)
 // Normal
 #[test]
 fn validator_integration_test_db_create_normal() {
     // Create keyword tests
-    assert_eq!(engine("create database test;"), true);
-    assert_eq!(engine("make database test."), true);
+    assert_eq!(engine("create database test;"), Ok(()));
+    assert_eq!(engine("make database test."), Ok(()));
 }
 
 #[test]
 fn validator_integration_test_db_use_normal() {
     // Use keyword tests
-    assert_eq!(engine("use database test;"), true);
-    assert_eq!(engine("enter database test;"), true);
+    assert_eq!(engine("use database test;"), Ok(()));
+    assert_eq!(engine("enter database test;"), Ok(()));
 }
 
 #[test]
 fn validator_integration_test_db_show_normal() {
     // Show keyword tests
-    assert_eq!(engine("show database;"), true);
-    assert_eq!(engine("list databases."), true);
+    assert_eq!(engine("show database;"), Ok(()));
+    assert_eq!(engine("list databases."), Ok(()));
 }
 
 #[test]
 fn validator_integration_test_db_destroy_normal() {
     // Destroy
-    assert_eq!(engine("remove database db1!"), true);
-    assert_eq!(engine("destroy database db1!"), true);
-    assert_eq!(engine("delete database db1!"), true);
+    assert_eq!(engine("remove database db1!"), Ok(()));
+    assert_eq!(engine("destroy database db1!"), Ok(()));
+    assert_eq!(engine("delete database db1!"), Ok(()));
 
     // Multiple databases tests
-    assert_eq!(engine("delete databases db1, db2, db3;"), true);
-    assert_eq!(engine("Destroy the databases db1, db2 and db3."), true);
+    assert_eq!(engine("delete databases db1, db2, db3;"), Ok(()));
+    assert_eq!(engine("Destroy the databases db1, db2 and db3."), Ok(()));
 }
 
 // Error
 #[test]
 fn validator_integration_test_db_create_error() {
     // Generic Error Test
-    assert_eq!(engine("create database test"), false);
-    assert_eq!(engine("Create the test!"), false);
-    assert_eq!(engine("Make the database \"test\"."), false);
+    assert!(engine("create database test").is_err());
+    assert!(engine("Create the test!").is_err());
+    assert!(engine("Make the database \"test\".").is_err());
 }
 
 #[test]
 fn validator_integration_test_db_use_error() {
     // Generic Error Test
-    assert_eq!(engine("use database test"), false);
-    assert_eq!(engine("Enter test!"), false);
-    assert_eq!(engine("enter the database \"test\"."), false);
+    assert!(engine("use database test").is_err());
+    assert!(engine("Enter test!").is_err());
+    assert!(engine("enter the database \"test\".").is_err());
 }
 
 #[test]
 fn validator_integration_test_db_show_error() {
     // Generic Error Test
-    assert_eq!(engine("show database"), false);
-    assert_eq!(engine("show!"), false);
-    assert_eq!(engine("List the \"databases\"."), false);
+    assert!(engine("show database").is_err());
+    assert!(engine("show!").is_err());
+    assert!(engine("List the \"databases\".").is_err());
 }
 
 #[test]
 fn validator_integration_test_db_destroy_error() {
     // Generic Error Test
-    assert_eq!(engine("delete databases db1, db2, db3"), false);
-    assert_eq!(engine("Delete db1!"), false);
-    assert_eq!(engine("Destroy the databases db1, \"db2\" and db3."), false);
+    assert!(engine("delete databases db1, db2, db3").is_err());
+    assert!(engine("Delete db1!").is_err());
+    assert!(engine("Destroy the databases db1, \"db2\" and db3.").is_err());
 }
 
 // Table Accessor Query Tests (Validator)
@@ -73,129 +81,487 @@ fn validator_integration_test_db_destroy_error() {
 #[test]
 fn validator_integration_test_table_accessor_normal_get() {
     // Test "wildcard" keywords
-    assert_eq!(engine("get all from test_table;"), true);
-    assert_eq!(engine("get any from test_table;"), true);
-    assert_eq!(engine("get everything from test_table;"), true);
+    assert_eq!(engine("get all from test_table;"), Ok(()));
+    assert_eq!(engine("get any from test_table;"), Ok(()));
+    assert_eq!(engine("get everything from test_table;"), Ok(()));
 
     // Test "get" keywords
-    assert_eq!(engine("get all from test_table;"), true);
-    assert_eq!(engine("retrieve all from test_table;"), true);
-    assert_eq!(engine("find all from test_table;"), true);
+    assert_eq!(engine("get all from test_table;"), Ok(()));
+    assert_eq!(engine("retrieve all from test_table;"), Ok(()));
+    assert_eq!(engine("find all from test_table;"), Ok(()));
 
     // Test column listing
-    assert_eq!(engine("get me id and value from test_table."), true);
-    assert_eq!(engine("get me id, price, value from test_table!"), true);
+    assert_eq!(engine("get me id and value from test_table."), Ok(()));
+    assert_eq!(engine("get me id, price, value from test_table!"), Ok(()));
+
+    // Test names outside of ASCII
+    assert_eq!(
+        engine("get caf\u{e9} and \u{1F375} from b\u{fc}cher;"),
+        Ok(())
+    );
 }
 
 #[test]
 fn validator_integration_test_table_accessor_normal_filter() {
     // Test "filter entrance" keywords
-    assert_eq!(engine("get all from test_table where id = 3;"), true);
-    assert_eq!(engine("get all from test_table wherever id = 3;"), true);
-    assert_eq!(engine("get all from test_table whenever id = 3;"), true);
+    assert_eq!(engine("get all from test_table where id = 3;"), Ok(()));
+    assert_eq!(engine("get all from test_table wherever id = 3;"), Ok(()));
+    assert_eq!(engine("get all from test_table whenever id = 3;"), Ok(()));
 
     // Test different conditionals
     assert_eq!(
         engine("get all from test_table where id = 3 and price = 2.0."),
-        true
+        Ok(())
     );
     assert_eq!(
         engine("get all from test_table where id = 3 or (price <= 2 and name is \"3\")!"),
-        true
+        Ok(())
     );
     assert_eq!(
         engine(
             "get all from test_table where (price < 3 or name is \"test\" and (id = 3 or (value < 4 and time >= 5)));"
         ),
-        true
+        Ok(())
     );
 }
 
 #[test]
 fn validator_integration_test_table_accessor_normal_postprocessor() {
     // Test "post-processor entrance" keywords
-    assert_eq!(engine("get all from test_table then limit 5;"), true);
-    assert_eq!(engine("get all from test_table afterwords limit 5;"), true);
-    assert_eq!(engine("get all from test_table after limit 5;"), true);
+    assert_eq!(engine("get all from test_table then limit 5;"), Ok(()));
+    assert_eq!(
+        engine("get all from test_table afterwords limit 5;"),
+        Ok(())
+    );
+    assert_eq!(engine("get all from test_table after limit 5;"), Ok(()));
 
     // Test post-processor w/ filter
     assert_eq!(
         engine(
             "get all from test_table where id = 3 or (price <= 2 and name is \"3\") then limit 5."
         ),
-        true
+        Ok(())
     );
 }
 
 #[test]
 fn validator_integration_test_table_accessor_normal_postprocessor_limit() {
     // Test "post-processor limit" keywords
-    assert_eq!(engine("get all from test_table then limit 5;"), true);
-    assert_eq!(engine("get all from test_table then limit it to 5;"), true);
+    assert_eq!(engine("get all from test_table then limit 5;"), Ok(()));
+    assert_eq!(
+        engine("get all from test_table then limit it to 5;"),
+        Ok(())
+    );
+}
+
+#[test]
+fn validator_integration_test_table_accessor_normal_postprocessor_sort() {
+    // Test "post-processor sort" keywords
+    assert_eq!(engine("get all from drinks then sort by price;"), Ok(()));
+    assert_eq!(
+        engine("get all from drinks then order it by price in descending order;"),
+        Ok(())
+    );
+    assert_eq!(
+        engine("get all from drinks then limit it to 5 and sort by name, price descending;"),
+        Ok(())
+    );
 }
 
 // Error
 #[test]
 fn validator_integration_test_table_accessor_error_get() {
     // Test improper tokens
-    assert_eq!(engine("get all from \"test_table\";"), false);
-    assert_eq!(engine("get all from test_table"), false);
-    assert_eq!(engine("get everything in test_table;"), false);
+    assert!(engine("get all from \"test_table\";").is_err());
+    assert!(engine("get all from test_table").is_err());
+    assert!(engine("get everything in test_table;").is_err());
 
     // Test invalid column listing
-    assert_eq!(engine("get me id and \"value\" from test_table."), false);
-    assert_eq!(engine("get me id, price value from test_table!"), false);
+    assert!(engine("get me id and \"value\" from test_table.").is_err());
+    assert!(engine("get me id, price value from test_table!").is_err());
 }
 
 #[test]
 fn validator_integration_test_table_accessor_error_filter() {
     // Test bad conditions
-    assert_eq!(engine("get all from test_table where id = 3"), false);
-    assert_eq!(
-        engine("get all from test_table where id is equal to 3;"),
-        false
-    );
-    assert_eq!(engine("get all from test_table where id <== 3;"), false);
+    assert!(engine("get all from test_table where id = 3").is_err());
+    assert!(engine("get all from test_table where id is equal 3;").is_err());
+    assert!(engine("get all from test_table where id <== 3;").is_err());
 
     // Test different bad conditionals
-    assert_eq!(
-        engine("get all from test_table where id = 3 or (price <= 2 and name is id)!"),
-        false
-    );
-    assert_eq!(
-        engine(
-            "get all from test_table where (price < 3 or name is \"test\" and (id = 3 or (value < 4 and time >= 5)))));"
-        ),
-        false
-    );
-    assert_eq!(
-        engine(
-            "get all from test_table where price < 3 or name is \"test\" and (id = 3 or (value < 4 and time >= 5)))));"
-        ),
-        false
+    assert!(
+        engine("get all from test_table where id = 3 or (price <= 2 and name is id +)!").is_err()
     );
+    assert!(engine("get all from test_table where (price < 3 or name is \"test\" and (id = 3 or (value < 4 and time >= 5)))));").is_err());
+    assert!(engine("get all from test_table where price < 3 or name is \"test\" and (id = 3 or (value < 4 and time >= 5)))));").is_err());
 }
 
 #[test]
 fn validator_integration_test_table_accessor_error_postprocessor() {
     // Generic tests
-    assert_eq!(engine("get all from test_table then limit 5"), false);
+    assert!(engine("get all from test_table then limit 5").is_err());
 
     // Test bad postprocessors
-    assert_eq!(engine("get all from test_table afterwords is 5;"), false);
+    assert!(engine("get all from test_table afterwords is 5;").is_err());
 
     // Test post-processor befor filter
-    assert_eq!(
+    assert!(
         engine(
             "get all from test_table then limit 5 where id = 3 or (price <= 2 and name is \"3\")."
-        ),
-        false
+        )
+        .is_err()
     );
 }
 
 #[test]
 fn validator_integration_test_table_accessor_error_postprocessor_limit() {
     // Test bad limit
-    assert_eq!(engine("get all from test_table then limit = 5;"), false);
-    assert_eq!(engine("get all from test_table then limit id;"), false);
+    assert!(engine("get all from test_table then limit = 5;").is_err());
+    assert!(engine("get all from test_table then limit id;").is_err());
+}
+
+#[test]
+fn validator_integration_test_table_accessor_error_postprocessor_sort() {
+    // Test bad sort
+    assert!(engine("get all from drinks then sort price;").is_err());
+    assert!(engine("get all from drinks then sort by ascending;").is_err());
+    assert!(engine("get all from drinks then sort by price and;").is_err());
+}
+
+// Error Tests
+#[test]
+fn validator_integration_test_error_kinds() {
+    assert!(matches!(
+        engine("get all from drinks"),
+        Err(EaqlError::MissingEndOfQuery(_))
+    ));
+    assert!(matches!(
+        engine("get all from drinks where name is \"latte;"),
+        Err(EaqlError::UnknownToken(_))
+    ));
+    assert!(matches!(
+        engine("fetch all from drinks;"),
+        Err(EaqlError::UnknownAction(_))
+    ));
+    assert!(matches!(
+        engine("get all from drinks where (price < 5 or id = 2;"),
+        Err(EaqlError::UnbalancedParen(_))
+    ));
+    assert!(matches!(
+        engine("get all from drinks where price < 5);"),
+        Err(EaqlError::UnbalancedParen(_))
+    ));
+    assert!(matches!(
+        engine("get all from drinks where;"),
+        Err(EaqlError::EmptyCondition(_))
+    ));
+    assert!(matches!(
+        engine("get all from drinks where price < 5 and;"),
+        Err(EaqlError::EmptyCondition(_))
+    ));
+    assert!(matches!(
+        engine("get all from drinks then limit it to 2.5;"),
+        Err(EaqlError::InvalidLimit(_))
+    ));
+    assert!(matches!(
+        engine("get all from drinks then limit it to 2 and limit it to 3;"),
+        Err(EaqlError::InvalidLimit(_))
+    ));
+    assert!(matches!(
+        engine("get all from drinks then sort by price and sort by name;"),
+        Err(EaqlError::InvalidSort(_))
+    ));
+    assert!(matches!(
+        engine("get all from drinks where price is;"),
+        Err(EaqlError::UnexpectedToken(_))
+    ));
+    assert!(matches!(
+        engine("get all from;"),
+        Err(EaqlError::UnexpectedToken(_))
+    ));
+}
+
+#[test]
+fn validator_integration_test_error_spans() {
+    let err: EaqlError = engine("get all from drinks then limit it to 2.5;").unwrap_err();
+
+    assert_eq!(err.span(), Some(Span::new(37, 40)));
+    assert_eq!(err.token().map(|v| v.lexeme.as_str()), Some("2.5"));
+    assert_eq!(
+        err.to_string(),
+        "Limit post-processor expects 32-bit integer, got -> 2.5"
+    );
+
+    // Missing pieces point just past the last token
+    let err: EaqlError = engine("get all from drinks").unwrap_err();
+
+    assert_eq!(err.span(), Some(Span::new(19, 19)));
+    assert_eq!(err.token().map(|v| v.lexeme.as_str()), Some("drinks"));
+}
+
+#[test]
+fn validator_integration_test_error_positions() {
+    let err: EaqlError = engine("get all\nfrom drinks\nthen limit it to 2.5;").unwrap_err();
+
+    assert_eq!(
+        err.position(),
+        Some(Position {
+            line: 3,
+            column: 18
+        })
+    );
+
+    // Missing pieces point at the column after the last token
+    let err: EaqlError = engine("get all\nfrom drinks").unwrap_err();
+
+    assert_eq!(
+        err.position(),
+        Some(Position {
+            line: 2,
+            column: 12
+        })
+    );
+}
+
+#[test]
+fn validator_integration_test_error_hints() {
+    let err: EaqlError = engine("get all from drinks where price 5;").unwrap_err();
+
+    assert_eq!(
+        err.hint(),
+        Some("expected a comparison like `is`, `>`, `<=`")
+    );
+    assert_eq!(suggestion(&err), None);
+
+    let err: EaqlError = engine("get all from drinks then limt it to 5;").unwrap_err();
+
+    assert_eq!(suggestion(&err), Some("did you mean `limit`?".to_string()));
+
+    // Identifiers where identifiers belong aren't mistaken for typos
+    let err: EaqlError = engine("get form from drinks where price;").unwrap_err();
+
+    assert_eq!(suggestion(&err), None);
+}
+
+#[test]
+fn validator_integration_test_error_phrases() {
+    // Unfinished phrases point at what they could have been
+    let err: EaqlError = engine("get all from drinks where price is greater 5;").unwrap_err();
+
+    assert!(matches!(err, EaqlError::UnexpectedToken(_)));
+    assert_eq!(err.span(), Some(Span::new(32, 42)));
+    assert_eq!(
+        err.hint(),
+        Some("did you mean `is greater than` or `is greater than or equal to`?")
+    );
+    assert_eq!(suggestion(&err), None);
+
+    // Phrases that could mean more than one comparison list each
+    let err: EaqlError = engine("get all from drinks where price is at 5;").unwrap_err();
+
+    assert_eq!(
+        err.message(),
+        "Couldn't tell which comparison \"is at\" was meant to be."
+    );
+    assert_eq!(
+        err.hint(),
+        Some("did you mean `is at most` or `is at least`?")
+    );
+
+    let err: EaqlError = engine("get all from drinks where price less 5;").unwrap_err();
+
+    assert_eq!(
+        err.hint(),
+        Some("did you mean `less than` or `less than or equal to`?")
+    );
+
+    let err: EaqlError =
+        engine("get all from drinks where price is less than or equal 5;").unwrap_err();
+
+    assert_eq!(err.hint(), Some("did you mean `is less than or equal to`?"));
+}
+
+#[test]
+fn validator_integration_test_error_patterns() {
+    // Patterns only match text
+    let err: EaqlError = engine("get all from drinks where name contains 5;").unwrap_err();
+
+    assert!(matches!(err, EaqlError::InvalidValue(_)));
+    assert_eq!(err.span(), Some(Span::new(40, 41)));
+    assert_eq!(
+        err.hint(),
+        Some("write the value as text, i.e. `contains \"5\"`")
+    );
+
+    // Only patterns can ignore case
+    let err: EaqlError = engine("get all from drinks where price is 5 ignoring case;").unwrap_err();
+
+    assert!(matches!(err, EaqlError::UnexpectedToken(_)));
+    assert_eq!(err.span(), Some(Span::new(37, 50)));
+
+    let err: EaqlError = engine("get all from drinks where name ends \"x\";").unwrap_err();
+
+    assert_eq!(err.hint(), Some("did you mean `ends with`?"));
+}
+
+#[test]
+fn validator_integration_test_error_lists_ranges() {
+    // Lists have to be wrapped in parentheses
+    let err: EaqlError = engine("get all from drinks where id is one of 1, 2;").unwrap_err();
+
+    assert_eq!(
+        err.hint(),
+        Some("lists of values are wrapped in parentheses, i.e. `is one of (\"coffee\", \"tea\")`")
+    );
+
+    let err: EaqlError = engine("get all from drinks where id is one of (1, 2;").unwrap_err();
+
+    assert!(matches!(err, EaqlError::UnbalancedParen(_)));
+
+    // Ranges need both of their ends
+    let err: EaqlError = engine("get all from drinks where price is between 2 or 5;").unwrap_err();
+
+    assert_eq!(
+        err.hint(),
+        Some("ranges are written as `is between 2 and ...`")
+    );
+}
+
+#[test]
+fn validator_integration_test_error_empty_values() {
+    // Null can't be ordered or matched
+    let err: EaqlError = engine("get all from drinks where price > null;").unwrap_err();
+
+    assert!(matches!(err, EaqlError::InvalidValue(_)));
+    assert_eq!(err.span(), Some(Span::new(34, 38)));
+    assert_eq!(
+        err.hint(),
+        Some("`true`, `false` and `null` are compared with `is` or `is not`")
+    );
+}
+
+#[test]
+fn validator_integration_test_error_arithmetic() {
+    // Arithmetic is only compared with plain comparisons
+    let err: EaqlError =
+        engine("get all from drinks where price * 2 is one of (1, 2);").unwrap_err();
+
+    assert_eq!(err.span(), Some(Span::new(36, 45)));
+    assert_eq!(
+        err.hint(),
+        Some("arithmetic and columns are compared with `is`, `is not`, `<`, `<=`, `>` or `>=`")
+    );
+
+    let err: EaqlError = engine("get all from drinks where (price + 1 > 5;").unwrap_err();
+
+    assert!(matches!(err, EaqlError::UnbalancedParen(_)));
+
+    let err: EaqlError = engine("get all from drinks where price * null > 5;").unwrap_err();
+
+    assert!(matches!(err, EaqlError::InvalidValue(_)));
+    assert_eq!(err.span(), Some(Span::new(34, 38)));
+}
+
+#[test]
+fn validator_integration_test_error_render() {
+    let query: &str = "get name\nfrm drinks;";
+
+    assert_eq!(
+        render(&engine(query).unwrap_err(), query),
+        "error: From-like keyword required for table selection, got \"frm\" instead
+ --> query:2:1
+  |
+2 | frm drinks;
+  | ^^^
+  |
+  = note: expected `from` followed by a table name, i.e. `from drinks`
+  = help: did you mean `from`?"
+    );
+}
+
+#[test]
+fn validator_integration_test_warnings() {
+    let warnings = engine_with_warnings("get all from drinks then limit it to -1;").unwrap();
+
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].message(),
+        "Limiting to -1 rows will always return nothing."
+    );
+    assert_eq!(warnings[0].span(), Some(Span::new(25, 39)));
+    assert_eq!(engine_with_warnings("get all from drinks;"), Ok(vec![]));
+}
+
+// Error Recovery Tests
+#[test]
+fn validator_integration_test_recovering_normal() {
+    let (partial, errors) = engine_recovering("get name, price from drinks where price < 5;");
+
+    assert_eq!(errors, vec![]);
+    let PartialQuery::Get(get) = partial else {
+        panic!("Expected a partial get query");
+    };
+
+    assert_eq!(get.complete().map(|v| v.columns().names().len()), Some(2));
+
+    let (partial, errors) = engine_recovering("show databases;");
+
+    assert_eq!(errors, vec![]);
+    assert!(matches!(partial, PartialQuery::Database(_)));
+}
+
+#[test]
+fn validator_integration_test_recovering_error() {
+    let query = "get name, from drinks where price < then sort by and limit it to 5";
+    let (partial, errors) = engine_recovering(query);
+
+    // Every clause with a problem is reported in one pass
+    assert_eq!(errors.len(), 4);
+    assert!(matches!(errors[0], EaqlError::MissingEndOfQuery(_)));
+    assert!(matches!(errors[1], EaqlError::UnexpectedToken(_)));
+    assert!(matches!(errors[2], EaqlError::UnexpectedToken(_)));
+    assert!(matches!(errors[3], EaqlError::InvalidSort(_)));
+
+    // The first error is the one the plain engine stops at
+    assert_eq!(engine(query), Err(errors[0].clone()));
+
+    let PartialQuery::Get(get) = partial else {
+        panic!("Expected a partial get query");
+    };
+
+    assert!(get.columns().is_none());
+    assert!(get.filter().is_none());
+    assert!(get.postprocessor().is_none());
+    assert_eq!(get.table().map(|v| v.name()), Some("drinks"));
+
+    let (partial, errors) = engine_recovering("retreive name from drinks; get all from drinks;");
+
+    assert!(matches!(partial, PartialQuery::Empty));
+    assert!(matches!(
+        errors[..],
+        [EaqlError::Unsupported(_), EaqlError::UnknownAction(_)]
+    ));
+}
+
+#[test]
+fn validator_integration_test_recovering_unterminated_string() {
+    // The string swallows the end of the query, so that's the only error
+    let (partial, errors) = engine_recovering("get name from drinks where name = \"unterminated.");
+
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0], EaqlError::UnknownToken(_)));
+    assert_eq!(errors[0].span().map(|v| v.start), Some(34));
+
+    let PartialQuery::Get(get) = partial else {
+        panic!("Expected a partial get query");
+    };
+
+    assert_eq!(get.table().map(|v| v.name()), Some("drinks"));
+
+    // Errors before the string are still reported
+    let (_, errors) = engine_recovering("get from drinks where name = \"unterminated.");
+
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(errors[0], EaqlError::UnknownToken(_)));
+    assert!(matches!(errors[1], EaqlError::UnexpectedToken(_)));
 }